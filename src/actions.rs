@@ -1,14 +1,19 @@
 //! Post-selection action handlers — every kubectl operation lives here.
 
 use anyhow::Result;
-use std::io::{self, Write};
-use std::process::Command;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use serde_json::Value;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
-use crate::items::{K8sItem, ResourceKind};
+use crate::items::{K8sItem, ResourceKind, StatusHealth};
 
 // ─── Secure runtime directory ─────────────────────────────────────────────────
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Returns a secure, per-process runtime directory for temp files.
@@ -43,16 +48,31 @@ pub fn preview_toggle_path() -> PathBuf {
 
 // ─── Preview mode (shared with items.rs via temp file) ────────────────────────
 
+/// Number of preview modes ctrl-p cycles through.
+pub const PREVIEW_MODES: u8 = 6;
+
 /// Install the preview-toggle shell script and reset the mode to 0 (describe).
 /// Called once at startup before skim opens.
 pub fn install_preview_toggle() {
+    write_preview_toggle(0);
+}
+
+/// Like [`install_preview_toggle`], but keep the mode the user last cycled
+/// to, so relaunching skim (ctrl-x, ctrl-o, after an action, …) doesn't
+/// throw them back to describe.
+pub fn install_preview_toggle_preserving() {
+    write_preview_toggle(current_preview_mode());
+}
+
+fn write_preview_toggle(mode: u8) {
     let mode_path = preview_mode_path();
     let toggle_path = preview_toggle_path();
     let script = format!(
         "#!/bin/sh\n\
          n=$(cat \"{mode}\" 2>/dev/null || echo 0)\n\
-         printf $(( (n + 1) % 4 )) > \"{mode}\"\n",
-        mode = mode_path.display()
+         printf $(( (n + 1) % {modes} )) > \"{mode}\"\n",
+        mode = mode_path.display(),
+        modes = PREVIEW_MODES
     );
     if let Err(e) = std::fs::write(&toggle_path, &script) {
         eprintln!("[kuberift] warning: cannot write preview toggle script: {e}");
@@ -63,18 +83,104 @@ pub fn install_preview_toggle() {
         use std::os::unix::fs::PermissionsExt;
         let _ = std::fs::set_permissions(&toggle_path, std::fs::Permissions::from_mode(0o700));
     }
-    if let Err(e) = std::fs::write(&mode_path, "0") {
+    if let Err(e) = std::fs::write(&mode_path, mode.to_string()) {
         eprintln!("[kuberift] warning: cannot write preview mode file: {e}");
     }
 }
 
-/// Read the current preview mode (0 = describe, 1 = yaml, 2 = logs, 3 = events).
+/// Read the current preview mode (0 = describe, 1 = yaml, 2 = logs,
+/// 3 = events, 4 = related, 5 = previous logs).
 pub fn current_preview_mode() -> u8 {
     std::fs::read_to_string(preview_mode_path())
         .ok()
         .and_then(|s| s.trim().parse::<u8>().ok())
         .unwrap_or(0)
-        % 4
+        % PREVIEW_MODES
+}
+
+// ─── kubectl version ──────────────────────────────────────────────────────────
+
+/// Oldest kubectl the argument builders are written for; older clients get a
+/// startup warning.
+pub const MIN_KUBECTL_VERSION: KubectlVersion = KubectlVersion {
+    major: 1,
+    minor: 18,
+};
+
+/// Client version reported by `kubectl version --client -o json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KubectlVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl std::fmt::Display for KubectlVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+static KUBECTL_VERSION: OnceLock<Option<KubectlVersion>> = OnceLock::new();
+
+/// Parse `kubectl version --client -o json`. `major`/`minor` are read from
+/// `clientVersion`, tolerating the `+` some distributions append
+/// (`"minor": "29+"`); `gitVersion` (`v1.29.2`) is the fallback.
+pub fn parse_kubectl_version(json: &str) -> Option<KubectlVersion> {
+    let root: Value = serde_json::from_str(json).ok()?;
+    let client = root.get("clientVersion")?;
+    let number = |field: &str| -> Option<u32> {
+        client
+            .get(field)?
+            .as_str()?
+            .trim_end_matches('+')
+            .parse()
+            .ok()
+    };
+    if let (Some(major), Some(minor)) = (number("major"), number("minor")) {
+        return Some(KubectlVersion { major, minor });
+    }
+    let git = client.get("gitVersion")?.as_str()?.trim_start_matches('v');
+    let mut parts = git.split('.');
+    Some(KubectlVersion {
+        major: parts.next()?.parse().ok()?,
+        minor: parts.next()?.parse().ok()?,
+    })
+}
+
+/// Cache the version found by the startup probe. Only the first call counts.
+pub fn set_kubectl_version(version: Option<KubectlVersion>) {
+    let _ = KUBECTL_VERSION.set(version);
+}
+
+/// The cached kubectl client version, asking kubectl on first use if the
+/// startup probe didn't run. `None` when kubectl is missing or its output
+/// can't be parsed.
+pub fn kubectl_version() -> Option<KubectlVersion> {
+    *KUBECTL_VERSION.get_or_init(|| {
+        let out = Command::new("kubectl")
+            .args(["version", "--client", "-o", "json"])
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        parse_kubectl_version(&String::from_utf8_lossy(&out.stdout))
+    })
+}
+
+/// `kubectl drain`'s flag for evicting pods with emptyDir volumes:
+/// `--delete-emptydir-data` since 1.20, `--delete-local-data` before.
+/// An unknown version gets the modern flag.
+pub fn drain_emptydir_flag(version: Option<KubectlVersion>) -> &'static str {
+    match version {
+        Some(v)
+            if v < (KubectlVersion {
+                major: 1,
+                minor: 20,
+            }) =>
+        {
+            "--delete-local-data"
+        }
+        _ => "--delete-emptydir-data",
+    }
 }
 
 // ─── kubectl command builder ──────────────────────────────────────────────────
@@ -83,32 +189,533 @@ pub fn current_preview_mode() -> u8 {
 /// belongs to a non-default cluster (multi-cluster mode).
 fn kubectl(item: &K8sItem) -> Command {
     let mut cmd = Command::new("kubectl");
-    if !item.context().is_empty() {
-        cmd.args(["--context", item.context()]);
-    }
+    cmd.args(context_args(item));
     cmd
 }
 
+/// `--context <ctx>` for items from a non-default cluster, otherwise nothing.
+fn context_args(item: &K8sItem) -> Vec<String> {
+    if item.context().is_empty() {
+        Vec::new()
+    } else {
+        vec!["--context".to_string(), item.context().to_string()]
+    }
+}
+
+// ─── kubectl argument builders ────────────────────────────────────────────────
+//
+// Each action assembles its arguments here rather than inline, so the exact
+// command can be shown without running it (alt-c). `--context` is added
+// separately by `kubectl()` / `command_line()`.
+
+/// `-n <ns>` for namespaced items, otherwise nothing.
+fn namespace_args(item: &K8sItem) -> Vec<String> {
+    if item.namespace().is_empty() {
+        Vec::new()
+    } else {
+        vec!["-n".to_string(), item.namespace().to_string()]
+    }
+}
+
+/// `<verb> <kind> -n <ns> -- <name>`, the shape shared by most read commands.
+fn by_name_args(verb: &str, extra: &[&str], item: &K8sItem) -> Vec<String> {
+    let mut args = vec![verb.to_string(), item.kind().as_str().to_string()];
+    args.extend(extra.iter().map(|a| (*a).to_string()));
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), item.name().to_string()]);
+    args
+}
+
+pub fn build_describe_args(item: &K8sItem) -> Vec<String> {
+    by_name_args("describe", &[], item)
+}
+
+pub fn build_yaml_args(item: &K8sItem) -> Vec<String> {
+    by_name_args("get", &["-o", "yaml"], item)
+}
+
+pub fn build_raw_watch_args(item: &K8sItem) -> Vec<String> {
+    by_name_args("get", &["-w"], item)
+}
+
+pub fn build_logs_args(item: &K8sItem) -> Vec<String> {
+    build_container_logs_args(item, &[])
+}
+
+/// [`build_logs_args`] with a container choice (`-c <name>` or
+/// `--all-containers`) from [`container_flags`].
+pub fn build_container_logs_args(item: &K8sItem, container: &[String]) -> Vec<String> {
+    let mut args = vec!["logs".to_string(), "--tail=200".to_string()];
+    args.extend(container.iter().cloned());
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), item.name().to_string()]);
+    args
+}
+
+/// Names of a pod's (non-init) containers, space-separated.
+pub fn pod_containers_args(item: &K8sItem) -> Vec<String> {
+    by_name_args("get", &["-o", "jsonpath={.spec.containers[*].name}"], item)
+}
+
+pub fn build_exec_args(item: &K8sItem, shell: &str) -> Vec<String> {
+    let mut args = vec![
+        "exec".to_string(),
+        "-it".to_string(),
+        item.name().to_string(),
+    ];
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), shell.to_string()]);
+    args
+}
+
+pub fn build_delete_args(item: &K8sItem) -> Vec<String> {
+    let mut args = vec!["delete".to_string(), item.kind().as_str().to_string()];
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), item.name().to_string()]);
+    args
+}
+
+/// `ports` is `<local>:<remote>`.
+pub fn build_portforward_args(item: &K8sItem, ports: &str) -> Vec<String> {
+    let mut args = vec![
+        "port-forward".to_string(),
+        format!("{}/{}", item.kind().as_str(), item.name()),
+        ports.to_string(),
+    ];
+    args.extend(namespace_args(item));
+    args
+}
+
+pub fn build_scale_args(item: &K8sItem, replicas: &str) -> Vec<String> {
+    let mut args = vec![
+        "scale".to_string(),
+        format!("{}/{}", item.kind().as_str(), item.name()),
+        format!("--replicas={replicas}"),
+    ];
+    args.extend(namespace_args(item));
+    args
+}
+
+/// Quote `arg` for a POSIX shell, leaving plain words untouched.
+fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// The full, copy-pasteable `kubectl …` line for `args` run against `item`.
+pub fn command_line(item: &K8sItem, args: &[String]) -> String {
+    std::iter::once("kubectl".to_string())
+        .chain(context_args(item))
+        .chain(args.iter().cloned())
+        .map(|a| shell_quote(&a))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ─── Progress spinner ─────────────────────────────────────────────────────────
+
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const SPINNER_TICK: Duration = Duration::from_millis(100);
+
+/// Run `f` (typically a blocking wait on a kubectl child) while a spinner and
+/// elapsed time tick on stderr, then clear the spinner line and return `f`'s
+/// result. Nothing is drawn when stderr is not a terminal.
+pub fn with_spinner<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    if !io::stderr().is_terminal() {
+        return f();
+    }
+    let (done_tx, done_rx) = mpsc::channel::<()>();
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            let start = Instant::now();
+            let mut stderr = io::stderr();
+            for frame in SPINNER_FRAMES.iter().cycle() {
+                let elapsed = start.elapsed().as_secs();
+                let _ = write!(stderr, "\r{frame} {label} ({elapsed}s)");
+                let _ = stderr.flush();
+                match done_rx.recv_timeout(SPINNER_TICK) {
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    _ => break,
+                }
+            }
+            // Clear the spinner line so following output starts clean.
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        });
+        let result = f();
+        drop(done_tx);
+        result
+    })
+}
+
+// ─── Pause after output ───────────────────────────────────────────────────────
+
+/// Whether to hold the screen after the action bound to `key` ran, so its
+/// output isn't immediately replaced by the list. Only the actions that print
+/// something to read (describe, yaml, logs, show-cmds, drift, drain,
+/// delete-all-matching, the f1 key list) pause, and only on a terminal
+/// (`tty`) without `--no-pause`.
+pub fn should_pause(key: KeyEvent, tty: bool, no_pause: bool) -> bool {
+    if no_pause || !tty {
+        return false;
+    }
+    match (key.code, key.modifiers) {
+        (KeyCode::Enter | KeyCode::F(1), _) => true,
+        (KeyCode::Char(c), KeyModifiers::CONTROL) => matches!(c, 'y' | 'l'),
+        (KeyCode::Char(c), KeyModifiers::ALT) => matches!(c, 'l' | 'c' | 'v' | 'e' | 'a'),
+        _ => false,
+    }
+}
+
+/// Block until any key is pressed.
+pub fn wait_for_key() -> Result<()> {
+    eprint!("\n[kuberift] press any key to continue…");
+    io::stderr().flush()?;
+    crossterm::terminal::enable_raw_mode()?;
+    let pressed = loop {
+        match crossterm::event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => break Ok(()),
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    crossterm::terminal::disable_raw_mode()?;
+    eprintln!();
+    pressed.map_err(Into::into)
+}
+
+// ─── Bulk action tally ────────────────────────────────────────────────────────
+
+/// Per-item success/failure tally for bulk actions, printed as a final summary
+/// line so the user doesn't have to count checkmarks after a large selection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionOutcome {
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+impl ActionOutcome {
+    /// Record the result of one item.
+    pub fn record(&mut self, ok: bool) {
+        if ok {
+            self.succeeded += 1;
+        } else {
+            self.failed += 1;
+        }
+    }
+
+    /// Number of items attempted (skipped items are not recorded).
+    pub fn total(self) -> usize {
+        self.succeeded + self.failed
+    }
+
+    /// Summary line, e.g. `Deleted 18/20 (2 failed)`.
+    pub fn summary(self, verb: &str) -> String {
+        let base = format!("{verb} {}/{}", self.succeeded, self.total());
+        if self.failed == 0 {
+            base
+        } else {
+            format!("{base} ({} failed)", self.failed)
+        }
+    }
+
+    /// Print the summary line, unless nothing was attempted.
+    pub fn print_summary(self, verb: &str) {
+        if self.total() > 0 {
+            println!("{}", self.summary(verb));
+        }
+    }
+}
+
 // ─── Logs ─────────────────────────────────────────────────────────────────────
 
 pub fn action_logs(items: &[&K8sItem]) -> Result<()> {
     for item in items {
-        if !matches!(item.kind(), ResourceKind::Pod) {
+        match item.kind() {
+            ResourceKind::Pod => {}
+            ResourceKind::Job | ResourceKind::CronJob => {
+                action_job_logs(item)?;
+                continue;
+            }
+            kind => {
+                eprintln!(
+                    "[kuberift] logs only available for pods, jobs and cronjobs (got {})",
+                    kind.as_str()
+                );
+                continue;
+            }
+        }
+        let containers = pod_containers(item);
+        let container = if containers.len() > 1 {
+            match ask_container(item, &containers)? {
+                Some(flags) => flags,
+                None => continue,
+            }
+        } else {
+            Vec::new()
+        };
+        println!("\n─── logs: {}/{} ───", item.namespace(), item.name());
+        let status = kubectl(item)
+            .args(build_container_logs_args(item, &container))
+            .status()?;
+        if !status.success() {
+            eprintln!("[kuberift] kubectl logs exited with {status}");
+        }
+    }
+    Ok(())
+}
+
+/// Container names from [`pod_containers_args`] output.
+pub fn parse_container_names(output: &str) -> Vec<String> {
+    output.split_whitespace().map(str::to_string).collect()
+}
+
+/// The pod's containers, or none when kubectl can't tell (logs then run
+/// without `-c`, as before).
+fn pod_containers(item: &K8sItem) -> Vec<String> {
+    match kubectl(item).args(pod_containers_args(item)).output() {
+        Ok(out) if out.status.success() => {
+            parse_container_names(&String::from_utf8_lossy(&out.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// `kubectl logs` flags for a container picked at the prompt: a 1-based
+/// number or a name selects one container, an empty answer all of them.
+pub fn container_flags(input: &str, containers: &[String]) -> Result<Vec<String>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(vec!["--all-containers".to_string()]);
+    }
+    let by_number = input
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .and_then(|i| containers.get(i));
+    by_number
+        .or_else(|| containers.iter().find(|c| *c == input))
+        .map(|name| vec!["-c".to_string(), name.clone()])
+        .ok_or_else(|| format!("'{input}' is not one of the pod's containers"))
+}
+
+/// Ask which of a multi-container pod's containers to show. `None` when the
+/// answer matches no container.
+fn ask_container(item: &K8sItem, containers: &[String]) -> Result<Option<Vec<String>>> {
+    println!("\n{} has {} containers:", item.name(), containers.len());
+    for (i, name) in containers.iter().enumerate() {
+        println!("  {}) {name}", i + 1);
+    }
+    print!("Container (number or name, Enter for all): ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    match container_flags(&input, containers) {
+        Ok(flags) => Ok(Some(flags)),
+        Err(e) => {
+            eprintln!("[kuberift] {e}");
+            Ok(None)
+        }
+    }
+}
+
+// ─── Job / CronJob logs ───────────────────────────────────────────────────────
+
+/// Pods of one job streamed at once by `kubectl logs -l` (its default is 5).
+const MAX_JOB_LOG_STREAMS: usize = 20;
+
+/// Label selector matching the pods the Job controller created for `job`.
+pub fn job_pod_selector(job: &str) -> String {
+    format!("job-name={job}")
+}
+
+/// `kubectl get pods -l job-name=<job> -o name` in the item's namespace.
+pub fn job_pods_args(item: &K8sItem, job: &str) -> Vec<String> {
+    let mut args = vec![
+        "get".to_string(),
+        "pods".to_string(),
+        "-l".to_string(),
+        job_pod_selector(job),
+        "-o".to_string(),
+        "name".to_string(),
+    ];
+    args.extend(namespace_args(item));
+    args
+}
+
+/// Pod names from `kubectl get pods -o name` output (`pod/<name>` lines).
+pub fn parse_pod_names(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.strip_prefix("pod/").unwrap_or(line).to_string())
+        .collect()
+}
+
+/// `kubectl get jobs -o json` in the item's namespace, to find a
+/// `CronJob`'s jobs by their owner reference.
+pub fn cronjob_jobs_args(item: &K8sItem) -> Vec<String> {
+    let mut args = vec![
+        "get".to_string(),
+        "jobs".to_string(),
+        "-o".to_string(),
+        "json".to_string(),
+    ];
+    args.extend(namespace_args(item));
+    args
+}
+
+/// The most recently created job owned by `cronjob` in a
+/// `kubectl get jobs -o json` list, if it has created any.
+pub fn latest_cronjob_job(jobs_json: &str, cronjob: &str) -> Result<Option<String>> {
+    let list: Value = serde_json::from_str(jobs_json)?;
+    let owned_by_cronjob = |job: &Value| {
+        job.pointer("/metadata/ownerReferences")
+            .and_then(Value::as_array)
+            .is_some_and(|owners| {
+                owners.iter().any(|o| {
+                    o.get("kind").and_then(Value::as_str) == Some("CronJob")
+                        && o.get("name").and_then(Value::as_str) == Some(cronjob)
+                })
+            })
+    };
+    // RFC 3339 timestamps in UTC sort chronologically as strings.
+    Ok(list
+        .get("items")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|job| owned_by_cronjob(job))
+        .filter_map(|job| {
+            let created = job.pointer("/metadata/creationTimestamp")?.as_str()?;
+            let name = job.pointer("/metadata/name")?.as_str()?;
+            Some((created, name))
+        })
+        .max()
+        .map(|(_, name)| name.to_string()))
+}
+
+/// `kubectl logs -l job-name=<job> --prefix -f`: every pod of the job
+/// combined, each line prefixed with its pod and container.
+pub fn job_logs_args(item: &K8sItem, job: &str) -> Vec<String> {
+    let mut args = vec![
+        "logs".to_string(),
+        "-l".to_string(),
+        job_pod_selector(job),
+        "--prefix".to_string(),
+        "--all-containers".to_string(),
+        "--tail=200".to_string(),
+        "-f".to_string(),
+        format!("--max-log-requests={MAX_JOB_LOG_STREAMS}"),
+    ];
+    args.extend(namespace_args(item));
+    args
+}
+
+/// Logs of a Job's pods, or of the pods of a `CronJob`'s most recent job,
+/// followed until Ctrl-C, which returns to the list.
+fn action_job_logs(item: &K8sItem) -> Result<()> {
+    let job = if matches!(item.kind(), ResourceKind::CronJob) {
+        let out = kubectl(item).args(cronjob_jobs_args(item)).output()?;
+        if !out.status.success() {
             eprintln!(
-                "[kuberift] logs only available for pods (got {})",
-                item.kind().as_str()
+                "[kuberift] cannot list jobs of cronjob {}: {}",
+                item.name(),
+                String::from_utf8_lossy(&out.stderr).trim()
             );
-            continue;
+            return Ok(());
         }
-        println!("\n─── logs: {}/{} ───", item.namespace(), item.name());
-        let mut args = vec!["logs", "--tail=200"];
+        let jobs = String::from_utf8_lossy(&out.stdout);
+        let Some(job) = latest_cronjob_job(&jobs, item.name()).ok().flatten() else {
+            eprintln!(
+                "[kuberift] cronjob {} has not run any jobs yet",
+                item.name()
+            );
+            return Ok(());
+        };
+        job
+    } else {
+        item.name().to_string()
+    };
+
+    let out = kubectl(item).args(job_pods_args(item, &job)).output()?;
+    let pods = parse_pod_names(&String::from_utf8_lossy(&out.stdout));
+    if !out.status.success() || pods.is_empty() {
+        eprintln!("[kuberift] job {job} has no pods (they may have been cleaned up)");
+        return Ok(());
+    }
+    println!(
+        "\n─── logs: {}/{job} ({} pod{}, Ctrl-C to stop) ───",
+        item.namespace(),
+        pods.len(),
+        if pods.len() == 1 { "" } else { "s" }
+    );
+    let status = run_until_interrupted(kubectl(item).args(job_logs_args(item, &job)))?;
+    if !status.success() {
+        eprintln!("[kuberift] kubectl logs exited with {status}");
+    }
+    Ok(())
+}
+
+// ─── Crash logs (previous + current) ──────────────────────────────────────────
+
+/// kubectl invocations for the crash-aware logs action: an unhealthy pod with
+/// a restarted container (e.g. `CrashLoopBackOff`, or `Running (restarted 2m
+/// ago)`) gets its previous container's logs first, followed by the current
+/// ones. Anything else just gets the current logs — a pod that never
+/// restarted (e.g. `ImagePullBackOff`) has no previous container to read.
+pub fn smart_logs_args(item: &K8sItem) -> Vec<Vec<String>> {
+    let logs = |previous: bool| {
+        let mut args = vec!["logs".to_string()];
+        if previous {
+            args.push("--previous".to_string());
+        }
+        args.push("--tail=200".to_string());
         if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
+            args.extend(["-n".to_string(), item.namespace().to_string()]);
         }
-        args.extend_from_slice(&["--", item.name()]);
+        args.extend(["--".to_string(), item.name().to_string()]);
+        args
+    };
+    let unhealthy = StatusHealth::classify(&item.status()) != StatusHealth::Healthy;
+    if unhealthy && item.crashed_at().is_some() {
+        vec![logs(true), logs(false)]
+    } else {
+        vec![logs(false)]
+    }
+}
+
+pub fn action_logs_smart(item: &K8sItem) -> Result<()> {
+    if !matches!(item.kind(), ResourceKind::Pod) {
+        eprintln!(
+            "[kuberift] crash logs only available for pods (got {})",
+            item.kind().as_str()
+        );
+        return Ok(());
+    }
+    for args in smart_logs_args(item) {
+        let previous = args.iter().any(|a| a == "--previous");
+        let which = if previous { "previous" } else { "current" };
+        println!(
+            "\n─── {which} logs: {}/{} ───",
+            item.namespace(),
+            item.name()
+        );
         let status = kubectl(item).args(&args).status()?;
         if !status.success() {
-            eprintln!("[kuberift] kubectl logs exited with {status}");
+            if previous {
+                eprintln!("[kuberift] no previous container logs (has the pod restarted?)");
+            } else {
+                eprintln!("[kuberift] kubectl logs exited with {status}");
+            }
         }
     }
     Ok(())
@@ -123,12 +730,7 @@ pub fn action_exec(item: &K8sItem) -> Result<()> {
     }
     println!("Dropping into shell: {}/{}", item.namespace(), item.name());
     for shell in &["/bin/sh", "/bin/bash"] {
-        let mut args = vec!["exec", "-it", item.name()];
-        if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
-        }
-        args.extend_from_slice(&["--", shell]);
-        let status = kubectl(item).args(&args).status()?;
+        let status = kubectl(item).args(build_exec_args(item, shell)).status()?;
         if status.success() {
             return Ok(());
         }
@@ -137,9 +739,74 @@ pub fn action_exec(item: &K8sItem) -> Result<()> {
     Ok(())
 }
 
+// ─── Foreground kubectl (Ctrl-C back to the list) ─────────────────────────────
+
+/// Set while SIGINT should do what it normally does and end kf; `None` when
+/// the handler couldn't be installed.
+#[cfg(unix)]
+static SIGINT_DEFAULT: OnceLock<Option<std::sync::Arc<std::sync::atomic::AtomicBool>>> =
+    OnceLock::new();
+
+/// Run `cmd` until it exits or Ctrl-C stops it. Ctrl-C reaches the whole
+/// foreground process group, so kf lets it pass while `cmd` runs and returns
+/// to the list instead of exiting along with kubectl.
+#[cfg(unix)]
+fn run_until_interrupted(cmd: &mut Command) -> io::Result<std::process::ExitStatus> {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    let default = SIGINT_DEFAULT.get_or_init(|| {
+        let flag = Arc::new(AtomicBool::new(true));
+        signal_hook::flag::register_conditional_default(
+            signal_hook::consts::SIGINT,
+            Arc::clone(&flag),
+        )
+        .ok()
+        .map(|_| flag)
+    });
+    if let Some(flag) = default {
+        flag.store(false, Ordering::SeqCst);
+    }
+    let status = cmd.status();
+    if let Some(flag) = default {
+        flag.store(true, Ordering::SeqCst);
+    }
+    status
+}
+
+#[cfg(not(unix))]
+fn run_until_interrupted(cmd: &mut Command) -> io::Result<std::process::ExitStatus> {
+    cmd.status()
+}
+
+// ─── Raw watch ────────────────────────────────────────────────────────────────
+
+/// Hand the terminal to `kubectl get -w` for one resource, streaming kubectl's
+/// own output until Ctrl-C, which returns to the list.
+pub fn action_raw_watch(item: &K8sItem) -> Result<()> {
+    println!(
+        "Watching {}/{} with kubectl  (Ctrl-C to stop)",
+        item.kind().as_str(),
+        item.name()
+    );
+    let status = run_until_interrupted(kubectl(item).args(build_raw_watch_args(item)))?;
+    if !status.success() {
+        eprintln!("[kuberift] kubectl get -w exited with {status}");
+    }
+    Ok(())
+}
+
 // ─── Delete ───────────────────────────────────────────────────────────────────
 
-pub fn action_delete(items: &[&K8sItem]) -> Result<()> {
+/// Default for `--bulk-delete-threshold`.
+pub const DEFAULT_BULK_DELETE_THRESHOLD: usize = 10;
+
+/// Whether deleting `count` resources needs the strict "type 'yes'" prompt
+/// rather than `[y/N]`. A threshold of 0 makes every delete strict.
+pub fn needs_strict_confirm(count: usize, threshold: usize) -> bool {
+    count > threshold
+}
+
+pub fn action_delete(items: &[&K8sItem], bulk_threshold: usize) -> Result<()> {
     let count = items.len();
     let noun = if count == 1 { "resource" } else { "resources" };
 
@@ -164,7 +831,7 @@ pub fn action_delete(items: &[&K8sItem]) -> Result<()> {
         );
     }
 
-    if count > 10 {
+    if needs_strict_confirm(count, bulk_threshold) {
         eprintln!("[kuberift] ⚠ WARNING: You are about to delete {count} resources.");
         print!("Type 'yes' (not just 'y') to confirm bulk delete: ");
         io::stdout().flush()?;
@@ -185,13 +852,10 @@ pub fn action_delete(items: &[&K8sItem]) -> Result<()> {
         }
     }
 
+    let mut outcome = ActionOutcome::default();
     for item in items {
-        let mut args = vec!["delete", item.kind().as_str()];
-        if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
-        }
-        args.extend_from_slice(&["--", item.name()]);
-        let out = kubectl(item).args(&args).output()?;
+        let out = kubectl(item).args(build_delete_args(item)).output()?;
+        outcome.record(out.status.success());
         if out.status.success() {
             println!("✓ deleted {}/{}", item.kind().as_str(), item.name());
         } else {
@@ -203,9 +867,342 @@ pub fn action_delete(items: &[&K8sItem]) -> Result<()> {
             );
         }
     }
+    outcome.print_summary("Deleted");
+    Ok(())
+}
+
+// ─── Force delete (alt-x) ─────────────────────────────────────────────────────
+
+/// Whether `item` may be force-deleted: only pods stuck in `Terminating`.
+pub fn force_deletable(item: &K8sItem) -> bool {
+    matches!(item.kind(), ResourceKind::Pod) && item.status().starts_with("Terminating")
+}
+
+/// `kubectl delete pod --grace-period=0 --force`, which removes the pod from
+/// the API without waiting for the kubelet to confirm it stopped.
+pub fn build_force_delete_args(item: &K8sItem) -> Vec<String> {
+    let mut args = vec![
+        "delete".to_string(),
+        item.kind().as_str().to_string(),
+        "--grace-period=0".to_string(),
+        "--force".to_string(),
+    ];
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), item.name().to_string()]);
+    args
+}
+
+/// Force-delete pods stuck in `Terminating`, each confirmed by typing its
+/// name. Anything else in the selection is refused with a warning.
+pub fn action_force_delete(items: &[&K8sItem]) -> Result<()> {
+    let stuck: Vec<&&K8sItem> = items
+        .iter()
+        .filter(|item| {
+            if force_deletable(item) {
+                true
+            } else {
+                eprintln!(
+                    "[kuberift] force delete is only for pods stuck in Terminating ({}/{} is {})",
+                    item.kind().as_str(),
+                    item.name(),
+                    item.status()
+                );
+                false
+            }
+        })
+        .collect();
+
+    if stuck.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!(
+        "[kuberift] ⚠ WARNING: force delete does not wait for the containers to stop. \
+         If the node is still running them, a replacement (e.g. a StatefulSet pod with \
+         the same name and volumes) can run alongside them."
+    );
+    let mut outcome = ActionOutcome::default();
+    for item in stuck {
+        print!("Type the pod name to force-delete {}: ", item.name());
+        io::stdout().flush()?;
+        let mut confirm = String::new();
+        io::stdin().read_line(&mut confirm)?;
+        if confirm.trim() != item.name() {
+            println!("Skipped {}.", item.name());
+            continue;
+        }
+        let out = kubectl(item).args(build_force_delete_args(item)).output()?;
+        outcome.record(out.status.success());
+        if out.status.success() {
+            println!("✓ force-deleted pod/{}", item.name());
+        } else {
+            eprintln!(
+                "✗ force delete failed pod/{}: {}",
+                item.name(),
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+        }
+    }
+    outcome.print_summary("Force-deleted");
+    Ok(())
+}
+
+// ─── Restart a pod's containers (alt-z) ───────────────────────────────────────
+
+/// What restarting a pod's containers amounts to. Kubernetes can't restart
+/// a single container, so the pod is deleted and its controller recreates it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PodRestart {
+    /// Delete the pod; its controller (`Kind/name`) starts a replacement.
+    Recreate { owner: String },
+    /// A bare pod: deleting it would not bring it back.
+    Standalone,
+    /// Not a pod.
+    NotAPod,
+}
+
+/// How `item` would be restarted, judged from its ownerReferences: only a
+/// controlling owner recreates a deleted pod.
+pub fn pod_restart_plan(item: &K8sItem) -> PodRestart {
+    if !matches!(item.kind(), ResourceKind::Pod) {
+        return PodRestart::NotAPod;
+    }
+    if item.controlled() {
+        PodRestart::Recreate {
+            owner: item.owner(),
+        }
+    } else {
+        PodRestart::Standalone
+    }
+}
+
+/// "Kick" a pod's containers: a managed pod is deleted after a `y/N`
+/// confirmation so its controller recreates it; a standalone pod is left
+/// alone with a warning, since nothing would recreate it.
+pub fn action_restart_container(item: &K8sItem) -> Result<()> {
+    let owner = match pod_restart_plan(item) {
+        PodRestart::Recreate { owner } => owner,
+        PodRestart::Standalone => {
+            eprintln!(
+                "[kuberift] pod/{} has no controller — deleting it would not recreate it \
+                 (use ctrl-d to delete it anyway)",
+                item.name()
+            );
+            return Ok(());
+        }
+        PodRestart::NotAPod => {
+            eprintln!(
+                "[kuberift] restart only works with pods (got {}); use ctrl-r for workloads",
+                item.kind().as_str()
+            );
+            return Ok(());
+        }
+    };
+
+    print!("Delete pod/{} so {owner} recreates it? [y/N] ", item.name());
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    if !input.trim().eq_ignore_ascii_case("y") {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    let out = kubectl(item).args(build_delete_args(item)).output()?;
+    if out.status.success() {
+        println!("↺ deleted pod/{} — {owner} will recreate it", item.name());
+    } else {
+        eprintln!(
+            "✗ delete failed pod/{}: {}",
+            item.name(),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+// ─── Delete by selector (alt-a / --delete-selector) ──────────────────────────
+
+/// Everything one `kubectl delete <kind> -l … --field-selector …` removes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorDelete {
+    /// kubectl resource name, e.g. `pod`.
+    pub kind: String,
+    /// Empty for cluster-scoped kinds.
+    pub namespace: String,
+    /// Empty for kubectl's current context.
+    pub context: String,
+    pub label: Option<String>,
+    pub field: Option<String>,
+    /// `--kubeconfig` file the context is read from; `None` for the default.
+    pub kubeconfig: Option<String>,
+}
+
+impl SelectorDelete {
+    /// Resources of `item`'s kind, namespace and context matching `label`.
+    pub fn like(item: &K8sItem, label: &str) -> Self {
+        Self {
+            kind: item.kind().as_str().to_string(),
+            namespace: item.namespace().to_string(),
+            context: item.context().to_string(),
+            label: Some(label.to_string()),
+            field: None,
+            kubeconfig: None,
+        }
+    }
+
+    /// `pod -l app=web --field-selector status.phase=Failed -n prod`, as
+    /// shown in the confirmation prompt.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![self.kind.clone()];
+        if let Some(label) = &self.label {
+            parts.push(format!("-l {label}"));
+        }
+        if let Some(field) = &self.field {
+            parts.push(format!("--field-selector {field}"));
+        }
+        if !self.namespace.is_empty() {
+            parts.push(format!("-n {}", self.namespace));
+        }
+        parts.join(" ")
+    }
+}
+
+/// `kubectl delete` of everything `target` matches. With `dry_run` nothing
+/// is deleted: the names that would be are printed one per line.
+pub fn build_selector_delete_args(target: &SelectorDelete, dry_run: bool) -> Vec<String> {
+    let mut args = vec!["delete".to_string(), target.kind.clone()];
+    if let Some(label) = &target.label {
+        args.extend(["-l".to_string(), label.clone()]);
+    }
+    if let Some(field) = &target.field {
+        args.extend(["--field-selector".to_string(), field.clone()]);
+    }
+    if !target.namespace.is_empty() {
+        args.extend(["-n".to_string(), target.namespace.clone()]);
+    }
+    if !target.context.is_empty() {
+        args.extend(["--context".to_string(), target.context.clone()]);
+    }
+    if let Some(kubeconfig) = &target.kubeconfig {
+        args.extend(["--kubeconfig".to_string(), kubeconfig.clone()]);
+    }
+    if dry_run {
+        args.extend([
+            "--dry-run=client".to_string(),
+            "-o".to_string(),
+            "name".to_string(),
+        ]);
+    }
+    args
+}
+
+/// Resources a dry-run delete (`-o name`) would remove: one `kind/name`
+/// line each.
+pub fn parse_dry_run_count(output: &str) -> usize {
+    output.lines().filter(|l| !l.trim().is_empty()).count()
+}
+
+/// Delete everything `target` matches in one `kubectl delete`: count it with
+/// a dry run first, then ask for the strict "type 'yes'" confirmation
+/// whatever the count.
+pub fn action_delete_matching(target: &SelectorDelete) -> Result<()> {
+    let out = with_spinner("counting matches", || {
+        Command::new("kubectl")
+            .args(build_selector_delete_args(target, true))
+            .output()
+    })?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "dry run failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    let count = parse_dry_run_count(&String::from_utf8_lossy(&out.stdout));
+    if count == 0 {
+        println!("Nothing matches {}.", target.describe());
+        return Ok(());
+    }
+
+    eprintln!(
+        "[kuberift] ⚠ WARNING: You are about to delete {count} resources matching {}.",
+        target.describe()
+    );
+    print!("Type 'yes' (not just 'y') to confirm delete of {count}: ");
+    io::stdout().flush()?;
+    let mut confirm = String::new();
+    io::stdin().read_line(&mut confirm)?;
+    if confirm.trim() != "yes" {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    let status = Command::new("kubectl")
+        .args(build_selector_delete_args(target, false))
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("kubectl delete {} failed", target.describe());
+    }
+    Ok(())
+}
+
+// ─── Apply a manifest (alt-i) ─────────────────────────────────────────────────
+
+/// `kubectl apply -f <path>`, into `namespace` (for objects that don't name
+/// their own) and `context` when given.
+pub fn build_apply_args(path: &Path, context: &str, namespace: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "apply".to_string(),
+        "-f".to_string(),
+        path.display().to_string(),
+    ];
+    if let Some(ns) = namespace {
+        args.extend(["-n".to_string(), ns.to_string()]);
+    }
+    if !context.is_empty() {
+        args.extend(["--context".to_string(), context.to_string()]);
+    }
+    args
+}
+
+/// Expand a leading `~/` in a typed path to the home directory.
+fn expand_home(input: &str) -> PathBuf {
+    match (input.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(input),
+    }
+}
+
+/// Apply the manifest at `path`, showing kubectl's output. Fails without
+/// running kubectl when `path` is not a file.
+pub fn action_apply(path: &Path, context: &str, namespace: Option<&str>) -> Result<()> {
+    if !path.is_file() {
+        anyhow::bail!("no manifest at {}", path.display());
+    }
+    let status = Command::new("kubectl")
+        .args(build_apply_args(path, context, namespace))
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("kubectl apply -f {} failed", path.display());
+    }
     Ok(())
 }
 
+/// Ask for a manifest path and apply it (see [`action_apply`]). An empty
+/// answer cancels.
+pub fn action_apply_prompt(context: &str, namespace: Option<&str>) -> Result<()> {
+    print!("Manifest to apply: ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    if input.is_empty() {
+        println!("Cancelled.");
+        return Ok(());
+    }
+    action_apply(&expand_home(input), context, namespace)
+}
+
 // ─── Port-forward ─────────────────────────────────────────────────────────────
 
 fn read_port(prompt: &str, default: Option<u16>) -> Result<Option<u16>> {
@@ -247,16 +1244,8 @@ pub fn action_portforward(item: &K8sItem) -> Result<()> {
     };
     let remote = read_port("Remote port", Some(local))?.unwrap_or(local);
 
-    let local = local.to_string();
-    let remote = remote.to_string();
-
     let target = format!("{}/{}", item.kind().as_str(), item.name());
-    let ports = format!("{local}:{remote}");
-
-    let mut args = vec!["port-forward", &target, &ports];
-    if !item.namespace().is_empty() {
-        args.extend_from_slice(&["-n", item.namespace()]);
-    }
+    let args = build_portforward_args(item, &format!("{local}:{remote}"));
 
     println!("Forwarding localhost:{local} → {target} port {remote}  (Ctrl-C to stop)");
     let status = kubectl(item).args(&args).status()?;
@@ -275,6 +1264,7 @@ pub fn action_rollout_restart(items: &[&K8sItem]) -> Result<()> {
         ResourceKind::DaemonSet,
     ];
 
+    let mut outcome = ActionOutcome::default();
     for item in items {
         if !RESTARTABLE.contains(item.kind()) {
             eprintln!(
@@ -285,26 +1275,178 @@ pub fn action_rollout_restart(items: &[&K8sItem]) -> Result<()> {
         }
 
         let target = format!("{}/{}", item.kind().as_str(), item.name());
-        let mut restart_args = vec!["rollout", "restart", &target];
-        if !item.namespace().is_empty() {
-            restart_args.extend_from_slice(&["-n", item.namespace()]);
+        let out = kubectl(item).args(rollout_args(item, "restart")).output()?;
+        outcome.record(out.status.success());
+        if out.status.success() {
+            println!("↺ restarting {target}");
+            // Captured rather than inherited so the spinner and kubectl's
+            // progress lines don't interleave on the terminal.
+            let status_out = with_spinner(&format!("waiting for {target}"), || {
+                kubectl(item).args(rollout_args(item, "status")).output()
+            })?;
+            io::stdout().write_all(&status_out.stdout)?;
+            io::stderr().write_all(&status_out.stderr)?;
+        } else {
+            eprintln!(
+                "✗ rollout restart failed: {}",
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
         }
+    }
+    outcome.print_summary("Restarted");
+    Ok(())
+}
+
+// ─── Rollout pause / resume ───────────────────────────────────────────────────
+
+/// `kubectl rollout <verb> deploy/<name> -n <ns>` arguments.
+pub fn rollout_args(item: &K8sItem, verb: &str) -> Vec<String> {
+    let mut args = vec![
+        "rollout".to_string(),
+        verb.to_string(),
+        format!("{}/{}", item.kind().as_str(), item.name()),
+    ];
+    if !item.namespace().is_empty() {
+        args.extend(["-n".to_string(), item.namespace().to_string()]);
+    }
+    args
+}
 
-        let out = kubectl(item).args(&restart_args).output()?;
+/// Pause or resume rollouts; only Deployments support either.
+fn rollout_pause_resume(items: &[&K8sItem], verb: &str, done: &str) -> Result<()> {
+    let mut outcome = ActionOutcome::default();
+    for item in items {
+        if !matches!(item.kind(), ResourceKind::Deployment) {
+            eprintln!(
+                "[kuberift] rollout {verb} only works with deployments (got {})",
+                item.kind().as_str()
+            );
+            continue;
+        }
+        let out = kubectl(item).args(rollout_args(item, verb)).output()?;
+        outcome.record(out.status.success());
         if out.status.success() {
-            println!("↺ restarting {target}");
-            let mut status_args = vec!["rollout", "status", &target];
-            if !item.namespace().is_empty() {
-                status_args.extend_from_slice(&["-n", item.namespace()]);
+            println!("✓ {verb}d deploy/{}", item.name());
+        } else {
+            eprintln!(
+                "✗ rollout {verb} failed: {}",
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+        }
+    }
+    outcome.print_summary(done);
+    Ok(())
+}
+
+pub fn action_rollout_pause(items: &[&K8sItem]) -> Result<()> {
+    rollout_pause_resume(items, "pause", "Paused")
+}
+
+pub fn action_rollout_resume(items: &[&K8sItem]) -> Result<()> {
+    rollout_pause_resume(items, "resume", "Resumed")
+}
+
+// ─── Node maintenance (alt-o / alt-u / alt-e) ─────────────────────────────────
+
+/// `kubectl cordon|uncordon -- <node>`.
+pub fn node_args(item: &K8sItem, verb: &str) -> Vec<String> {
+    vec![verb.to_string(), "--".to_string(), item.name().to_string()]
+}
+
+/// `kubectl drain`, evicting everything but `DaemonSet` pods; pods with
+/// emptyDir volumes lose that data. The emptyDir flag follows the installed
+/// kubectl's version.
+pub fn build_drain_args(item: &K8sItem) -> Vec<String> {
+    vec![
+        "drain".to_string(),
+        "--ignore-daemonsets".to_string(),
+        drain_emptydir_flag(kubectl_version()).to_string(),
+        "--".to_string(),
+        item.name().to_string(),
+    ]
+}
+
+/// The nodes in `items`; anything else is skipped with a message.
+fn nodes_only<'a>(items: &[&'a K8sItem], verb: &str) -> Vec<&'a K8sItem> {
+    items
+        .iter()
+        .copied()
+        .filter(|item| {
+            if matches!(item.kind(), ResourceKind::Node) {
+                true
+            } else {
+                eprintln!(
+                    "[kuberift] {verb} only works with nodes (skipping {}/{})",
+                    item.kind().as_str(),
+                    item.name()
+                );
+                false
             }
-            kubectl(item).args(&status_args).status()?;
+        })
+        .collect()
+}
+
+/// Cordon or uncordon every selected node.
+fn node_schedulable(items: &[&K8sItem], verb: &str, done: &str) -> Result<()> {
+    let mut outcome = ActionOutcome::default();
+    for item in nodes_only(items, verb) {
+        let out = kubectl(item).args(node_args(item, verb)).output()?;
+        outcome.record(out.status.success());
+        if out.status.success() {
+            println!("✓ {verb}ed node/{}", item.name());
         } else {
             eprintln!(
-                "✗ rollout restart failed: {}",
+                "✗ {verb} failed node/{}: {}",
+                item.name(),
                 String::from_utf8_lossy(&out.stderr).trim()
             );
         }
     }
+    outcome.print_summary(done);
+    Ok(())
+}
+
+pub fn action_cordon(items: &[&K8sItem]) -> Result<()> {
+    node_schedulable(items, "cordon", "Cordoned")
+}
+
+pub fn action_uncordon(items: &[&K8sItem]) -> Result<()> {
+    node_schedulable(items, "uncordon", "Uncordoned")
+}
+
+/// Drain the selected nodes after a `[y/N]` confirmation. kubectl's own
+/// eviction progress is streamed.
+pub fn action_drain(items: &[&K8sItem]) -> Result<()> {
+    let nodes = nodes_only(items, "drain");
+    if nodes.is_empty() {
+        return Ok(());
+    }
+    for item in &nodes {
+        println!("  • node/{} — {}", item.name(), item.status());
+    }
+    let noun = if nodes.len() == 1 { "node" } else { "nodes" };
+    print!(
+        "\nDrain {} {noun}? Pods are evicted and emptyDir data is lost. [y/N] ",
+        nodes.len()
+    );
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    if !input.trim().eq_ignore_ascii_case("y") {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    let mut outcome = ActionOutcome::default();
+    for item in nodes {
+        println!("\n─── drain: node/{} ───", item.name());
+        let status = kubectl(item).args(build_drain_args(item)).status()?;
+        outcome.record(status.success());
+        if !status.success() {
+            eprintln!("[kuberift] kubectl drain exited with {status}");
+        }
+    }
+    outcome.print_summary("Drained");
     Ok(())
 }
 
@@ -369,14 +1511,13 @@ pub fn action_scale(items: &[&K8sItem]) -> Result<()> {
         return Ok(());
     }
 
-    let replicas_str = format!("--replicas={replicas}");
+    let mut outcome = ActionOutcome::default();
     for item in &scalable {
         let target = format!("{}/{}", item.kind().as_str(), item.name());
-        let mut args = vec!["scale", &target, &replicas_str];
-        if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
-        }
-        let out = kubectl(item).args(&args).output()?;
+        let out = kubectl(item)
+            .args(build_scale_args(item, &replicas.to_string()))
+            .output()?;
+        outcome.record(out.status.success());
         if out.status.success() {
             println!("✓ scaled {target} to {replicas} replicas");
         } else {
@@ -386,6 +1527,7 @@ pub fn action_scale(items: &[&K8sItem]) -> Result<()> {
             );
         }
     }
+    outcome.print_summary("Scaled");
     Ok(())
 }
 
@@ -406,12 +1548,7 @@ pub fn action_edit(items: &[&K8sItem], editor_override: &str) -> Result<()> {
         }
 
         // 1. Fetch current YAML
-        let mut get_args = vec!["get", item.kind().as_str(), "-o", "yaml"];
-        if !item.namespace().is_empty() {
-            get_args.extend_from_slice(&["-n", item.namespace()]);
-        }
-        get_args.extend_from_slice(&["--", item.name()]);
-        let out = kubectl(item).args(&get_args).output()?;
+        let out = kubectl(item).args(build_yaml_args(item)).output()?;
         if !out.status.success() {
             eprintln!(
                 "[kuberift] failed to fetch {}/{}: {}",
@@ -507,12 +1644,7 @@ fn resolve_editor(config_editor: &str) -> String {
 
 pub fn action_yaml(items: &[&K8sItem]) -> Result<()> {
     for item in items {
-        let mut args = vec!["get", item.kind().as_str(), "-o", "yaml"];
-        if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
-        }
-        args.extend_from_slice(&["--", item.name()]);
-        let out = kubectl(item).args(&args).output()?;
+        let out = kubectl(item).args(build_yaml_args(item)).output()?;
         if out.status.success() {
             print!("{}", String::from_utf8_lossy(&out.stdout));
         } else {
@@ -525,20 +1657,526 @@ pub fn action_yaml(items: &[&K8sItem]) -> Result<()> {
     Ok(())
 }
 
-// ─── Describe (default Enter) ─────────────────────────────────────────────────
+// ─── Clipboard ────────────────────────────────────────────────────────────────
 
-pub fn action_describe(items: &[&K8sItem]) -> Result<()> {
+/// Clipboard tools tried in order: macOS, Wayland, X11 (two flavours), WSL.
+const CLIPBOARD_TOOLS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Copy `text` to the system clipboard with the first tool that is installed
+/// and succeeds. `Ok(false)` when none could (e.g. a headless box).
+pub fn copy_to_clipboard(text: &str) -> Result<bool> {
+    for (tool, args) in CLIPBOARD_TOOLS {
+        let mut child = match Command::new(tool)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            // A tool that exits early (no display) closes the pipe; try the next one.
+            if stdin.write_all(text.as_bytes()).is_err() {
+                let _ = child.wait();
+                continue;
+            }
+        }
+        if child.wait()?.success() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// ─── Copy YAML (alt-y) ────────────────────────────────────────────────────────
+
+/// Copy the resource's manifest to the clipboard. Falls back to printing it
+/// when no clipboard tool is available.
+pub fn action_copy_yaml(item: &K8sItem) -> Result<()> {
+    let out = kubectl(item).args(build_yaml_args(item)).output()?;
+    if !out.status.success() {
+        eprintln!(
+            "[kuberift] kubectl get yaml failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+        return Ok(());
+    }
+    let yaml = String::from_utf8_lossy(&out.stdout);
+    let target = format!("{}/{}", item.kind().as_str(), item.name());
+    if copy_to_clipboard(&yaml)? {
+        println!("✓ copied {target} YAML to the clipboard");
+    } else {
+        eprintln!(
+            "[kuberift] no clipboard tool found (pbcopy, wl-copy, xclip, xsel, clip.exe); \
+             printing {target} instead"
+        );
+        print!("{yaml}");
+    }
+    Ok(())
+}
+
+// ─── Drift from last-applied (alt-v) ──────────────────────────────────────────
+
+/// Annotation `kubectl apply` stores the applied manifest in.
+pub const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+pub fn build_json_args(item: &K8sItem) -> Vec<String> {
+    by_name_args("get", &["-o", "json"], item)
+}
+
+/// One field whose live value differs from the last-applied configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    /// Dotted field path, e.g. `spec.template.spec.containers[0].image`.
+    pub path: String,
+    pub applied: Value,
+    /// `None` when the field was removed from the live object.
+    pub live: Option<Value>,
+}
+
+/// The manifest stored in `object`'s last-applied annotation. `Ok(None)` when
+/// the object was never `kubectl apply`-ed.
+pub fn last_applied(object: &Value) -> Result<Option<Value>> {
+    let Some(raw) = object
+        .pointer("/metadata/annotations")
+        .and_then(|a| a.get(LAST_APPLIED_ANNOTATION))
+        .and_then(Value::as_str)
+    else {
+        return Ok(None);
+    };
+    serde_json::from_str(raw)
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("unreadable {LAST_APPLIED_ANNOTATION} annotation: {e}"))
+}
+
+/// Fields set in `applied` whose value in `live` differs. Only fields the
+/// manifest sets are compared: defaults and status the cluster fills in are
+/// not drift. Lists of equal length are compared item by item, otherwise as
+/// a whole.
+pub fn find_drift(applied: &Value, live: &Value) -> Vec<Drift> {
+    let mut drift = Vec::new();
+    collect_drift("", applied, Some(live), &mut drift);
+    drift
+}
+
+fn collect_drift(path: &str, applied: &Value, live: Option<&Value>, out: &mut Vec<Drift>) {
+    match (applied, live) {
+        (Value::Object(fields), Some(Value::Object(live_fields))) => {
+            for (key, value) in fields {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                collect_drift(&child, value, live_fields.get(key), out);
+            }
+        }
+        (Value::Array(items), Some(Value::Array(live_items)))
+            if items.len() == live_items.len() =>
+        {
+            for (i, (value, live_value)) in items.iter().zip(live_items).enumerate() {
+                collect_drift(&format!("{path}[{i}]"), value, Some(live_value), out);
+            }
+        }
+        (_, Some(live_value)) if live_value == applied => {}
+        _ => out.push(Drift {
+            path: path.to_string(),
+            applied: applied.clone(),
+            live: live.cloned(),
+        }),
+    }
+}
+
+/// `- path: applied` / `+ path: live` lines, red and green with `ansi`.
+pub fn render_drift(drift: &[Drift], ansi: bool) -> String {
+    let paint = |line: String, code: u8| {
+        if ansi {
+            format!("\x1b[{code}m{line}\x1b[0m\n")
+        } else {
+            format!("{line}\n")
+        }
+    };
+    let mut out = String::new();
+    for d in drift {
+        out.push_str(&paint(format!("- {}: {}", d.path, d.applied), 31));
+        match &d.live {
+            Some(live) => out.push_str(&paint(format!("+ {}: {live}", d.path), 32)),
+            None => out.push_str(&paint(format!("+ {}: (removed)", d.path), 32)),
+        }
+    }
+    out
+}
+
+/// Drift report for an object fetched with `kubectl get -o json`.
+pub fn drift_report(object_json: &str, ansi: bool) -> Result<String> {
+    let live: Value = serde_json::from_str(object_json)?;
+    let Some(applied) = last_applied(&live)? else {
+        return Ok(format!(
+            "no {LAST_APPLIED_ANNOTATION} annotation; not managed with kubectl apply\n"
+        ));
+    };
+    let drift = find_drift(&applied, &live);
+    if drift.is_empty() {
+        return Ok("no drift from the last-applied configuration\n".to_string());
+    }
+    Ok(render_drift(&drift, ansi))
+}
+
+/// Show how each selected resource drifted from what `kubectl apply` last set.
+pub fn action_drift(items: &[&K8sItem]) -> Result<()> {
+    let ansi = io::stdout().is_terminal();
     for item in items {
-        let mut args = vec!["describe", item.kind().as_str()];
-        if !item.namespace().is_empty() {
-            args.extend_from_slice(&["-n", item.namespace()]);
+        let out = kubectl(item).args(build_json_args(item)).output()?;
+        if !out.status.success() {
+            eprintln!(
+                "[kuberift] kubectl get json failed: {}",
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+            continue;
         }
-        args.extend_from_slice(&["--", item.name()]);
-        let out = kubectl(item).args(&args).output()?;
+        println!("\n# {}", item.output_str());
+        match drift_report(&String::from_utf8_lossy(&out.stdout), ansi) {
+            Ok(report) => print!("{report}"),
+            Err(e) => eprintln!("[kuberift] {}: {e}", item.output_str()),
+        }
+    }
+    Ok(())
+}
+
+// ─── Show commands (alt-c) ────────────────────────────────────────────────────
+
+/// Every action that applies to `item`, with the arguments it would run.
+/// Values that are prompted for at run time appear as `<placeholders>`.
+pub fn dry_run_commands(item: &K8sItem) -> Vec<(&'static str, Vec<String>)> {
+    let kind = item.kind();
+    let mut cmds = vec![
+        ("describe", build_describe_args(item)),
+        ("yaml", build_yaml_args(item)),
+        ("watch", build_raw_watch_args(item)),
+    ];
+    if matches!(kind, ResourceKind::Pod) {
+        cmds.push(("logs", build_logs_args(item)));
+        cmds.push(("exec", build_exec_args(item, "/bin/sh")));
+    }
+    if matches!(kind, ResourceKind::Job) {
+        cmds.push(("logs", job_logs_args(item, item.name())));
+    }
+    if matches!(kind, ResourceKind::Pod | ResourceKind::Service) {
+        cmds.push((
+            "port-forward",
+            build_portforward_args(item, "<local>:<remote>"),
+        ));
+    }
+    if matches!(
+        kind,
+        ResourceKind::Deployment | ResourceKind::StatefulSet | ResourceKind::DaemonSet
+    ) {
+        cmds.push(("restart", rollout_args(item, "restart")));
+    }
+    if matches!(kind, ResourceKind::Deployment) {
+        cmds.push(("pause", rollout_args(item, "pause")));
+        cmds.push(("resume", rollout_args(item, "resume")));
+    }
+    if matches!(kind, ResourceKind::Deployment | ResourceKind::StatefulSet) {
+        cmds.push(("scale", build_scale_args(item, "<replicas>")));
+    }
+    if matches!(kind, ResourceKind::Node) {
+        cmds.push(("cordon", node_args(item, "cordon")));
+        cmds.push(("uncordon", node_args(item, "uncordon")));
+        cmds.push(("drain", build_drain_args(item)));
+    }
+    cmds.push(("drift", build_json_args(item)));
+    cmds.push(("delete", build_delete_args(item)));
+    if force_deletable(item) {
+        cmds.push(("force-delete", build_force_delete_args(item)));
+    }
+    cmds
+}
+
+/// Print the kubectl commands each action would run on the selection,
+/// without running anything.
+pub fn action_show_commands(items: &[&K8sItem]) {
+    for item in items {
+        println!("\n# {}", item.output_str());
+        for (label, args) in dry_run_commands(item) {
+            println!("{:<13} {}", label, command_line(item, &args));
+        }
+    }
+}
+
+// ─── JSONPath extract (--jsonpath) ────────────────────────────────────────────
+
+/// Wrap a bare JSONPath like `.spec.nodeName` in braces; kubectl's template
+/// printer treats text outside `{…}` as a literal.
+fn jsonpath_template(expr: &str) -> String {
+    let expr = expr.trim();
+    if expr.contains('{') {
+        expr.to_string()
+    } else {
+        format!("{{{expr}}}")
+    }
+}
+
+/// Build the `kubectl get` arguments that extract `expr` from `item`.
+/// `--context` is added separately by the `kubectl()` builder.
+pub fn jsonpath_args(item: &K8sItem, expr: &str) -> Vec<String> {
+    let mut args = vec![
+        "get".to_string(),
+        item.kind().as_str().to_string(),
+        "-o".to_string(),
+        format!("jsonpath={}", jsonpath_template(expr)),
+    ];
+    args.extend(namespace_args(item));
+    args.extend(["--".to_string(), item.name().to_string()]);
+    args
+}
+
+pub fn action_jsonpath(items: &[&K8sItem], expr: &str) -> Result<()> {
+    if expr.trim().is_empty() {
+        eprintln!("[kuberift] --jsonpath expression is empty");
+        return Ok(());
+    }
+    let prefix = items.len() > 1;
+    for item in items {
+        let out = kubectl(item).args(jsonpath_args(item, expr)).output()?;
         if out.status.success() {
-            print!("{}", String::from_utf8_lossy(&out.stdout));
+            let value = String::from_utf8_lossy(&out.stdout);
+            if prefix {
+                println!("{}: {}", item.output_str(), value.trim_end());
+            } else {
+                println!("{}", value.trim_end());
+            }
+        } else {
+            eprintln!(
+                "✗ jsonpath failed {}: {}",
+                item.output_str(),
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+        }
+    }
+    Ok(())
+}
+
+// ─── Markdown report (--report) ───────────────────────────────────────────────
+
+/// Shortest backtick fence that can't be closed by a run of backticks inside `body`.
+fn code_fence(body: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in body.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
         } else {
-            println!("{}", item.output_str());
+            run = 0;
+        }
+    }
+    "`".repeat((longest + 1).max(3))
+}
+
+/// Render the Markdown report: a summary table grouped by health, then one
+/// section per resource with its describe output (or the kubectl error).
+fn render_report(entries: &[(&K8sItem, String, Result<String, String>)]) -> String {
+    let mut md = String::from("# KubeRift report\n\n");
+    md.push_str(&format!(
+        "Generated {} — {} resource(s)\n\n",
+        k8s_openapi::jiff::Timestamp::now(),
+        entries.len()
+    ));
+
+    md.push_str("## Summary\n\n| Health | Count | Resources |\n|---|---:|---|\n");
+    for health in StatusHealth::ALL {
+        let names: Vec<String> = entries
+            .iter()
+            .filter(|(_, status, _)| StatusHealth::classify(status) == *health)
+            .map(|(item, _, _)| format!("`{}`", item.output_str().replace('|', "\\|")))
+            .collect();
+        if !names.is_empty() {
+            md.push_str(&format!(
+                "| {} | {} | {} |\n",
+                health.as_str(),
+                names.len(),
+                names.join(", ")
+            ));
+        }
+    }
+
+    for (item, status, describe) in entries {
+        md.push_str(&format!("\n## {} — {status}\n\n", item.output_str()));
+        match describe {
+            Ok(body) => {
+                let fence = code_fence(body);
+                md.push_str(&format!("{fence}text\n{}\n{fence}\n", body.trim_end()));
+            }
+            Err(err) => {
+                md.push_str(&format!("> ⚠ kubectl describe failed: {}\n", err.trim()));
+            }
+        }
+    }
+    md
+}
+
+/// Write the Markdown report of `items` to `path`, describing up to
+/// [`DESCRIBE_CONCURRENCY`] of them at once.
+pub fn action_report(items: &[&K8sItem], path: &Path) -> Result<()> {
+    let describes = map_ordered(items, DESCRIBE_CONCURRENCY, |item| {
+        match kubectl(item).args(build_describe_args(item)).output() {
+            Ok(out) if out.status.success() => Ok(String::from_utf8_lossy(&out.stdout).to_string()),
+            Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    });
+    let entries: Vec<_> = items
+        .iter()
+        .zip(describes)
+        .map(|(item, describe)| (*item, item.status(), describe))
+        .collect();
+
+    std::fs::write(path, render_report(&entries))?;
+    println!(
+        "✓ report written to {} ({} resources)",
+        path.display(),
+        entries.len()
+    );
+    Ok(())
+}
+
+// ─── Describe (default Enter) ─────────────────────────────────────────────────
+
+/// Maximum number of `kubectl describe` processes run at once for a multi-select.
+pub const DESCRIBE_CONCURRENCY: usize = 8;
+
+/// Apply `f` to every input on up to `limit` worker threads and return the
+/// results in input order, regardless of the order in which they complete.
+pub fn map_ordered<T, R, F>(inputs: &[T], limit: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let mut slots: Vec<Option<R>> = std::iter::repeat_with(|| None).take(inputs.len()).collect();
+    let (tx, rx) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for _ in 0..limit.clamp(1, inputs.len().max(1)) {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(input) = inputs.get(i) else { break };
+                if tx.send((i, f(input))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+        for (i, result) in rx {
+            slots[i] = Some(result);
+        }
+    });
+    slots.into_iter().flatten().collect()
+}
+
+/// Backing `service:port` pairs of an Ingress, one per line.
+const INGRESS_BACKENDS_JSONPATH: &str = "{range .spec.rules[*].http.paths[*]}\
+     {.backend.service.name}:{.backend.service.port.number}{.backend.service.port.name}\
+     {\"\\n\"}{end}";
+
+/// Allocatable vs. capacity for a Node, as a small table.
+const NODE_RESOURCES_COLUMNS: &str = "CPU:.status.allocatable.cpu,\
+     CPU-CAPACITY:.status.capacity.cpu,\
+     MEMORY:.status.allocatable.memory,\
+     MEMORY-CAPACITY:.status.capacity.memory,\
+     PODS:.status.allocatable.pods,\
+     PODS-CAPACITY:.status.capacity.pods";
+
+/// The commands Enter runs for `item`, in order. Always starts with
+/// `describe`; Services add their endpoints, Ingresses their backing
+/// services and Nodes an allocatable/capacity table.
+pub fn default_action_for(kind: &ResourceKind, item: &K8sItem) -> Vec<Vec<String>> {
+    let mut commands = vec![build_describe_args(item)];
+    match kind {
+        ResourceKind::Service => {
+            let mut endpoints: Vec<String> = ["get", "endpoints", "-o", "wide"]
+                .iter()
+                .map(|a| (*a).to_string())
+                .collect();
+            endpoints.extend(namespace_args(item));
+            endpoints.extend(["--".to_string(), item.name().to_string()]);
+            commands.push(endpoints);
+        }
+        ResourceKind::Ingress => {
+            commands.push(by_name_args(
+                "get",
+                &["-o", &format!("jsonpath={INGRESS_BACKENDS_JSONPATH}")],
+                item,
+            ));
+        }
+        ResourceKind::Node => {
+            commands.push(by_name_args(
+                "get",
+                &["-o", &format!("custom-columns={NODE_RESOURCES_COLUMNS}")],
+                item,
+            ));
+        }
+        _ => {}
+    }
+    commands
+}
+
+/// Section title printed above each follow-up command's output.
+fn enrichment_title(kind: &ResourceKind) -> &'static str {
+    match kind {
+        ResourceKind::Service => "Endpoints",
+        ResourceKind::Ingress => "Backing services",
+        ResourceKind::Node => "Allocatable / capacity",
+        _ => "Details",
+    }
+}
+
+/// Run `item`'s Enter commands and join their output. `None` when the
+/// describe itself fails; a failing follow-up command is just left out.
+fn default_action_output(item: &K8sItem) -> io::Result<Option<String>> {
+    let mut commands = default_action_for(item.kind(), item).into_iter();
+    let Some(describe) = commands.next() else {
+        return Ok(None);
+    };
+    let out = kubectl(item).args(describe).output()?;
+    if !out.status.success() {
+        return Ok(None);
+    }
+    let mut text = String::from_utf8_lossy(&out.stdout).into_owned();
+    for args in commands {
+        let out = kubectl(item).args(args).output()?;
+        if out.status.success() {
+            let title = enrichment_title(item.kind());
+            text.push_str(&format!("\n── {title} ──\n"));
+            text.push_str(&String::from_utf8_lossy(&out.stdout));
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+        }
+    }
+    Ok(Some(text))
+}
+
+pub fn action_describe(items: &[&K8sItem]) -> Result<()> {
+    let outputs = map_ordered(items, DESCRIBE_CONCURRENCY, |item| {
+        default_action_output(item)
+    });
+    for (item, out) in items.iter().zip(outputs) {
+        match out? {
+            Some(text) => print!("{text}"),
+            None => println!("{}", item.output_str()),
         }
     }
     Ok(())
@@ -1,8 +1,22 @@
 use clap::Parser;
 use clap_complete::Shell;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::actions::DEFAULT_BULK_DELETE_THRESHOLD;
 use crate::config::Config;
-use crate::items::ResourceKind;
+use crate::items::{
+    AgeFormat, Column, Columns, Layout, OutputFormat, ResourceKind, SortField, StatusHealth, Theme,
+    DEFAULT_NAME_WIDTH, MIN_NAME_WIDTH,
+};
+use crate::k8s::discovery::VPA_PLURAL;
+use crate::k8s::resources::{
+    parse_namespaces, ALL_KINDS, DEFAULT_MAX_RETRIES, DEFAULT_STALE_AFTER_MINS, INIT_TIMEOUT,
+    SYSTEM_NAMESPACES,
+};
+use crate::top::{TopSort, TopTarget, DEFAULT_TOP_N};
+use crate::ui::list_binds;
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Parser, Debug)]
@@ -14,9 +28,16 @@ use crate::items::ResourceKind;
 )]
 pub struct Args {
     /// Resource type to filter (pods/po, svc, deploy, sts, ds, cm, secret,
-    /// ing, node, ns, pv, pvc, job, cronjob). Omit to show ALL resource types.
+    /// ep, ing, node, ns, pv, pvc, job, cronjob, hpa, vpa, sa, role,
+    /// rolebinding), or several separated by commas (pod,svc,deploy). Omit to
+    /// show ALL resource types.
     pub resource: Option<String>,
 
+    /// Resource types to leave out, as comma-separated aliases
+    /// (`--exclude secret,cm`) — e.g. kinds that flood the list.
+    #[arg(long, value_name = "KINDS", alias = "watch-kinds-exclude")]
+    pub exclude: Option<String>,
+
     /// Watch resources from all kubeconfig contexts simultaneously.
     /// Resources are prefixed with their cluster context name.
     #[arg(long)]
@@ -24,29 +45,156 @@ pub struct Args {
 
     /// Use a specific kubeconfig context instead of the current one.
     /// Overrides the last-used context saved by ctrl-x switching.
+    /// Repeat it (`--context prod --context staging`) to watch just those
+    /// clusters together, like --all-contexts.
     #[arg(long, value_name = "CONTEXT")]
-    pub context: Option<String>,
+    pub context: Vec<String>,
+
+    /// Ignore the context saved by ctrl-x switching and start in kubeconfig's
+    /// current-context (as set by `kubectl config use-context`).
+    #[arg(long, conflicts_with = "context")]
+    pub use_kube_current: bool,
+
+    /// Print the effective configuration (context, namespace, kinds, sort,
+    /// theme, key bindings and the config-file values that applied), then exit.
+    #[arg(long)]
+    pub print_config: bool,
 
-    /// Restrict to a specific namespace. Default: all namespaces.
+    /// Forget the context saved by ctrl-x switching, then exit.
+    #[arg(long)]
+    pub forget_context: bool,
+
+    /// Restrict to a namespace, or several comma-separated (`-n app,app-system`).
+    /// Default: all namespaces.
     /// Cluster-scoped resources (Node, Namespace, PV) ignore this flag.
-    #[arg(short = 'n', long, value_name = "NAMESPACE")]
+    #[arg(short = 'n', long, value_name = "NAMESPACE[,...]")]
     pub namespace: Option<String>,
 
+    /// Picker mode for shell integration: no action keys; Enter prints the
+    /// selected resource(s) and exits. Example: `POD=$(kf pod --pick)`
+    #[arg(long, conflicts_with_all = ["count", "jsonpath", "report"])]
+    pub pick: bool,
+
+    /// Guided --pick: first choose a resource kind (of those that have
+    /// resources), then pick among that kind's resources.
+    #[arg(
+        long,
+        conflicts_with_all = ["count", "jsonpath", "report", "all_contexts", "top", "watch_file"]
+    )]
+    pub two_stage: bool,
+
+    /// Enable mouse support: click to select rows, scroll the preview with the
+    /// wheel. Off by default because mouse mode blocks the terminal's own text
+    /// selection (hold Shift to select while it is on).
+    #[arg(long)]
+    pub mouse: bool,
+
+    /// Match the query as exact substrings instead of fuzzily, so `web`
+    /// no longer matches `kube-system`. alt-m toggles it while browsing.
+    #[arg(long, visible_alias = "no-fuzzy")]
+    pub exact: bool,
+
+    /// Go straight back to the list after describe, yaml or logs output
+    /// instead of waiting for a key press.
+    #[arg(long)]
+    pub no_pause: bool,
+
     /// Disable all write and exec actions (delete, exec, port-forward, rollout-restart).
     /// Describe, logs, and YAML remain available.
     #[arg(long)]
     pub read_only: bool,
 
+    /// Deleting more than N resources at once requires typing 'yes' instead
+    /// of answering y/N. 0 makes every delete strict.
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_BULK_DELETE_THRESHOLD)]
+    pub bulk_delete_threshold: usize,
+
+    /// Show the first, sorted render after at most SECS even if some kinds are
+    /// still listing; they join the list when ready. Raise it on slow clusters
+    /// or when watching many kinds. 0 waits for every kind.
+    #[arg(long, value_name = "SECS", default_value_t = INIT_TIMEOUT.as_secs())]
+    pub init_timeout: u64,
+
+    /// With --all-contexts, show the combined list after at most SECS even if
+    /// some clusters are still loading; they join the list when ready.
+    #[arg(long, value_name = "SECS", default_value_t = INIT_TIMEOUT.as_secs())]
+    pub context_timeout: u64,
+
+    /// Warn on the status row when a kind that has resources receives no
+    /// watch events for MINS minutes, as the watch may have gone stale.
+    /// Off (0) by default; pick a window longer than the quietest kind you
+    /// watch normally goes without changes.
+    #[arg(long, value_name = "MINS", default_value_t = DEFAULT_STALE_AFTER_MINS)]
+    pub stale_after: u64,
+
+    /// With --all-contexts, list each cluster's resources together under a
+    /// separator row instead of interleaving them by health.
+    #[arg(long, requires = "all_contexts")]
+    pub group_by_context: bool,
+
+    /// Skip everything that already exists and list only resources added,
+    /// changed or deleted after startup.
+    #[arg(long, conflicts_with_all = ["count", "top"])]
+    pub changes_only: bool,
+
+    /// Hide resources in these namespaces (comma-separated).
+    /// Cluster-scoped kinds are unaffected.
+    #[arg(long, value_name = "NS,...", value_delimiter = ',')]
+    pub exclude_namespaces: Vec<String>,
+
+    /// Hide kube-system, kube-public and kube-node-lease.
+    #[arg(long)]
+    pub no_system: bool,
+
     /// Filter resources by a Kubernetes label selector.
     /// Accepts any expression valid for kubectl --selector
     /// (e.g. `app=backend`, `env in (prod,staging)`, `!canary`).
     #[arg(short = 'l', long, value_name = "SELECTOR")]
     pub label: Option<String>,
 
+    /// Show each resource's labels as `[key=value]` chips. alt-t adds one of
+    /// the selected resource's labels to the -l filter.
+    #[arg(long)]
+    pub show_labels: bool,
+
+    /// List columns to show, in order, from kind, namespace, name, status and
+    /// age — e.g. `--columns name,status` in a single-namespace view.
+    /// Hidden columns aren't searched either. Default: all of them.
+    #[arg(long, value_name = "COLUMNS", value_parser = parse_columns)]
+    pub columns: Option<Columns>,
+
+    /// Show CPU and memory usage from `kubectl top` on pod and node rows,
+    /// refreshed every 30s (needs metrics-server; left out when it's missing).
+    #[arg(long, conflicts_with_all = ["watch_file", "count", "top"])]
+    pub metrics: bool,
+
+    /// Leave out pods in Terminating, e.g. the old pods of a rolling update
+    /// (they disappear shortly anyway). A shown pod that starts terminating
+    /// is marked [DELETED].
+    #[arg(long)]
+    pub hide_terminating: bool,
+
+    /// Abbreviate long statuses in the list (e.g. `LoadBalancer` → `LB`,
+    /// `kubernetes.io/service-account-token` → `sa-token`). The preview and
+    /// printed output keep the full status.
+    #[arg(long)]
+    pub compact_status: bool,
+
     /// Path to kubeconfig file. Defaults to $KUBECONFIG or ~/.kube/config.
     #[arg(long, value_name = "PATH")]
     pub kubeconfig: Option<String>,
 
+    /// Show resources from a `kubectl get -o json` dump instead of a cluster,
+    /// reloading the list whenever the file changes. Write the file
+    /// atomically (rename into place); a half-written file is skipped.
+    /// Kinds kf has no model for are listed under their own kind name.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["all_contexts", "count", "top", "changes_only"]
+    )]
+    pub watch_file: Option<PathBuf>,
+
     /// Print shell completions for SHELL to stdout and exit.
     /// Example: `kf --completions bash >> ~/.bash_completion`
     #[arg(long, value_name = "SHELL", hide = true)]
@@ -57,10 +205,367 @@ pub struct Args {
     #[arg(long, hide = true)]
     pub mangen: bool,
 
+    /// Print a legend of the resource-kind and status colors, then exit.
+    #[arg(long)]
+    pub help_colors: bool,
+
+    /// Print sample rows of every resource kind and status tier as the list
+    /// draws them, in the --theme palette, then exit. No cluster needed.
+    #[arg(long)]
+    pub theme_preview: bool,
+
     /// Disable automatic discovery and watching of CRDs (Custom Resource Definitions).
     /// When set, only built-in resource types (pods, deploys, etc.) are shown.
     #[arg(long)]
     pub no_crds: bool,
+
+    /// Width of the name column; longer names are truncated with "…".
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = DEFAULT_NAME_WIDTH,
+        value_parser = parse_name_width
+    )]
+    pub name_width: usize,
+
+    /// Built-in color preset: dark (default), light, high-contrast or colorblind.
+    #[arg(long, value_name = "NAME", value_parser = parse_theme)]
+    pub theme: Option<Theme>,
+
+    /// Age column: relative (default, e.g. `2d`), absolute (local date and
+    /// time) or iso (ISO 8601, UTC).
+    #[arg(
+        long,
+        value_name = "relative|absolute|iso",
+        value_parser = parse_age_format,
+        default_value = "relative"
+    )]
+    pub age_format: AgeFormat,
+
+    /// Share of the terminal height the list takes, in percent (default 60).
+    #[arg(long, value_name = "PCT", value_parser = parse_height)]
+    pub height: Option<u8>,
+
+    /// List layout: default (prompt at the bottom) or reverse (prompt at the
+    /// top). Unhealthy resources stay at the top of the list in both.
+    #[arg(
+        long,
+        value_name = "default|reverse",
+        value_parser = parse_layout,
+        default_value = "default"
+    )]
+    pub layout: Layout,
+
+    /// Wrap preview text at N columns instead of the preview pane width.
+    /// 0 disables wrapping (tabs are still expanded).
+    #[arg(long, value_name = "N")]
+    pub describe_width: Option<usize>,
+
+    /// Instead of describing, print this JSONPath field for each selected resource
+    /// (e.g. `.spec.nodeName`). Multi-select output is prefixed with the resource.
+    #[arg(long, value_name = "EXPR", value_parser = parse_jsonpath)]
+    pub jsonpath: Option<String>,
+
+    /// Instead of describing, write a Markdown report of the selected resources
+    /// (health summary plus describe output per resource) to PATH.
+    #[arg(long, value_name = "PATH", conflicts_with = "jsonpath")]
+    pub report: Option<PathBuf>,
+
+    /// With --report, skip the list and report every resource matching the
+    /// resource argument and the -n/-l filters.
+    #[arg(long, requires = "report", conflicts_with_all = ["pick", "two_stage"])]
+    pub report_all: bool,
+
+    /// How selected resources are printed: text (default; `kind/ns/name` with
+    /// --pick, `kubectl describe` otherwise), json (an array of objects with
+    /// kind, namespace, name, status, age and context) or grouped-json (those
+    /// objects in arrays keyed by context, for per-cluster pipelines).
+    #[arg(
+        short = 'o',
+        long,
+        value_name = "text|json|grouped-json",
+        value_parser = parse_output,
+        default_value = "text",
+        conflicts_with_all = ["jsonpath", "report"]
+    )]
+    pub output: OutputFormat,
+
+    /// Consecutive watch failures tolerated per resource kind before that kind
+    /// is marked unavailable and no longer retried. 0 retries forever.
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_MAX_RETRIES)]
+    pub max_retries: u32,
+
+    /// Objects fetched per page when a watcher lists a kind (initially and after
+    /// an expired resourceVersion). Lower it for very large kinds. Default: 500.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub page_size: Option<u32>,
+
+    /// List once, print the number of matching resources and exit.
+    /// Deleted entries are not counted.
+    #[arg(long)]
+    pub count: bool,
+
+    /// With --count, only count resources at least this severe
+    /// (critical, warning, healthy).
+    #[arg(long, value_name = "LEVEL", value_parser = parse_min_severity, requires = "count")]
+    pub min_severity: Option<StatusHealth>,
+
+    /// With --count, exit with status 1 when the count is non-zero.
+    #[arg(long, requires = "count")]
+    pub fail_if_nonzero: bool,
+
+    /// Print the heaviest pods or nodes from `kubectl top` and exit
+    /// (needs metrics-server). Pods span all namespaces unless -n is given.
+    #[arg(
+        long,
+        value_name = "pods|nodes",
+        value_parser = parse_top_target,
+        conflicts_with_all = ["count", "pick", "all_contexts"]
+    )]
+    pub top: Option<TopTarget>,
+
+    /// Initial order of the list: health (default), name, namespace, kind,
+    /// status or age. Overrides `default_sort` in the config file.
+    #[arg(long, value_name = "FIELD", value_parser = parse_sort_field)]
+    pub sort: Option<SortField>,
+
+    /// Reverse the --sort order (with age: oldest on top instead of newest).
+    #[arg(long)]
+    pub reverse: bool,
+
+    /// Print which kinds (built-in and custom) the cluster serves, with their
+    /// API group/version, and exit.
+    #[arg(long, conflicts_with_all = ["count", "top", "pick", "all_contexts"])]
+    pub api_resources: bool,
+
+    /// With --top, sort by cpu (default) or memory.
+    #[arg(long, value_name = "cpu|memory", value_parser = parse_top_sort, requires = "top")]
+    pub sort_by: Option<TopSort>,
+
+    /// With --top, how many rows to print.
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_TOP_N)]
+    pub top_n: usize,
+
+    /// Follow the rollout of this deployment (in -n or the context's
+    /// namespace): print its replica progression and pods' statuses as they
+    /// change, exiting when the rollout completes (non-zero if it fails).
+    #[arg(
+        long,
+        value_name = "DEPLOYMENT",
+        conflicts_with_all = ["count", "top", "pick", "all_contexts", "api_resources", "watch_file"]
+    )]
+    pub rollout: Option<String>,
+
+    /// Delete every resource of the given kind that matches this field
+    /// selector (and -l, if given) in -n or the context's namespace, e.g.
+    /// `kf pods --delete-selector status.phase=Failed`. The matches are
+    /// counted with a dry run first and the delete must be confirmed by
+    /// typing 'yes'.
+    #[arg(
+        long,
+        value_name = "FIELD_SELECTOR",
+        conflicts_with_all = [
+            "count", "top", "pick", "all_contexts", "api_resources", "watch_file", "rollout",
+            "read_only"
+        ]
+    )]
+    pub delete_selector: Option<String>,
+
+    /// List once, print the resources as an aligned table (NAMESPACE KIND
+    /// NAME STATUS AGE, like `kubectl get`) and exit. Statuses are colored
+    /// by health on a terminal unless NO_COLOR is set.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "count", "top", "pick", "api_resources", "watch_file", "rollout", "delete_selector",
+            "two_stage"
+        ]
+    )]
+    pub summary: bool,
+}
+
+/// Parse `--jsonpath`, rejecting an empty expression.
+pub fn parse_jsonpath(s: &str) -> Result<String, String> {
+    if s.trim().is_empty() {
+        return Err("JSONPath expression must not be empty".to_string());
+    }
+    Ok(s.to_string())
+}
+
+/// Parse `--min-severity` into a health tier.
+pub fn parse_min_severity(s: &str) -> Result<StatusHealth, String> {
+    StatusHealth::from_name(s)
+        .filter(|h| *h != StatusHealth::Unknown)
+        .ok_or_else(|| format!("'{s}' is not a severity (expected critical, warning or healthy)"))
+}
+
+/// Parse `--top`.
+pub fn parse_top_target(s: &str) -> Result<TopTarget, String> {
+    match s.to_lowercase().as_str() {
+        "po" | "pod" | "pods" => Ok(TopTarget::Pods),
+        "no" | "node" | "nodes" => Ok(TopTarget::Nodes),
+        _ => Err(format!(
+            "'{s}' is not a top target (expected pods or nodes)"
+        )),
+    }
+}
+
+/// Parse `--sort`.
+pub fn parse_sort_field(s: &str) -> Result<SortField, String> {
+    let wanted = s.to_lowercase();
+    SortField::ALL
+        .iter()
+        .copied()
+        .find(|f| f.as_str() == wanted)
+        .ok_or_else(|| {
+            let names: Vec<&str> = SortField::ALL.iter().map(|f| f.as_str()).collect();
+            format!("'{s}' is not a sort field (expected {})", names.join(", "))
+        })
+}
+
+/// Parse `--sort-by`.
+pub fn parse_top_sort(s: &str) -> Result<TopSort, String> {
+    match s.to_lowercase().as_str() {
+        "cpu" => Ok(TopSort::Cpu),
+        "memory" | "mem" => Ok(TopSort::Memory),
+        _ => Err(format!(
+            "'{s}' is not a sort column (expected cpu or memory)"
+        )),
+    }
+}
+
+/// Parse `--theme`, accepting only the built-in preset names.
+pub fn parse_theme(s: &str) -> Result<Theme, String> {
+    Theme::preset(s).ok_or_else(|| {
+        let names: Vec<&str> = Theme::PRESETS.iter().map(|t| t.name).collect();
+        format!(
+            "unknown theme '{s}' (expected one of: {})",
+            names.join(", ")
+        )
+    })
+}
+
+/// Parse `--age-format`.
+pub fn parse_age_format(s: &str) -> Result<AgeFormat, String> {
+    AgeFormat::ALL
+        .iter()
+        .copied()
+        .find(|f| f.as_str() == s)
+        .ok_or_else(|| format!("'{s}' is not an age format (expected relative, absolute or iso)"))
+}
+
+/// Parse `--layout`.
+pub fn parse_layout(s: &str) -> Result<Layout, String> {
+    Layout::ALL
+        .iter()
+        .copied()
+        .find(|l| l.as_str() == s)
+        .ok_or_else(|| format!("'{s}' is not a layout (expected default or reverse)"))
+}
+
+/// Parse `--columns`: comma-separated column names, each at most once.
+pub fn parse_columns(s: &str) -> Result<Columns, String> {
+    let mut order = Vec::new();
+    for name in s.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        let column = Column::ALL
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                format!("'{name}' is not a column (expected kind, namespace, name, status or age)")
+            })?;
+        if order.contains(&column) {
+            return Err(format!("column '{name}' is listed twice"));
+        }
+        order.push(column);
+    }
+    Columns::new(&order).ok_or_else(|| "no columns given".to_string())
+}
+
+/// Parse `--output`.
+pub fn parse_output(s: &str) -> Result<OutputFormat, String> {
+    OutputFormat::ALL
+        .iter()
+        .copied()
+        .find(|f| f.as_str() == s)
+        .ok_or_else(|| {
+            format!("'{s}' is not an output format (expected text, json or grouped-json)")
+        })
+}
+
+/// Parse `--height`: a percentage from 1 to 100, with or without `%`.
+pub fn parse_height(s: &str) -> Result<u8, String> {
+    let pct: u8 = s
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| format!("'{s}' is not a valid height"))?;
+    if !(1..=100).contains(&pct) {
+        return Err("height must be between 1 and 100 percent".to_string());
+    }
+    Ok(pct)
+}
+
+/// Parse `--name-width`, rejecting values below [`MIN_NAME_WIDTH`].
+pub fn parse_name_width(s: &str) -> Result<usize, String> {
+    let width: usize = s
+        .parse()
+        .map_err(|_| format!("'{s}' is not a valid width"))?;
+    if width < MIN_NAME_WIDTH {
+        return Err(format!("name width must be at least {MIN_NAME_WIDTH}"));
+    }
+    Ok(width)
+}
+
+/// Header badge describing the watch scope: `[ns:<name>]` for a single
+/// namespace, `[ns:<a>,<b>]` for several, `[all-ns]` when watching cluster-wide.
+pub fn namespace_badge(namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) => format!("[ns:{}]", parse_namespaces(ns).join(",")),
+        None => "[all-ns]".to_string(),
+    }
+}
+
+/// Notice printed when `--namespace` is given but every requested kind is
+/// cluster-scoped, so the namespace has no effect.
+pub fn ignored_namespace_notice(kinds: &[ResourceKind], namespace: Option<&str>) -> Option<String> {
+    let ns = namespace?;
+    if kinds.is_empty() || !kinds.iter().all(ResourceKind::is_cluster_scoped) {
+        return None;
+    }
+    let names: Vec<&str> = kinds.iter().map(ResourceKind::as_str).collect();
+    Some(format!(
+        "--namespace {ns} is ignored: {} is cluster-scoped",
+        names.join(", ")
+    ))
+}
+
+/// Status line summarising what the list is filtered by, e.g.
+/// `filters: ns=foo l=app=nginx sort=age | 42 items`. The count is omitted
+/// when unknown; the sort order is shown only when it isn't the default.
+pub fn status_footer(
+    namespace: Option<&str>,
+    label: Option<&str>,
+    sort: SortField,
+    count: Option<usize>,
+) -> String {
+    let mut parts = Vec::new();
+    if let Some(ns) = namespace {
+        parts.push(format!("ns={}", parse_namespaces(ns).join(",")));
+    }
+    if let Some(sel) = label {
+        parts.push(format!("l={sel}"));
+    }
+    if sort != SortField::default() {
+        parts.push(format!("sort={}", sort.as_str()));
+    }
+    let filters = if parts.is_empty() {
+        "none".to_string()
+    } else {
+        parts.join(" ")
+    };
+    match count {
+        Some(n) => format!("filters: {filters} | {n} items"),
+        None => format!("filters: {filters}"),
+    }
 }
 
 impl Args {
@@ -70,8 +575,8 @@ impl Args {
         if self.namespace.is_none() && !config.general.default_namespace.is_empty() {
             self.namespace = Some(config.general.default_namespace.clone());
         }
-        if self.context.is_none() && !config.general.default_context.is_empty() {
-            self.context = Some(config.general.default_context.clone());
+        if self.context.is_empty() && !config.general.default_context.is_empty() {
+            self.context = vec![config.general.default_context.clone()];
         }
         if self.resource.is_none() && !config.general.default_resource.is_empty() {
             self.resource = Some(config.general.default_resource.clone());
@@ -81,36 +586,465 @@ impl Args {
         }
     }
 
+    /// The config-file values [`Args::merge_with_config`] and the session
+    /// take from `config`, as `section.key = value`. Call before merging.
+    pub fn config_overrides(&self, config: &Config) -> Vec<String> {
+        let general = &config.general;
+        let mut applied = Vec::new();
+        let quoted = |key: &str, value: &str| format!("{key} = {value:?}");
+        if self.context.is_empty() && !general.default_context.is_empty() {
+            applied.push(quoted("general.default_context", &general.default_context));
+        }
+        if self.namespace.is_none() && !general.default_namespace.is_empty() {
+            applied.push(quoted(
+                "general.default_namespace",
+                &general.default_namespace,
+            ));
+        }
+        if self.resource.is_none() && !general.default_resource.is_empty() {
+            applied.push(quoted(
+                "general.default_resource",
+                &general.default_resource,
+            ));
+        }
+        if general.read_only && !self.read_only {
+            applied.push("general.read_only = true".to_string());
+        }
+        if !general.editor.is_empty() {
+            applied.push(quoted("general.editor", &general.editor));
+        }
+        if self.sort.is_none() && !config.ui.default_sort.is_empty() {
+            applied.push(quoted("ui.default_sort", &config.ui.default_sort));
+        }
+        applied
+    }
+
+    /// The `--stale-after` window; `None` when the check is disabled.
+    pub fn stale_window(&self) -> Option<Duration> {
+        (self.stale_after > 0).then(|| Duration::from_secs(self.stale_after * 60))
+    }
+
+    /// Whether several clusters are watched together: `--all-contexts`, or
+    /// `--context` given more than once.
+    pub fn multi_context(&self) -> bool {
+        self.all_contexts || self.context.len() > 1
+    }
+
+    /// The first flag given that acts on a single cluster, when several
+    /// `--context`s are — those can't be combined.
+    pub fn single_context_flag(&self) -> Option<&'static str> {
+        if self.context.len() < 2 {
+            return None;
+        }
+        [
+            (self.two_stage, "--two-stage"),
+            (self.top.is_some(), "--top"),
+            (self.api_resources, "--api-resources"),
+            (self.rollout.is_some(), "--rollout"),
+            (self.delete_selector.is_some(), "--delete-selector"),
+            (self.watch_file.is_some(), "--watch-file"),
+        ]
+        .into_iter()
+        .find_map(|(given, flag)| given.then_some(flag))
+    }
+
+    /// The `--init-timeout` wait; `None` when it waits for every kind.
+    pub fn init_window(&self) -> Option<Duration> {
+        (self.init_timeout > 0).then(|| Duration::from_secs(self.init_timeout))
+    }
+
+    /// Namespaces to hide: `--exclude-namespaces` plus the system ones with `--no-system`.
+    pub fn excluded_namespaces(&self) -> Vec<String> {
+        let mut excluded = self.exclude_namespaces.clone();
+        if self.no_system {
+            excluded.extend(SYSTEM_NAMESPACES.iter().map(|ns| (*ns).to_string()));
+        }
+        excluded.retain(|ns| !ns.is_empty());
+        excluded.sort();
+        excluded.dedup();
+        excluded
+    }
+
     /// Parse the resource argument into a list of `ResourceKind` to stream.
-    /// Returns None when the argument is absent (meaning: stream everything).
+    /// Returns None when the argument is absent (meaning: stream everything),
+    /// and when none of its comma-separated kinds is valid (see
+    /// [`parse_resource_kinds`]).
     pub fn resource_filter(&self) -> Option<Vec<ResourceKind>> {
-        let s = self.resource.as_deref()?.to_lowercase();
-        let kinds = match s.as_str() {
-            "pod" | "pods" | "po" => vec![ResourceKind::Pod],
-            "svc" | "service" | "services" => vec![ResourceKind::Service],
-            "deploy" | "deployment" | "deployments" => vec![ResourceKind::Deployment],
-            "sts" | "statefulset" | "statefulsets" => vec![ResourceKind::StatefulSet],
-            "ds" | "daemonset" | "daemonsets" => vec![ResourceKind::DaemonSet],
-            "cm" | "configmap" | "configmaps" => vec![ResourceKind::ConfigMap],
-            "secret" | "secrets" => vec![ResourceKind::Secret],
-            "ing" | "ingress" | "ingresses" => vec![ResourceKind::Ingress],
-            "node" | "nodes" | "no" => vec![ResourceKind::Node],
-            "ns" | "namespace" | "namespaces" => vec![ResourceKind::Namespace],
-            "pv" | "persistentvolume" | "persistentvolumes" => {
-                vec![ResourceKind::PersistentVolume]
+        let (kinds, _) = parse_resource_kinds(self.resource.as_deref()?);
+        (!kinds.is_empty()).then_some(kinds)
+    }
+
+    /// Kinds named by `--exclude`; invalid tokens are left out (see
+    /// [`parse_resource_kinds`]).
+    pub fn excluded_kinds(&self) -> Vec<ResourceKind> {
+        self.exclude
+            .as_deref()
+            .map(|arg| parse_resource_kinds(arg).0)
+            .unwrap_or_default()
+    }
+}
+
+/// `kinds` without the `excluded` ones, in order.
+pub fn apply_exclusions(kinds: &[ResourceKind], excluded: &[ResourceKind]) -> Vec<ResourceKind> {
+    kinds
+        .iter()
+        .filter(|kind| !excluded.contains(kind))
+        .cloned()
+        .collect()
+}
+
+/// Resolve a resource argument — one kind or several separated by commas
+/// (`pod,svc,deploy`) — to the kinds it names, in order and without
+/// duplicates, plus the tokens that can't name a resource (anything but
+/// letters, digits, `-` and `.`). Empty tokens are skipped.
+pub fn parse_resource_kinds(arg: &str) -> (Vec<ResourceKind>, Vec<String>) {
+    let mut kinds = Vec::new();
+    let mut rejected = Vec::new();
+    for token in arg.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let valid = token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+        if !valid {
+            rejected.push(token.to_string());
+            continue;
+        }
+        let kind = resolve_kind(&token.to_lowercase());
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    (kinds, rejected)
+}
+
+/// The kind one (lowercase) alias names.
+fn resolve_kind(alias: &str) -> ResourceKind {
+    match alias {
+        "pod" | "pods" | "po" => ResourceKind::Pod,
+        "svc" | "service" | "services" => ResourceKind::Service,
+        "ep" | "endpoint" | "endpoints" => ResourceKind::Endpoints,
+        "deploy" | "deployment" | "deployments" => ResourceKind::Deployment,
+        "sts" | "statefulset" | "statefulsets" => ResourceKind::StatefulSet,
+        "ds" | "daemonset" | "daemonsets" => ResourceKind::DaemonSet,
+        "cm" | "configmap" | "configmaps" => ResourceKind::ConfigMap,
+        "secret" | "secrets" => ResourceKind::Secret,
+        "ing" | "ingress" | "ingresses" => ResourceKind::Ingress,
+        "node" | "nodes" | "no" => ResourceKind::Node,
+        "ns" | "namespace" | "namespaces" => ResourceKind::Namespace,
+        "pv" | "persistentvolume" | "persistentvolumes" => ResourceKind::PersistentVolume,
+        "pvc" | "persistentvolumeclaim" | "persistentvolumeclaims" => {
+            ResourceKind::PersistentVolumeClaim
+        }
+        "job" | "jobs" => ResourceKind::Job,
+        "cj" | "cronjob" | "cronjobs" => ResourceKind::CronJob,
+        "ev" | "event" | "events" => ResourceKind::Event,
+        "sa" | "serviceaccount" | "serviceaccounts" => ResourceKind::ServiceAccount,
+        "role" | "roles" => ResourceKind::Role,
+        "rolebinding" | "rolebindings" => ResourceKind::RoleBinding,
+        "hpa" | "horizontalpodautoscaler" | "horizontalpodautoscalers" => {
+            ResourceKind::HorizontalPodAutoscaler
+        }
+        // VPA is a CRD: watched through discovery with a curated status.
+        "vpa" | "verticalpodautoscaler" | "verticalpodautoscalers" => {
+            ResourceKind::Custom(VPA_PLURAL.to_string())
+        }
+        // Not a built-in resource — treat as a CRD kind/plural to match
+        // against discovered API resources at runtime.
+        other => ResourceKind::Custom(other.to_string()),
+    }
+}
+
+/// The list header's `res:` label: `all`, or the short names of the kinds
+/// when fewer than all of them are watched.
+pub fn kind_label(kinds: &[ResourceKind]) -> String {
+    if kinds.len() < ALL_KINDS.len() {
+        kinds
+            .iter()
+            .map(ResourceKind::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    } else {
+        "all".to_string()
+    }
+}
+
+// ─── Effective configuration (--print-config) ────────────────────────────────
+
+/// What a session would run with once flags, the config file and kubeconfig
+/// are resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    /// The config file, when one exists.
+    pub config_file: Option<PathBuf>,
+    pub context: String,
+    pub namespace: Option<String>,
+    pub kinds: Vec<ResourceKind>,
+    pub sort: SortField,
+    pub sort_reverse: bool,
+    pub theme: &'static str,
+    /// `--theme`, or `default` when the built-in palette is used.
+    pub theme_source: &'static str,
+    pub read_only: bool,
+    pub key_bindings: Vec<String>,
+    /// See [`Args::config_overrides`].
+    pub overrides: Vec<String>,
+}
+
+impl EffectiveConfig {
+    /// Resolve `args` (already merged with `config`) for a session in
+    /// `context`. `config_file` and `overrides` are left empty.
+    pub fn new(args: &Args, config: &Config, context: &str) -> Self {
+        let (theme, theme_source) = args
+            .theme
+            .map_or((Theme::DARK.name, "default"), |t| (t.name, "--theme"));
+        Self {
+            config_file: None,
+            context: context.to_string(),
+            namespace: args.namespace.clone(),
+            kinds: apply_exclusions(
+                &args.resource_filter().unwrap_or_else(|| ALL_KINDS.to_vec()),
+                &args.excluded_kinds(),
+            ),
+            sort: args
+                .sort
+                .unwrap_or_else(|| SortField::parse(&config.ui.default_sort)),
+            sort_reverse: args.reverse,
+            theme,
+            theme_source,
+            read_only: args.read_only,
+            key_bindings: if args.pick {
+                Vec::new()
+            } else {
+                list_binds(!args.multi_context())
+            },
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Two-column table, one setting per row; list values continue on
+    /// indented lines.
+    pub fn render(&self) -> String {
+        let none = |items: &[String]| {
+            if items.is_empty() {
+                vec!["none".to_string()]
+            } else {
+                items.to_vec()
+            }
+        };
+        let rows: [(&str, Vec<String>); 9] = [
+            (
+                "config file",
+                vec![self
+                    .config_file
+                    .as_ref()
+                    .map_or_else(|| "none".to_string(), |path| path.display().to_string())],
+            ),
+            ("context", vec![self.context.clone()]),
+            (
+                "namespace",
+                vec![self.namespace.clone().unwrap_or_else(|| "all".to_string())],
+            ),
+            (
+                "kinds",
+                vec![self
+                    .kinds
+                    .iter()
+                    .map(ResourceKind::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")],
+            ),
+            (
+                "sort",
+                vec![if self.sort_reverse {
+                    format!("{} (reversed)", self.sort.as_str())
+                } else {
+                    self.sort.as_str().to_string()
+                }],
+            ),
+            (
+                "theme",
+                vec![format!("{} ({})", self.theme, self.theme_source)],
+            ),
+            (
+                "read-only",
+                vec![if self.read_only { "yes" } else { "no" }.to_string()],
+            ),
+            ("config values", none(&self.overrides)),
+            ("key bindings", none(&self.key_bindings)),
+        ];
+        let mut out = String::new();
+        for (label, values) in rows {
+            for (i, value) in values.iter().enumerate() {
+                let label = if i == 0 { label } else { "" };
+                let _ = writeln!(out, "{label:<14}{value}");
             }
-            "pvc" | "persistentvolumeclaim" | "persistentvolumeclaims" => {
-                vec![ResourceKind::PersistentVolumeClaim]
+        }
+        out
+    }
+}
+
+// ─── Label selector validation (-l) ──────────────────────────────────────────
+
+/// Check a `-l/--selector` expression client-side, so a typo is reported
+/// clearly instead of surfacing as an opaque watch error. Accepts the forms
+/// kubectl does: `app=nginx`, `app==nginx`, `app!=nginx`, `env in (a,b)`,
+/// `env notin (a,b)`, `app`, `!app` and `replicas>1`, comma-separated.
+pub fn validate_label_selector(expr: &str) -> anyhow::Result<()> {
+    if expr.trim().is_empty() {
+        anyhow::bail!("label selector is empty");
+    }
+    for requirement in split_requirements(expr)? {
+        validate_requirement(requirement.trim())
+            .map_err(|e| anyhow::anyhow!("invalid label selector '{expr}': {e}"))?;
+    }
+    Ok(())
+}
+
+/// `selector` narrowed by one more requirement (e.g. `app=nginx`), as picked
+/// from a label chip. A requirement already present is not added twice.
+pub fn add_label_requirement(selector: Option<&str>, requirement: &str) -> String {
+    let Some(selector) = selector.map(str::trim).filter(|s| !s.is_empty()) else {
+        return requirement.to_string();
+    };
+    let present = split_requirements(selector)
+        .unwrap_or_default()
+        .iter()
+        .any(|r| r.trim() == requirement);
+    if present {
+        selector.to_string()
+    } else {
+        format!("{selector},{requirement}")
+    }
+}
+
+/// Split on commas that are not inside a `( … )` value set.
+fn split_requirements(expr: &str) -> anyhow::Result<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    anyhow::anyhow!("invalid label selector '{expr}': unmatched ')'")
+                })?;
             }
-            "job" | "jobs" => vec![ResourceKind::Job],
-            "cj" | "cronjob" | "cronjobs" => vec![ResourceKind::CronJob],
-            "ev" | "event" | "events" => vec![ResourceKind::Event],
-            _ => {
-                // Not a built-in resource — treat as a CRD kind/plural to match
-                // against discovered API resources at runtime.
-                vec![ResourceKind::Custom(s)]
+            ',' if depth == 0 => {
+                parts.push(&expr[start..i]);
+                start = i + 1;
             }
-        };
-        Some(kinds)
+            _ => {}
+        }
     }
+    if depth != 0 {
+        anyhow::bail!("invalid label selector '{expr}': unclosed '('");
+    }
+    parts.push(&expr[start..]);
+    Ok(parts)
+}
+
+fn validate_requirement(req: &str) -> Result<(), String> {
+    if req.is_empty() {
+        return Err("empty requirement (stray comma?)".to_string());
+    }
+    // Existence: `!app`
+    if let Some(key) = req.strip_prefix('!') {
+        if !key.contains(['=', '(', '<', '>']) {
+            return validate_label_key(key.trim());
+        }
+    }
+    // Set-based: `env in (prod,staging)`
+    if let Some((head, values)) = req.split_once('(') {
+        let (key, op) = head
+            .trim_end()
+            .rsplit_once(char::is_whitespace)
+            .ok_or_else(|| format!("expected 'in' or 'notin' before '(' in '{req}'"))?;
+        if op != "in" && op != "notin" {
+            return Err(format!(
+                "unknown set operator '{op}' (expected in or notin)"
+            ));
+        }
+        validate_label_key(key.trim())?;
+        let values = values
+            .trim_end()
+            .strip_suffix(')')
+            .ok_or_else(|| format!("trailing text after ')' in '{req}'"))?;
+        for value in values.split(',') {
+            validate_label_value(value.trim())?;
+        }
+        return Ok(());
+    }
+    // Equality, inequality and numeric comparison.
+    if let Some(i) = req.find(['=', '!', '<', '>']) {
+        let key = req[..i].trim();
+        let rest = &req[i..];
+        let (op, value) = ["==", "!=", "=", "<", ">"]
+            .iter()
+            .find_map(|op| rest.strip_prefix(op).map(|v| (*op, v.trim())))
+            .ok_or_else(|| format!("unknown operator in '{req}'"))?;
+        validate_label_key(key)?;
+        if value.is_empty() {
+            return Err(format!("missing value after '{key}{op}'"));
+        }
+        if op == "<" || op == ">" {
+            value
+                .parse::<i64>()
+                .map_err(|_| format!("'{op}' needs an integer value (got '{value}')"))?;
+            return Ok(());
+        }
+        return validate_label_value(value);
+    }
+    // Existence: `app`
+    validate_label_key(req)
+}
+
+/// A label key: an optional DNS-subdomain prefix and `/`, then a name.
+fn validate_label_key(key: &str) -> Result<(), String> {
+    let name = match key.split_once('/') {
+        Some((prefix, name)) => {
+            let valid_prefix = !prefix.is_empty()
+                && prefix.len() <= 253
+                && prefix
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.');
+            if !valid_prefix {
+                return Err(format!("invalid label key prefix '{prefix}'"));
+            }
+            name
+        }
+        None => key,
+    };
+    if name.is_empty() {
+        return Err("missing label key".to_string());
+    }
+    if !is_label_word(name) {
+        return Err(format!(
+            "invalid label key '{key}' (max 63 chars: letters, digits, '-', '_', '.', \
+             starting and ending with a letter or digit)"
+        ));
+    }
+    Ok(())
+}
+
+fn validate_label_value(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("empty value in set".to_string());
+    }
+    if !is_label_word(value) {
+        return Err(format!(
+            "invalid label value '{value}' (max 63 chars: letters, digits, '-', '_', '.', \
+             starting and ending with a letter or digit)"
+        ));
+    }
+    Ok(())
+}
+
+/// Label names and values share a syntax: ≤ 63 chars of `[A-Za-z0-9_.-]`,
+/// beginning and ending with an alphanumeric.
+fn is_label_word(s: &str) -> bool {
+    s.len() <= 63
+        && s.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && s.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
 }
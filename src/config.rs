@@ -1,6 +1,9 @@
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+use crate::items::StatusOverride;
+
 /// Top-level application configuration, loaded from `~/.config/kuberift/config.toml`.
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct Config {
@@ -91,3 +94,51 @@ pub fn parse_config(raw: &str, source: &Path) -> Config {
         Config::default()
     })
 }
+
+// ─── Status overrides ─────────────────────────────────────────────────────────
+
+/// Returns the path to the status overrides file:
+/// `$XDG_CONFIG_HOME/kuberift/status_overrides.toml`.
+pub fn status_overrides_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("kuberift").join("status_overrides.toml"))
+}
+
+/// Load status overrides from the default XDG path. Empty if the file is missing.
+pub fn load_status_overrides() -> Vec<StatusOverride> {
+    status_overrides_path()
+        .and_then(|path| {
+            let raw = std::fs::read_to_string(&path).ok()?;
+            Some(parse_status_overrides(&raw, &path))
+        })
+        .unwrap_or_default()
+}
+
+/// Parse `"<status>" = "<category>"` entries (`Degraded = "critical"`,
+/// `"Sync*" = "warning"`). Warns on parse errors and skips entries whose
+/// category isn't critical, warning, healthy or unknown.
+pub fn parse_status_overrides(raw: &str, source: &Path) -> Vec<StatusOverride> {
+    let entries: BTreeMap<String, String> = match toml::from_str(raw) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!(
+                "[kuberift] warning: failed to parse status overrides '{}': {err}",
+                source.display()
+            );
+            return Vec::new();
+        }
+    };
+    entries
+        .iter()
+        .filter_map(|(status, category)| {
+            let parsed = StatusOverride::new(status, category);
+            if parsed.is_none() {
+                eprintln!(
+                    "[kuberift] warning: {}: \"{status}\" = \"{category}\": category must be \
+                     critical, warning, healthy or unknown",
+                    source.display()
+                );
+            }
+            parsed
+        })
+        .collect()
+}
@@ -1,13 +1,48 @@
+use k8s_openapi::jiff::{tz::TimeZone, Timestamp};
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span},
 };
 use skim::{DisplayContext, ItemPreview, PreviewContext, SkimItem};
 use std::borrow::Cow;
-use std::sync::{Arc, RwLock};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // ─── Name truncation helper ───────────────────────────────────────────────────
 
+/// Name column width used when `--name-width` is not given.
+pub const DEFAULT_NAME_WIDTH: usize = 31;
+
+/// Smallest accepted `--name-width`; anything narrower makes names unreadable.
+pub const MIN_NAME_WIDTH: usize = 8;
+
+static NAME_WIDTH: OnceLock<usize> = OnceLock::new();
+
+/// Set the name column width for the lifetime of the process.
+/// Values below [`MIN_NAME_WIDTH`] are clamped. Only the first call takes effect.
+pub fn set_name_width(width: usize) {
+    let _ = NAME_WIDTH.set(width.max(MIN_NAME_WIDTH));
+}
+
+/// The active name column width (defaults to [`DEFAULT_NAME_WIDTH`]).
+pub fn name_width() -> usize {
+    NAME_WIDTH.get().copied().unwrap_or(DEFAULT_NAME_WIDTH)
+}
+
+/// Format `name` as a fixed-width list column: truncated to [`name_width`] and
+/// padded so the status column lines up across rows.
+pub fn name_column(name: &str) -> String {
+    let width = name_width();
+    let t = truncate_name(name, width);
+    if t.len() <= width {
+        format!("{t:<pad$} ", pad = width + 1)
+    } else {
+        format!("{t} ")
+    }
+}
+
 /// Truncate `name` to at most `max_chars` bytes, appending "…" if truncated.
 /// Always splits on a valid UTF-8 char boundary to avoid panics on multi-byte names.
 pub fn truncate_name(name: &str, max_chars: usize) -> Cow<'_, str> {
@@ -21,6 +56,351 @@ pub fn truncate_name(name: &str, max_chars: usize) -> Cow<'_, str> {
     Cow::Owned(format!("{}…", &name[..end]))
 }
 
+// ─── Age column format (--age-format) ─────────────────────────────────────────
+
+/// How the age column shows when a resource was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgeFormat {
+    /// Elapsed time, e.g. `2d`.
+    #[default]
+    Relative,
+    /// Local date and time, e.g. `2024-05-01 14:03`.
+    Absolute,
+    /// ISO 8601 in UTC, e.g. `2024-05-01T12:03:00Z`.
+    Iso,
+}
+
+impl AgeFormat {
+    pub const ALL: &[AgeFormat] = &[Self::Relative, Self::Absolute, Self::Iso];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Relative => "relative",
+            Self::Absolute => "absolute",
+            Self::Iso => "iso",
+        }
+    }
+}
+
+static AGE_FORMAT: OnceLock<AgeFormat> = OnceLock::new();
+
+/// Set the age column format for the lifetime of the process. Only the first call takes effect.
+pub fn set_age_format(format: AgeFormat) {
+    let _ = AGE_FORMAT.set(format);
+}
+
+/// The active age column format (defaults to [`AgeFormat::Relative`]).
+pub fn age_format() -> AgeFormat {
+    AGE_FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Render a creation time (Unix seconds) in `format`; `?` if it is out of range.
+pub fn format_age(epoch: i64, format: AgeFormat) -> String {
+    let Ok(created) = Timestamp::from_second(epoch) else {
+        return "?".to_string();
+    };
+    match format {
+        AgeFormat::Relative => {
+            crate::k8s::resources::age_since(created).unwrap_or_else(|| "?".to_string())
+        }
+        AgeFormat::Absolute => created
+            .to_zoned(TimeZone::system())
+            .strftime("%Y-%m-%d %H:%M")
+            .to_string(),
+        AgeFormat::Iso => created.to_string(),
+    }
+}
+
+/// The age column for `state`: the watcher's relative age, or the creation
+/// time in the `--age-format` chosen when one is known.
+fn shown_age(state: &ItemState) -> String {
+    match (state.created, age_format()) {
+        (Some(epoch), format) if format != AgeFormat::Relative => format_age(epoch, format),
+        _ => state.age.clone(),
+    }
+}
+
+// ─── Label chips (--show-labels) ──────────────────────────────────────────────
+
+/// Chips shown per row before the rest are summarised as `+N`.
+pub const MAX_LABEL_CHIPS: usize = 3;
+
+static SHOW_LABELS: AtomicBool = AtomicBool::new(false);
+
+/// Show each item's labels as chips after the age column.
+pub fn set_show_labels(show: bool) {
+    SHOW_LABELS.store(show, Ordering::Relaxed);
+}
+
+pub fn show_labels() -> bool {
+    SHOW_LABELS.load(Ordering::Relaxed)
+}
+
+/// `[app=nginx] [tier=web] +2`: the first `max` labels as chips, then how
+/// many were left out. Empty when there are no labels.
+pub fn label_chips(labels: &[(String, String)], max: usize) -> String {
+    let mut chips: Vec<String> = labels
+        .iter()
+        .take(max)
+        .map(|(k, v)| format!("[{k}={v}]"))
+        .collect();
+    if labels.len() > max {
+        chips.push(format!("+{}", labels.len() - max));
+    }
+    chips.join(" ")
+}
+
+// ─── List row fitting ─────────────────────────────────────────────────────────
+
+/// Columns available to a list row; 0 until [`set_list_width`] is called.
+static LIST_WIDTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Reads the list row width afresh from the terminal; `None` when the
+/// terminal size is unknown. Set with [`set_list_width_source`].
+static LIST_WIDTH_SOURCE: Mutex<Option<fn() -> Option<usize>>> = Mutex::new(None);
+
+/// Set when the terminal was resized, so the next [`list_width`] re-reads it.
+static LIST_WIDTH_STALE: AtomicBool = AtomicBool::new(false);
+
+/// Record how many columns a list row may use (0 = unknown, rows are not fitted).
+pub fn set_list_width(width: usize) {
+    LIST_WIDTH.store(width, Ordering::Relaxed);
+}
+
+/// Read the list row width with `source` now and again after every
+/// [`invalidate_list_width`].
+pub fn set_list_width_source(source: fn() -> Option<usize>) {
+    *LIST_WIDTH_SOURCE.lock().unwrap() = Some(source);
+    set_list_width(source().unwrap_or(0));
+    LIST_WIDTH_STALE.store(false, Ordering::Relaxed);
+}
+
+/// Mark the cached width out of date (the terminal was resized); the next
+/// [`list_width`] reads it again from the source, if one is set.
+pub fn invalidate_list_width() {
+    LIST_WIDTH_STALE.store(true, Ordering::Relaxed);
+}
+
+/// The cached list row width, if known.
+pub fn list_width() -> Option<usize> {
+    if LIST_WIDTH_STALE.swap(false, Ordering::Relaxed) {
+        if let Some(source) = *LIST_WIDTH_SOURCE.lock().unwrap() {
+            set_list_width(source().unwrap_or(0));
+        }
+    }
+    match LIST_WIDTH.load(Ordering::Relaxed) {
+        0 => None,
+        width => Some(width),
+    }
+}
+
+// ─── Column selection (--columns) ─────────────────────────────────────────────
+
+/// A list column `--columns` can show, hide or move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Kind,
+    Namespace,
+    Name,
+    Status,
+    Age,
+}
+
+impl Column {
+    pub const ALL: [Column; 5] = [
+        Self::Kind,
+        Self::Namespace,
+        Self::Name,
+        Self::Status,
+        Self::Age,
+    ];
+
+    /// Name as given to `--columns`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Kind => "kind",
+            Self::Namespace => "namespace",
+            Self::Name => "name",
+            Self::Status => "status",
+            Self::Age => "age",
+        }
+    }
+}
+
+/// The columns a row shows, in order. The context prefix follows the
+/// namespace (or the name, when the namespace is hidden); the restart marker
+/// and label chips always come last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns(Vec<Column>);
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self(Column::ALL.to_vec())
+    }
+}
+
+impl Columns {
+    /// Columns in the order given; `None` if `order` is empty or repeats one.
+    pub fn new(order: &[Column]) -> Option<Self> {
+        let repeated = order
+            .iter()
+            .enumerate()
+            .any(|(i, col)| order[..i].contains(col));
+        (!order.is_empty() && !repeated).then(|| Self(order.to_vec()))
+    }
+
+    pub fn order(&self) -> &[Column] {
+        &self.0
+    }
+
+    pub fn shows(&self, column: Column) -> bool {
+        self.0.contains(&column)
+    }
+
+    /// The column shown after `column`, if any.
+    fn after(&self, column: Column) -> Option<Column> {
+        let i = self.0.iter().position(|c| *c == column)?;
+        self.0.get(i + 1).copied()
+    }
+}
+
+static COLUMNS: OnceLock<Columns> = OnceLock::new();
+
+/// Set the list columns for the lifetime of the process. Only the first call takes effect.
+pub fn set_columns(columns: Columns) {
+    let _ = COLUMNS.set(columns);
+}
+
+/// The active list columns (defaults to all of them, in [`Column::ALL`] order).
+pub fn columns() -> &'static Columns {
+    COLUMNS.get_or_init(Columns::default)
+}
+
+/// Shorten `text` to at most `width` terminal columns, ending in "…" when
+/// cut. Wide characters such as CJK count as two columns.
+fn fit_text(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        used += c.width().unwrap_or(0);
+        if used > width - 1 {
+            break;
+        }
+        out.push(c);
+    }
+    out.push('…');
+    out
+}
+
+// ─── Preview wrapping ────────────────────────────────────────────────────────
+
+/// Columns between tab stops when expanding tabs in preview text.
+const TAB_WIDTH: usize = 8;
+
+static DESCRIBE_WIDTH: OnceLock<usize> = OnceLock::new();
+
+/// Fix the preview wrap column (`--describe-width`); 0 disables wrapping.
+/// Without a call the preview wraps at the pane width. Only the first call takes effect.
+pub fn set_describe_width(width: usize) {
+    let _ = DESCRIBE_WIDTH.set(width);
+}
+
+/// Column to wrap preview text at for a pane `pane_width` columns wide (0 = don't wrap).
+pub fn preview_wrap_width(pane_width: usize) -> usize {
+    DESCRIBE_WIDTH.get().copied().unwrap_or(pane_width)
+}
+
+/// Expand tabs to [`TAB_WIDTH`] stops.
+fn expand_tabs(line: &str) -> Cow<'_, str> {
+    if !line.contains('\t') {
+        return Cow::Borrowed(line);
+    }
+    let mut out = String::with_capacity(line.len() + TAB_WIDTH);
+    let mut col = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            let pad = TAB_WIDTH - col % TAB_WIDTH;
+            out.extend(std::iter::repeat_n(' ', pad));
+            col += pad;
+        } else {
+            out.push(c);
+            col += 1;
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Line under the preview header carrying a pod's [`ItemState::detail`];
+/// nothing when there is none.
+pub fn detail_line(detail: &str) -> String {
+    if detail.is_empty() {
+        String::new()
+    } else {
+        format!("⚠ {detail}\n")
+    }
+}
+
+/// Reflow preview text for a pane `width` columns wide: tabs are expanded and
+/// long lines soft-wrap at spaces, continuing under the line's own indent so
+/// `kubectl describe` sections stay readable. Words longer than a line are
+/// split. `width == 0` only expands tabs.
+pub fn wrap_preview(text: &str, width: usize) -> String {
+    let mut out = String::with_capacity(text.len());
+    for raw in text.lines() {
+        let line = expand_tabs(raw);
+        if width == 0 || line.chars().count() <= width {
+            out.push_str(&line);
+            out.push('\n');
+            continue;
+        }
+
+        let indent_len = line.len() - line.trim_start_matches(' ').len();
+        let indent = if indent_len < width / 2 {
+            &line[..indent_len]
+        } else {
+            ""
+        };
+        let mut cur = line[..indent_len.min(width - 1)].to_string();
+        let mut cur_len = cur.len();
+        let mut at_start = true;
+
+        for word in line[indent_len..].split(' ') {
+            let mut chars: Vec<char> = word.chars().collect();
+            if !at_start && cur_len + 1 + chars.len() > width {
+                out.push_str(cur.trim_end());
+                out.push('\n');
+                cur = indent.to_string();
+                cur_len = indent.len();
+                at_start = true;
+            }
+            if !at_start {
+                cur.push(' ');
+                cur_len += 1;
+            }
+            while cur_len + chars.len() > width {
+                let take = width.saturating_sub(cur_len).max(1).min(chars.len());
+                cur.extend(chars.drain(..take));
+                out.push_str(&cur);
+                out.push('\n');
+                cur = indent.to_string();
+                cur_len = indent.len();
+            }
+            cur_len += chars.len();
+            cur.extend(chars);
+            at_start = false;
+        }
+        out.push_str(cur.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
 // ─── Status health classification ────────────────────────────────────────────
 
 /// Health category for a Kubernetes resource status string.
@@ -37,10 +417,156 @@ pub enum StatusHealth {
     Unknown,
 }
 
+// ─── Compact status (--compact-status) ────────────────────────────────────────
+
+/// Long statuses and the short forms `--compact-status` shows in the list.
+const STATUS_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("kubernetes.io/service-account-token", "sa-token"),
+    ("kubernetes.io/dockerconfigjson", "docker-cfg"),
+    ("kubernetes.io/dockercfg", "docker-cfg"),
+    ("kubernetes.io/basic-auth", "basic-auth"),
+    ("kubernetes.io/ssh-auth", "ssh-auth"),
+    ("kubernetes.io/tls", "tls"),
+    ("bootstrap.kubernetes.io/token", "bootstrap"),
+    ("LoadBalancer", "LB"),
+    ("ExternalName", "ExtName"),
+    ("ContainerCreating", "Creating"),
+    ("PodInitializing", "Initializing"),
+    ("CrashLoopBackOff", "CrashLoop"),
+    ("ImagePullBackOff", "PullBackOff"),
+    ("CreateContainerConfigError", "ConfigError"),
+];
+
+static COMPACT_STATUS: AtomicBool = AtomicBool::new(false);
+
+/// Abbreviate long statuses in the list column (see [`abbreviate_status`]).
+pub fn set_compact_status(compact: bool) {
+    COMPACT_STATUS.store(compact, Ordering::Relaxed);
+}
+
+pub fn compact_status() -> bool {
+    COMPACT_STATUS.load(Ordering::Relaxed)
+}
+
+/// Short form of a known long status, keeping a restart count suffix
+/// (`CrashLoopBackOff (x7)` → `CrashLoop (x7)`); anything else unchanged.
+pub fn abbreviate_status(status: &str) -> Cow<'_, str> {
+    let base = without_restart_count(status);
+    match STATUS_ABBREVIATIONS.iter().find(|(long, _)| *long == base) {
+        Some((_, short)) => Cow::Owned(format!("{short}{}", &status[base.len()..])),
+        None => Cow::Borrowed(status),
+    }
+}
+
+/// `status` without a trailing pod restart count, e.g. `OOMKilled (x3)` → `OOMKilled`.
+pub fn without_restart_count(status: &str) -> &str {
+    split_restart_count(status).map_or(status, |(base, _)| base)
+}
+
+/// The trailing pod restart count of `status`, e.g. `Running (x12)` → 12.
+pub fn restart_count(status: &str) -> Option<u64> {
+    split_restart_count(status)?.1.parse().ok()
+}
+
+fn split_restart_count(status: &str) -> Option<(&str, &str)> {
+    let (base, count) = status.strip_suffix(')')?.rsplit_once(" (x")?;
+    (!count.is_empty() && count.bytes().all(|b| b.is_ascii_digit())).then_some((base, count))
+}
+
+/// `status` without the note [`crate::k8s::resources::with_recent_crash`]
+/// adds, e.g. `Running (debug) (restarted 2m ago)` → `Running (debug)`;
+/// `None` when there is none.
+pub fn without_crash_note(status: &str) -> Option<&str> {
+    let (base, age) = status.strip_suffix(" ago)")?.rsplit_once(" (restarted ")?;
+    (!age.is_empty()).then_some(base)
+}
+
+/// A running pod restarted more than this many times shows `Running (xN)`
+/// and is a warning.
+pub const RESTART_WARNING_THRESHOLD: u64 = 5;
+
+// ─── Status overrides (status_overrides.toml) ─────────────────────────────────
+
+/// A user rule from `status_overrides.toml`: statuses equal to `pattern` — or
+/// starting with it, when `prefix` is set — classify as `health`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusOverride {
+    pub pattern: String,
+    pub prefix: bool,
+    pub health: StatusHealth,
+}
+
+impl StatusOverride {
+    /// Parse one `"<status>" = "<category>"` entry; a trailing `*` on the
+    /// status makes it a prefix. `None` when the category isn't a tier name.
+    pub fn new(pattern: &str, category: &str) -> Option<Self> {
+        let health = StatusHealth::from_name(category)?;
+        Some(match pattern.strip_suffix('*') {
+            Some(prefix) => Self {
+                pattern: prefix.to_string(),
+                prefix: true,
+                health,
+            },
+            None => Self {
+                pattern: pattern.to_string(),
+                prefix: false,
+                health,
+            },
+        })
+    }
+
+    fn matches(&self, status: &str) -> bool {
+        if self.prefix {
+            status.starts_with(&self.pattern)
+        } else {
+            status == self.pattern
+        }
+    }
+}
+
+static STATUS_OVERRIDES: OnceLock<Vec<StatusOverride>> = OnceLock::new();
+
+/// Install the user's overrides; only the first call counts.
+pub fn set_status_overrides(overrides: Vec<StatusOverride>) {
+    let _ = STATUS_OVERRIDES.set(overrides);
+}
+
+fn status_overrides() -> &'static [StatusOverride] {
+    STATUS_OVERRIDES.get().map_or(&[], Vec::as_slice)
+}
+
+/// The category `overrides` give `status`: an exact match wins, then the
+/// longest matching prefix.
+pub fn override_for(status: &str, overrides: &[StatusOverride]) -> Option<StatusHealth> {
+    overrides
+        .iter()
+        .filter(|o| o.matches(status))
+        .max_by_key(|o| (!o.prefix, o.pattern.len()))
+        .map(|o| o.health)
+}
+
 impl StatusHealth {
-    /// Classify a status string into a health category.
+    /// Classify a status string into a health category, consulting the
+    /// user's `status_overrides.toml` first. A restart count suffix (` (x7)`)
+    /// doesn't change the category, except that a `Running` pod above
+    /// [`RESTART_WARNING_THRESHOLD`] is a warning.
     pub fn classify(status: &str) -> Self {
-        match status {
+        Self::classify_with(status, status_overrides())
+    }
+
+    /// [`StatusHealth::classify`] with an explicit set of overrides.
+    pub fn classify_with(status: &str, overrides: &[StatusOverride]) -> Self {
+        if let Some(health) = override_for(without_restart_count(status), overrides) {
+            return health;
+        }
+        // A recent crash makes a pod a warning, or whatever worse its status is.
+        if let Some(base) = without_crash_note(status) {
+            return match Self::classify_with(base, overrides) {
+                Self::Critical => Self::Critical,
+                _ => Self::Warning,
+            };
+        }
+        match without_restart_count(status) {
             // ── Exact critical matches ────────────────────────────────────────
             "Failed" | "Error" | "OOMKilled" | "NotReady" | "Lost" | "Evicted" | "BackOff" => {
                 Self::Critical
@@ -52,25 +578,37 @@ impl StatusHealth {
                 || s.starts_with("Init:Error")
                 || s.starts_with("Init:ErrImage")
                 || s.starts_with("Init:ImagePull")
-                || s.starts_with("Failed(") =>
+                || s.starts_with("Failed(")
+                || s.starts_with("NotReady (") =>
             {
                 Self::Critical
             }
             // ── Exact warning matches ─────────────────────────────────────────
-            "Pending" | "Terminating" | "ContainerCreating" | "Unknown" => Self::Warning,
+            "Pending" | "Terminating" | "ContainerCreating" | "Unknown" | "Bound (unused)"
+            | "0 ready" => Self::Warning,
             // ── Prefix-based warning matches ──────────────────────────────────
-            s if s.starts_with("Init:") => Self::Warning,
+            s if s.starts_with("Init:")
+                || s.starts_with("Unknown (")
+                || s.starts_with("Undersized(") =>
+            {
+                Self::Warning
+            }
+            // ── Running, but restarting a lot ─────────────────────────────────
+            "Running" if restart_count(status).is_some_and(|n| n > RESTART_WARNING_THRESHOLD) => {
+                Self::Warning
+            }
             // ── Deleted ───────────────────────────────────────────────────────
             "[DELETED]" => Self::Unknown,
             // ── Exact healthy matches ─────────────────────────────────────────
-            "Running" | "Active" | "Bound" | "Complete" | "Succeeded" | "Ready" | "Scheduled"
-            | "ClusterIP" | "NodePort" | "LoadBalancer" => Self::Healthy,
+            "Running" | "Running (debug)" | "Active" | "Bound" | "Complete" | "Succeeded"
+            | "Ready" | "Scheduled" | "ClusterIP" | "NodePort" | "LoadBalancer" => Self::Healthy,
             // ── Prefix-based healthy ──────────────────────────────────────────
-            s if s.starts_with("Active(") => Self::Healthy,
-            // ── Ratio: "3/3" healthy, "1/3" warning ──────────────────────────
+            s if s.starts_with("Active(") || s.starts_with("Ready (") => Self::Healthy,
+            // ── Ratio: "3/3" healthy, "1/3" warning; detail after the counts
+            // ("5/5 (cpu 80%)") doesn't count ─────────────────────────────────
             s if s.contains('/') => {
                 let parts: Vec<&str> = s.splitn(2, '/').collect();
-                if parts.len() == 2 && parts[0] == parts[1] {
+                if parts.len() == 2 && parts[1].split_whitespace().next() == Some(parts[0]) {
                     Self::Healthy
                 } else {
                     Self::Warning
@@ -81,16 +619,34 @@ impl StatusHealth {
         }
     }
 
-    /// Terminal color for this health category.
+    /// Terminal color for this health category in the active [`theme`].
     pub fn color(self) -> Color {
+        theme().health_color(self)
+    }
+
+    /// Lowercase label used in reports and legends.
+    pub fn as_str(self) -> &'static str {
         match self {
-            Self::Critical => Color::Red,
-            Self::Warning => Color::Yellow,
-            Self::Healthy => Color::Green,
-            Self::Unknown => Color::DarkGray,
+            Self::Critical => "critical",
+            Self::Warning => "warning",
+            Self::Healthy => "healthy",
+            Self::Unknown => "unknown",
         }
     }
 
+    /// A few representative statuses, shown in the `--help-colors` legend.
+    pub fn examples(self) -> &'static [&'static str] {
+        match self {
+            Self::Critical => &["CrashLoopBackOff", "Error", "ImagePullBackOff", "OOMKilled"],
+            Self::Warning => &["Pending", "Terminating", "Init:0/1", "1/3"],
+            Self::Healthy => &["Running", "Active", "Bound", "3/3"],
+            Self::Unknown => &["[DELETED]"],
+        }
+    }
+
+    /// Every tier, most severe first.
+    pub const ALL: &[StatusHealth] = &[Self::Critical, Self::Warning, Self::Healthy, Self::Unknown];
+
     /// Sort priority: 0 = top of list (critical), 1 = middle, 2 = bottom (healthy).
     /// Skim renders higher-indexed items at the top, so lower priority = sent last.
     pub fn priority(self) -> u8 {
@@ -100,6 +656,66 @@ impl StatusHealth {
             Self::Healthy => 2,
         }
     }
+
+    /// Parse a tier name as printed by [`StatusHealth::as_str`] (case-insensitive).
+    pub fn from_name(s: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|h| h.as_str().eq_ignore_ascii_case(s.trim()))
+    }
+
+    /// Whether this tier is at least as severe as `min`.
+    /// Unknown (deleted / unavailable) ranks below healthy.
+    pub fn at_least(self, min: StatusHealth) -> bool {
+        let rank = |h: StatusHealth| match h {
+            Self::Critical => 3,
+            Self::Warning => 2,
+            Self::Healthy => 1,
+            Self::Unknown => 0,
+        };
+        rank(self) >= rank(min)
+    }
+}
+
+/// Number of resources in `items` counted by `--count`: deleted rows never
+/// count, and `min_severity` drops anything healthier.
+pub fn count_matching(items: &[K8sItem], min_severity: Option<StatusHealth>) -> usize {
+    items
+        .iter()
+        .map(|item| StatusHealth::classify(&item.status()))
+        .filter(|health| *health != StatusHealth::Unknown)
+        .filter(|health| min_severity.is_none_or(|min| health.at_least(min)))
+        .count()
+}
+
+/// Counts for the loaded-list summary: every row, then how many of them are
+/// critical and how many are warnings.
+pub fn summarize(items: &[K8sItem]) -> (usize, usize, usize) {
+    items.iter().fold(
+        (0, 0, 0),
+        |(total, crit, warn), item| match StatusHealth::classify(&item.status()) {
+            StatusHealth::Critical => (total + 1, crit + 1, warn),
+            StatusHealth::Warning => (total + 1, crit, warn + 1),
+            _ => (total + 1, crit, warn),
+        },
+    )
+}
+
+// ─── Kind visibility (alt-k) ─────────────────────────────────────────────────
+
+/// Whether resources of `kind` are shown, given the set hidden at runtime.
+pub fn kind_visible(kind: &ResourceKind, hidden: &HashSet<ResourceKind>) -> bool {
+    !hidden.contains(kind)
+}
+
+/// Flip the visibility of each kind in `toggled`.
+pub fn toggle_hidden(hidden: &mut HashSet<ResourceKind>, toggled: &[ResourceKind]) {
+    for kind in toggled {
+        if !hidden.remove(kind) {
+            hidden.insert(kind.clone());
+        }
+    }
 }
 
 /// How to sort the initial resource batch.
@@ -159,6 +775,53 @@ impl std::fmt::Display for SortField {
     }
 }
 
+/// Where skim draws the list (`--layout`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// Prompt at the bottom; the last item of a batch is drawn at the top.
+    #[default]
+    Default,
+    /// Prompt at the top; the first item of a batch is drawn at the top.
+    Reverse,
+}
+
+impl Layout {
+    pub const ALL: &[Layout] = &[Self::Default, Self::Reverse];
+
+    /// Name as given to `--layout` and to skim's `layout` option.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Reverse => "reverse",
+        }
+    }
+}
+
+/// How selected resources are printed (`--output`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// `kind/namespace/name` lines with `--pick`, `kubectl describe` otherwise.
+    #[default]
+    Text,
+    /// A JSON array of the selected items.
+    Json,
+    /// A JSON object mapping each context to an array of its selected items.
+    GroupedJson,
+}
+
+impl OutputFormat {
+    pub const ALL: &[OutputFormat] = &[Self::Text, Self::Json, Self::GroupedJson];
+
+    /// Name as given to `--output`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::GroupedJson => "grouped-json",
+        }
+    }
+}
+
 /// The kind of Kubernetes resource this item represents.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -178,6 +841,11 @@ pub enum ResourceKind {
     Job,
     CronJob,
     Event,
+    HorizontalPodAutoscaler,
+    Endpoints,
+    ServiceAccount,
+    Role,
+    RoleBinding,
     Custom(String),
 }
 
@@ -199,24 +867,24 @@ impl ResourceKind {
             Self::Job => "job",
             Self::CronJob => "cronjob",
             Self::Event => "event",
+            Self::HorizontalPodAutoscaler => "hpa",
+            Self::Endpoints => "ep",
+            Self::ServiceAccount => "sa",
+            Self::Role => "role",
+            Self::RoleBinding => "rolebinding",
             Self::Custom(s) => s,
         }
     }
 
+    /// Color of this kind in the active [`theme`].
     pub fn color(&self) -> Color {
-        match self {
-            Self::Pod => Color::Green,
-            Self::Service => Color::Blue,
-            Self::Deployment | Self::StatefulSet | Self::DaemonSet => Color::Yellow,
-            Self::ConfigMap | Self::Secret => Color::Magenta,
-            Self::Ingress => Color::Cyan,
-            Self::Node | Self::Namespace => Color::White,
-            Self::PersistentVolume => Color::LightCyan,
-            Self::PersistentVolumeClaim => Color::LightMagenta,
-            Self::Job | Self::CronJob => Color::LightBlue,
-            Self::Event => Color::Gray,
-            Self::Custom(_) => Color::LightYellow,
-        }
+        theme().kind_color(self)
+    }
+
+    /// Whether objects of this kind live outside any namespace. A CRD's scope
+    /// is only known after discovery, so `Custom` kinds count as namespaced.
+    pub fn is_cluster_scoped(&self) -> bool {
+        matches!(self, Self::Node | Self::Namespace | Self::PersistentVolume)
     }
 }
 
@@ -226,32 +894,720 @@ impl std::fmt::Display for ResourceKind {
     }
 }
 
-/// Live-updated mutable state shared between a [`K8sItem`] in skim's list
-/// and the watcher that streams updates from the Kubernetes API.
-#[derive(Debug, Clone)]
-pub struct ItemState {
-    pub status: String,
-    pub age: String,
-}
+// ─── Themes (--theme) ─────────────────────────────────────────────────────────
 
-/// A Kubernetes resource item displayed in the skim TUI.
-///
-/// Mutable display state (status, age) lives behind an [`Arc<RwLock<ItemState>>`]
-/// so the watcher can update a resource in-place without sending a duplicate
-/// entry to skim.
-#[derive(Debug, Clone)]
-pub struct K8sItem {
-    kind: ResourceKind,
-    namespace: String,
-    name: String,
-    /// The cluster context this resource belongs to (empty in single-cluster mode).
-    context: String,
-    state: Arc<RwLock<ItemState>>,
+/// Orange, used by the colorblind preset in place of red.
+const ORANGE: Color = Color::Rgb(230, 159, 0);
+
+/// Colors for resource kinds and status health. Kinds that are shown together
+/// in the list share a slot (e.g. all workload controllers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub pod: Color,
+    pub service: Color,
+    pub workload: Color,
+    pub config: Color,
+    pub ingress: Color,
+    pub cluster: Color,
+    pub pv: Color,
+    pub pvc: Color,
+    pub job: Color,
+    pub event: Color,
+    /// ServiceAccounts, Roles and RoleBindings.
+    pub rbac: Color,
+    pub custom: Color,
+    pub critical: Color,
+    pub warning: Color,
+    pub healthy: Color,
+    pub unknown: Color,
 }
 
-impl K8sItem {
-    pub fn new(
-        kind: ResourceKind,
+impl Theme {
+    /// The default palette, tuned for dark terminal backgrounds.
+    pub const DARK: Self = Self {
+        name: "dark",
+        pod: Color::Green,
+        service: Color::Blue,
+        workload: Color::Yellow,
+        config: Color::Magenta,
+        ingress: Color::Cyan,
+        cluster: Color::White,
+        pv: Color::LightCyan,
+        pvc: Color::LightMagenta,
+        job: Color::LightBlue,
+        event: Color::Gray,
+        rbac: Color::LightRed,
+        custom: Color::LightYellow,
+        critical: Color::Red,
+        warning: Color::Yellow,
+        healthy: Color::Green,
+        unknown: Color::DarkGray,
+    };
+
+    /// For light backgrounds: no yellow, white or light variants, which are
+    /// hard to read on white.
+    pub const LIGHT: Self = Self {
+        name: "light",
+        pod: Color::Green,
+        service: Color::Blue,
+        workload: Color::Magenta,
+        config: Color::Cyan,
+        ingress: Color::Cyan,
+        cluster: Color::DarkGray,
+        pv: Color::Blue,
+        pvc: Color::Magenta,
+        job: Color::Blue,
+        event: Color::DarkGray,
+        rbac: Color::Red,
+        custom: Color::Magenta,
+        critical: Color::Red,
+        warning: Color::Magenta,
+        healthy: Color::Green,
+        unknown: Color::DarkGray,
+    };
+
+    /// Bright variants throughout, for low-contrast terminals and projectors.
+    pub const HIGH_CONTRAST: Self = Self {
+        name: "high-contrast",
+        pod: Color::LightGreen,
+        service: Color::LightBlue,
+        workload: Color::LightYellow,
+        config: Color::LightMagenta,
+        ingress: Color::LightCyan,
+        cluster: Color::White,
+        pv: Color::LightCyan,
+        pvc: Color::LightMagenta,
+        job: Color::LightBlue,
+        event: Color::White,
+        rbac: Color::LightRed,
+        custom: Color::LightYellow,
+        critical: Color::LightRed,
+        warning: Color::LightYellow,
+        healthy: Color::LightGreen,
+        unknown: Color::White,
+    };
+
+    /// Avoids the red/green pair: orange for critical, blue for healthy.
+    pub const COLORBLIND: Self = Self {
+        name: "colorblind",
+        pod: Color::Cyan,
+        service: Color::Blue,
+        workload: Color::Yellow,
+        config: Color::Magenta,
+        ingress: Color::Cyan,
+        cluster: Color::White,
+        pv: Color::LightCyan,
+        pvc: Color::LightMagenta,
+        job: Color::LightBlue,
+        event: Color::Gray,
+        rbac: Color::Magenta,
+        custom: Color::LightYellow,
+        critical: ORANGE,
+        warning: Color::LightYellow,
+        healthy: Color::Blue,
+        unknown: Color::DarkGray,
+    };
+
+    /// Every built-in preset, in the order listed by `--help`.
+    pub const PRESETS: [Self; 4] = [
+        Self::DARK,
+        Self::LIGHT,
+        Self::HIGH_CONTRAST,
+        Self::COLORBLIND,
+    ];
+
+    /// Look up a preset by name (case-insensitive).
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::PRESETS
+            .into_iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn kind_color(&self, kind: &ResourceKind) -> Color {
+        match kind {
+            ResourceKind::Pod => self.pod,
+            ResourceKind::Service | ResourceKind::Endpoints => self.service,
+            ResourceKind::Deployment
+            | ResourceKind::StatefulSet
+            | ResourceKind::DaemonSet
+            | ResourceKind::HorizontalPodAutoscaler => self.workload,
+            ResourceKind::ConfigMap | ResourceKind::Secret => self.config,
+            ResourceKind::Ingress => self.ingress,
+            ResourceKind::Node | ResourceKind::Namespace => self.cluster,
+            ResourceKind::PersistentVolume => self.pv,
+            ResourceKind::PersistentVolumeClaim => self.pvc,
+            ResourceKind::Job | ResourceKind::CronJob => self.job,
+            ResourceKind::Event => self.event,
+            ResourceKind::ServiceAccount | ResourceKind::Role | ResourceKind::RoleBinding => {
+                self.rbac
+            }
+            ResourceKind::Custom(_) => self.custom,
+        }
+    }
+
+    pub fn health_color(&self, health: StatusHealth) -> Color {
+        match health {
+            StatusHealth::Critical => self.critical,
+            StatusHealth::Warning => self.warning,
+            StatusHealth::Healthy => self.healthy,
+            StatusHealth::Unknown => self.unknown,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Set the color theme for the lifetime of the process. Only the first call takes effect.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The active color theme (defaults to [`Theme::DARK`]).
+pub fn theme() -> &'static Theme {
+    THEME.get().unwrap_or(&Theme::DARK)
+}
+
+// ─── Color legend (--help-colors) ─────────────────────────────────────────────
+
+/// Human-readable name of a terminal color.
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::White => "white",
+        Color::Gray => "gray",
+        Color::DarkGray => "dark gray",
+        Color::LightRed => "light red",
+        Color::LightGreen => "light green",
+        Color::LightYellow => "light yellow",
+        Color::LightBlue => "light blue",
+        Color::LightMagenta => "light magenta",
+        Color::LightCyan => "light cyan",
+        ORANGE => "orange",
+        _ => "default",
+    }
+}
+
+/// ANSI SGR foreground parameters matching how ratatui renders the color.
+pub(crate) fn ansi_fg(color: Color) -> String {
+    let code = match color {
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Rgb(r, g, b) => return format!("38;2;{r};{g};{b}"),
+        _ => 39,
+    };
+    code.to_string()
+}
+
+/// Legend of every resource-kind color and status-health tier.
+/// With `ansi`, each label is printed in its own color so the legend demonstrates itself.
+pub fn color_legend(ansi: bool) -> String {
+    use std::fmt::Write;
+
+    let paint = |text: String, color: Color| {
+        if ansi {
+            format!("\x1b[{}m{text}\x1b[0m", ansi_fg(color))
+        } else {
+            text
+        }
+    };
+    let custom = ResourceKind::Custom("<crd>".to_string());
+
+    let mut out = String::from("Resource kinds\n");
+    for kind in crate::k8s::resources::ALL_KINDS
+        .iter()
+        .chain(std::iter::once(&custom))
+    {
+        let color = kind.color();
+        let _ = writeln!(
+            out,
+            "  {}  {}",
+            paint(format!("{:<11}", kind.as_str()), color),
+            color_name(color)
+        );
+    }
+
+    out.push_str("\nStatus health\n");
+    for health in StatusHealth::ALL {
+        let color = health.color();
+        let _ = writeln!(
+            out,
+            "  {}  {:<10} {}",
+            paint(format!("{:<11}", health.as_str()), color),
+            color_name(color),
+            health.examples().join(", ")
+        );
+    }
+    out
+}
+
+// ─── Demo data and theme preview (--theme-preview) ────────────────────────────
+
+/// Sample resources: every kind (and a custom resource) at least once, with
+/// statuses covering every [`StatusHealth`] tier. Listed when no cluster is
+/// reachable and by `--theme-preview`.
+pub fn demo_items() -> Vec<K8sItem> {
+    vec![
+        K8sItem::new(
+            ResourceKind::Pod,
+            "production",
+            "api-server-7d9f8b6c5-xk2lp",
+            "CrashLoopBackOff",
+            "1h",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Pod,
+            "staging",
+            "frontend-5c7d8e9f0-ab1cd",
+            "Pending",
+            "5m",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Pod,
+            "production",
+            "worker-6f8b9c4d7-mn3qr",
+            "Running",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Pod,
+            "production",
+            "worker-6f8b9c4d7-zz9yx",
+            "[DELETED]",
+            "3d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Deployment,
+            "production",
+            "api-server",
+            "2/3",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Deployment,
+            "staging",
+            "frontend",
+            "0/1",
+            "5m",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::StatefulSet,
+            "production",
+            "postgres",
+            "3/3",
+            "12d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::DaemonSet,
+            "kube-system",
+            "fluentd",
+            "2/3",
+            "30d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Service,
+            "production",
+            "api-service",
+            "ClusterIP",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Endpoints,
+            "staging",
+            "frontend",
+            "0 ready",
+            "5m",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::ServiceAccount,
+            "production",
+            "api-server",
+            "0 secrets",
+            "30d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::RoleBinding,
+            "production",
+            "api-server-reader",
+            "2 subjects",
+            "30d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Ingress,
+            "production",
+            "api-ingress",
+            "10.0.0.5",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::ConfigMap,
+            "production",
+            "app-config",
+            "ConfigMap",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Secret,
+            "production",
+            "api-tls",
+            "kubernetes.io/tls",
+            "30d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Node,
+            "",
+            "kind-control-plane",
+            "Ready (control-plane, v1.29.2)",
+            "7d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Namespace,
+            "",
+            "production",
+            "Active",
+            "30d",
+            "",
+        ),
+        K8sItem::new(ResourceKind::Namespace, "", "staging", "Active", "10d", ""),
+        K8sItem::new(
+            ResourceKind::PersistentVolume,
+            "",
+            "pvc-4f1c2a9e",
+            "Bound",
+            "12d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::PersistentVolumeClaim,
+            "production",
+            "scratch",
+            "Bound (unused)",
+            "4d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Job,
+            "production",
+            "db-migrate-28711",
+            "Failed(1)",
+            "40m",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::CronJob,
+            "production",
+            "nightly-backup",
+            "Scheduled",
+            "12d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Event,
+            "production",
+            "api-server-7d9f8b6c5-xk2lp.17c2a",
+            "BackOff",
+            "1m",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::HorizontalPodAutoscaler,
+            "production",
+            "api-server",
+            "2/3 (cpu 91%)",
+            "2d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::Custom("Certificate".to_string()),
+            "production",
+            "api-tls",
+            "Ready",
+            "30d",
+            "",
+        ),
+    ]
+}
+
+/// `line` as plain text; with `ansi`, each span in its foreground color.
+pub fn line_to_ansi(line: &Line, ansi: bool) -> String {
+    line.spans
+        .iter()
+        .map(|span| match span.style.fg {
+            Some(color) if ansi => format!("\x1b[{}m{}\x1b[0m", ansi_fg(color), span.content),
+            _ => span.content.to_string(),
+        })
+        .collect()
+}
+
+/// The [`demo_items`] rows exactly as the list draws them, in the active
+/// theme, under a line naming it.
+pub fn theme_preview(ansi: bool) -> String {
+    let mut out = format!("Theme: {}\n\n", theme().name);
+    for item in demo_items() {
+        out.push_str(&line_to_ansi(&item.row_line(None), ansi));
+        out.push('\n');
+    }
+    out
+}
+
+// ─── Describe + events (preview mode 0) ──────────────────────────────────────
+
+/// `kubectl get events` args for the events appended below the describe
+/// preview. `kubectl describe` truncates its own event list, and events are
+/// usually what explains a failure, so pods and deployments that are
+/// Critical or Warning get the full list. `None` for everything else, which
+/// keeps the common (healthy) preview to a single kubectl call.
+pub fn describe_events_args(item: &K8sItem) -> Option<Vec<String>> {
+    if !matches!(item.kind(), ResourceKind::Pod | ResourceKind::Deployment) {
+        return None;
+    }
+    let health = StatusHealth::classify(&item.status());
+    if !matches!(health, StatusHealth::Critical | StatusHealth::Warning) {
+        return None;
+    }
+    let mut args = events_args(item);
+    args.push("--sort-by=.lastTimestamp".to_string());
+    Some(args)
+}
+
+// ─── Events (preview mode 3) ──────────────────────────────────────────────────
+
+/// `kubectl get events` args for the events preview: every event whose
+/// involved object is `item` (matched by name and kind), with `-n` and
+/// `--context` like the other modes.
+pub fn events_args(item: &K8sItem) -> Vec<String> {
+    // Map our short kind names to the Kubernetes Kind for field-selector
+    let k8s_kind = match item.kind() {
+        ResourceKind::Pod => "Pod",
+        ResourceKind::Service => "Service",
+        ResourceKind::Deployment => "Deployment",
+        ResourceKind::StatefulSet => "StatefulSet",
+        ResourceKind::DaemonSet => "DaemonSet",
+        ResourceKind::ConfigMap => "ConfigMap",
+        ResourceKind::Secret => "Secret",
+        ResourceKind::Ingress => "Ingress",
+        ResourceKind::Node => "Node",
+        ResourceKind::Namespace => "Namespace",
+        ResourceKind::PersistentVolume => "PersistentVolume",
+        ResourceKind::PersistentVolumeClaim => "PersistentVolumeClaim",
+        ResourceKind::Job => "Job",
+        ResourceKind::CronJob => "CronJob",
+        ResourceKind::Event => "Event",
+        ResourceKind::HorizontalPodAutoscaler => "HorizontalPodAutoscaler",
+        ResourceKind::Endpoints => "Endpoints",
+        ResourceKind::ServiceAccount => "ServiceAccount",
+        ResourceKind::Role => "Role",
+        ResourceKind::RoleBinding => "RoleBinding",
+        ResourceKind::Custom(s) => s.as_str(),
+    };
+    let mut args = vec![
+        "get".to_string(),
+        "events".to_string(),
+        "--field-selector".to_string(),
+        format!(
+            "involvedObject.name={},involvedObject.kind={k8s_kind}",
+            item.name()
+        ),
+    ];
+    if !item.namespace().is_empty() {
+        args.extend(["-n".to_string(), item.namespace().to_string()]);
+    }
+    if !item.context().is_empty() {
+        args.extend(["--context".to_string(), item.context().to_string()]);
+    }
+    args
+}
+
+// ─── Related resources (preview mode 4) ──────────────────────────────────────
+
+/// `kubectl get` args listing the `resource` objects in `namespace`, one per
+/// line: the name, a tab and the controlling owner as `Kind/name` (empty
+/// without one). [`owned_by`] picks out the children of one owner.
+fn owned_args(resource: &str, namespace: &str) -> Vec<String> {
+    let mut args = vec!["get".to_string(), resource.to_string()];
+    if !namespace.is_empty() {
+        args.extend(["-n".to_string(), namespace.to_string()]);
+    }
+    args.extend([
+        "-o".to_string(),
+        concat!(
+            r#"jsonpath={range .items[*]}{.metadata.name}{"\t"}"#,
+            r#"{range .metadata.ownerReferences[?(@.controller==true)]}{.kind}/{.name}{end}"#,
+            r#"{"\n"}{end}"#,
+        )
+        .to_string(),
+    ]);
+    args
+}
+
+/// Names in an [`owned_args`] listing whose controlling owner is `owner`
+/// (`Kind/name`), one per line. Matching on kind and name keeps a pod that
+/// merely references an object of the same name out.
+pub fn owned_by(listing: &str, owner: &str) -> String {
+    listing
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .filter(|(_, controller)| *controller == owner)
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// kubectl argument sets for the related-resources preview, keyed by what they
+/// list: `owner` (the owner's own owner, one level up the chain), `siblings`
+/// (other pods of the same owner) and the children a workload kind creates.
+/// The lists of children come with the `Kind/name` to keep through
+/// [`owned_by`].
+pub fn related_summary(item: &K8sItem) -> Vec<(&'static str, Vec<String>, Option<String>)> {
+    let ns = item.namespace();
+    let owner = item.owner();
+    let mut queries = Vec::new();
+
+    if let Some((owner_kind, owner_name)) = owner.split_once('/') {
+        let mut args = vec![
+            "get".to_string(),
+            owner_kind.to_lowercase(),
+            owner_name.to_string(),
+        ];
+        if !ns.is_empty() {
+            args.extend(["-n".to_string(), ns.to_string()]);
+        }
+        args.extend([
+            "-o".to_string(),
+            "jsonpath={range .metadata.ownerReferences[?(@.controller==true)]}{.kind}/{.name}{end}"
+                .to_string(),
+        ]);
+        queries.push(("owner", args, None));
+        if matches!(item.kind(), ResourceKind::Pod) && item.controlled() {
+            queries.push(("siblings", owned_args("pods", ns), Some(owner.clone())));
+        }
+    }
+
+    let children = match item.kind() {
+        ResourceKind::Deployment => Some(("replicasets", "replicasets", "Deployment")),
+        ResourceKind::StatefulSet => Some(("pods", "pods", "StatefulSet")),
+        ResourceKind::DaemonSet => Some(("pods", "pods", "DaemonSet")),
+        ResourceKind::Job => Some(("pods", "pods", "Job")),
+        ResourceKind::CronJob => Some(("jobs", "jobs", "CronJob")),
+        _ => None,
+    };
+    if let Some((label, resource, kind)) = children {
+        let owner = format!("{kind}/{}", item.name());
+        queries.push((label, owned_args(resource, ns), Some(owner)));
+    }
+    queries
+}
+
+/// Render `names` as tree branches under `indent`.
+fn tree_lines<'a>(names: impl Iterator<Item = &'a str>, indent: &str) -> String {
+    let names: Vec<&str> = names.filter(|n| !n.is_empty()).collect();
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let branch = if i + 1 == names.len() { "└" } else { "├" };
+            format!("{indent}{branch} {name}\n")
+        })
+        .collect()
+}
+
+/// Live-updated mutable state shared between a [`K8sItem`] in skim's list
+/// and the watcher that streams updates from the Kubernetes API.
+#[derive(Debug, Clone, Default)]
+pub struct ItemState {
+    pub status: String,
+    pub age: String,
+    /// When `rollout restart` last restarted a workload, in Unix seconds; shown
+    /// as a `↺ 2m ago` marker while recent. `None` when never, or for other kinds.
+    pub restarted_at: Option<i64>,
+    /// When a pod's container last crashed, in Unix seconds; a running pod
+    /// shows `Running (restarted 2m ago)` while it is recent. `None` for other kinds.
+    pub crashed_at: Option<i64>,
+    /// Controlling owner as `Kind/name` (e.g. `ReplicaSet/api-7d9f8b6c5`); empty when none.
+    pub owner: String,
+    /// Whether `owner` is a controlling reference (`controller: true`) rather
+    /// than the first of some plain ones.
+    pub controlled: bool,
+    /// `metadata.labels`, sorted by key.
+    pub labels: Vec<(String, String)>,
+    /// Creation time in Unix seconds, for absolute `--age-format`s; `None` when unknown.
+    pub created: Option<i64>,
+    /// PVCs a pod mounts (`spec.volumes[].persistentVolumeClaim.claimName`); empty for other kinds.
+    pub claims: Vec<String>,
+    /// Why an unhealthy pod is unhealthy, in the container state's own words
+    /// (e.g. `Back-off pulling image …`); empty for healthy pods and other kinds.
+    pub detail: String,
+    /// `kubectl top` usage of a pod or node with `--metrics` (e.g. `cpu 250m mem 512Mi`);
+    /// empty when metrics are off or unavailable.
+    pub usage: String,
+}
+
+impl ItemState {
+    /// The status shown, sorted and counted by: the stored status with a
+    /// recent crash noted as of now.
+    pub fn current_status(&self) -> String {
+        crate::k8s::resources::with_recent_crash(&self.status, self.crashed_at)
+    }
+}
+
+/// A Kubernetes resource item displayed in the skim TUI.
+///
+/// Mutable display state (status, age) lives behind an [`Arc<RwLock<ItemState>>`]
+/// so the watcher can update a resource in-place without sending a duplicate
+/// entry to skim.
+#[derive(Debug, Clone)]
+pub struct K8sItem {
+    kind: ResourceKind,
+    namespace: String,
+    name: String,
+    /// The cluster context this resource belongs to (empty in single-cluster mode).
+    context: String,
+    state: Arc<RwLock<ItemState>>,
+}
+
+impl K8sItem {
+    pub fn new(
+        kind: ResourceKind,
         namespace: impl Into<String>,
         name: impl Into<String>,
         status: impl Into<String>,
@@ -266,6 +1622,15 @@ impl K8sItem {
             state: Arc::new(RwLock::new(ItemState {
                 status: status.into(),
                 age: age.into(),
+                restarted_at: None,
+                crashed_at: None,
+                owner: String::new(),
+                controlled: false,
+                labels: Vec::new(),
+                created: None,
+                claims: Vec::new(),
+                detail: String::new(),
+                usage: String::new(),
             })),
         }
     }
@@ -298,12 +1663,43 @@ impl K8sItem {
     pub fn name(&self) -> &str {
         &self.name
     }
+    /// Status as of now; see [`ItemState::current_status`].
     pub fn status(&self) -> String {
-        self.state.read().unwrap().status.clone()
+        self.state.read().unwrap().current_status()
+    }
+    /// Age as shown in the list, in the `--age-format`.
+    pub fn shown_age(&self) -> String {
+        shown_age(&self.state.read().unwrap())
     }
     pub fn context(&self) -> &str {
         &self.context
     }
+    /// The `↺ 2m ago` restart marker as of now; empty when none is recent.
+    pub fn restarted(&self) -> String {
+        crate::k8s::resources::restart_marker(self.state.read().unwrap().restarted_at)
+    }
+    pub fn owner(&self) -> String {
+        self.state.read().unwrap().owner.clone()
+    }
+    /// Whether [`K8sItem::owner`] is a controller, which recreates the item.
+    pub fn controlled(&self) -> bool {
+        self.state.read().unwrap().controlled
+    }
+    pub fn claims(&self) -> Vec<String> {
+        self.state.read().unwrap().claims.clone()
+    }
+    pub fn detail(&self) -> String {
+        self.state.read().unwrap().detail.clone()
+    }
+    /// When a pod's container last crashed (Unix seconds); `None` if none has restarted.
+    pub fn crashed_at(&self) -> Option<i64> {
+        self.state.read().unwrap().crashed_at
+    }
+
+    /// `metadata.labels` as `(key, value)` pairs, sorted by key.
+    pub fn labels(&self) -> Vec<(String, String)> {
+        self.state.read().unwrap().labels.clone()
+    }
 
     /// The shared live state handle — used by watchers to update this item.
     pub fn state(&self) -> &Arc<RwLock<ItemState>> {
@@ -316,39 +1712,233 @@ impl K8sItem {
         StatusHealth::classify(&status).color()
     }
 
-    /// Preview helper: fetch events related to this resource via kubectl.
-    fn preview_events(&self) -> ItemPreview {
-        // Map our short kind names to the Kubernetes Kind for field-selector
-        let k8s_kind = match &self.kind {
-            ResourceKind::Pod => "Pod",
-            ResourceKind::Service => "Service",
-            ResourceKind::Deployment => "Deployment",
-            ResourceKind::StatefulSet => "StatefulSet",
-            ResourceKind::DaemonSet => "DaemonSet",
-            ResourceKind::ConfigMap => "ConfigMap",
-            ResourceKind::Secret => "Secret",
-            ResourceKind::Ingress => "Ingress",
-            ResourceKind::Node => "Node",
-            ResourceKind::Namespace => "Namespace",
-            ResourceKind::PersistentVolume => "PersistentVolume",
-            ResourceKind::PersistentVolumeClaim => "PersistentVolumeClaim",
-            ResourceKind::Job => "Job",
-            ResourceKind::CronJob => "CronJob",
-            ResourceKind::Event => "Event",
-            ResourceKind::Custom(s) => s.as_str(),
+    /// The colored list row. In multi-cluster mode a context prefix, colored
+    /// per cluster, precedes the namespace/name.
+    ///
+    /// With `max_width`, columns give way least important first so the row
+    /// fits without skim clipping it mid-span: label chips, usage, the restart
+    /// marker, then age, then status (cut with "…"), then column padding and
+    /// the context/namespace prefix, and only then the name itself.
+    pub fn row_line(&self, max_width: Option<usize>) -> Line<'static> {
+        self.row_line_with(columns(), max_width)
+    }
+
+    /// [`K8sItem::row_line`] showing `columns`, in their order.
+    pub fn row_line_with(&self, columns: &Columns, max_width: Option<usize>) -> Line<'static> {
+        let state = self.state.read().unwrap();
+        let status = state.current_status();
+        let shown = |column: Column, text: String| {
+            if columns.shows(column) {
+                text
+            } else {
+                String::new()
+            }
         };
-        let selector = format!(
-            "involvedObject.name={},involvedObject.kind={}",
-            self.name, k8s_kind
-        );
-        let mut args = vec!["get", "events", "--field-selector", &selector];
+        let mut cols = [
+            shown(Column::Kind, format!("{:<8} ", self.kind.as_str())),
+            if self.context.is_empty() {
+                String::new()
+            } else {
+                format!("{}/", self.context)
+            },
+            if self.namespace.is_empty() {
+                String::new()
+            } else if columns.after(Column::Namespace) == Some(Column::Name) {
+                format!("{}/", self.namespace)
+            } else {
+                shown(Column::Namespace, format!("{} ", self.namespace))
+            },
+            shown(Column::Name, name_column(&self.name)),
+            shown(
+                Column::Status,
+                if compact_status() {
+                    format!("{:<17} ", abbreviate_status(&status))
+                } else {
+                    format!("{status:<17} ")
+                },
+            ),
+            match columns.after(Column::Age) {
+                Some(_) => shown(Column::Age, format!("{} ", shown_age(&state))),
+                None => shown(Column::Age, shown_age(&state)),
+            },
+            match crate::k8s::resources::restart_marker(state.restarted_at) {
+                marker if marker.is_empty() => marker,
+                marker => format!("  {marker}"),
+            },
+            if show_labels() && !state.labels.is_empty() {
+                format!("  {}", label_chips(&state.labels, MAX_LABEL_CHIPS))
+            } else {
+                String::new()
+            },
+            if state.usage.is_empty() {
+                String::new()
+            } else {
+                format!("  {}", state.usage)
+            },
+        ];
+        if let Some(max) = max_width {
+            fit_row(&mut cols, max);
+        }
+        let styles = [
+            Style::default().fg(self.kind.color()),
+            Style::default().fg(context_color(&self.context)),
+            Style::default().fg(Color::Cyan),
+            Style::default().fg(Color::White),
+            Style::default().fg(StatusHealth::classify(&status).color()),
+            Style::default().fg(Color::DarkGray),
+            Style::default().fg(Color::LightBlue),
+            Style::default().fg(Color::DarkGray),
+            Style::default().fg(Color::Magenta),
+        ];
+        Line::from(
+            slot_order(columns)
+                .into_iter()
+                .filter(|&slot| !cols[slot].is_empty())
+                .map(|slot| Span::styled(std::mem::take(&mut cols[slot]), styles[slot]))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// The searchable text of a row showing `columns`, in their order, plus
+    /// the restart marker, every label (with `--show-labels`) and the
+    /// failure detail.
+    pub fn text_with(&self, columns: &Columns) -> String {
+        let state = self.state.read().unwrap();
+        let ctx_prefix = if self.context.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", self.context)
+        };
+        let ns_prefix = if self.namespace.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", self.namespace)
+        };
+        // `ns/name` stays one word when the namespace comes right before the name.
+        let joined = columns.after(Column::Namespace) == Some(Column::Name);
+        let mut fields: Vec<String> = Vec::new();
+        for column in columns.order() {
+            fields.push(match column {
+                Column::Kind => format!("{:<8}", self.kind.as_str()),
+                Column::Namespace if joined => continue,
+                Column::Namespace => format!("{ctx_prefix}{}", self.namespace),
+                Column::Name if joined => format!(
+                    "{ctx_prefix}{ns_prefix}{}",
+                    truncate_name(&self.name, name_width())
+                ),
+                Column::Name if !columns.shows(Column::Namespace) => {
+                    format!("{ctx_prefix}{}", truncate_name(&self.name, name_width()))
+                }
+                Column::Name => truncate_name(&self.name, name_width()).into_owned(),
+                Column::Status => state.current_status(),
+                Column::Age => shown_age(&state),
+            });
+        }
+        fields.retain(|field| !field.is_empty());
+        let mut text = fields.join(" ");
+        let restarted = crate::k8s::resources::restart_marker(state.restarted_at);
+        if !restarted.is_empty() {
+            text.push(' ');
+            text.push_str(&restarted);
+        }
+        // Every label, not just the displayed chips, so any of them can be searched for.
+        if show_labels() && !state.labels.is_empty() {
+            text.push(' ');
+            text.push_str(&label_chips(&state.labels, usize::MAX));
+        }
+        // Not displayed, but searchable: "back-off pulling" finds every such pod.
+        if !state.detail.is_empty() {
+            text.push(' ');
+            text.push_str(&state.detail);
+        }
+        text
+    }
+
+    /// Unwrapped preview content for the current mode.
+    fn preview_raw(&self) -> ItemPreview {
+        let mode = crate::actions::current_preview_mode();
+
+        // Mode 3 = events: uses a completely different command structure.
+        if mode == 3 {
+            return self.preview_events();
+        }
+        // Mode 4 = related: owner chain and owned resources.
+        if mode == 4 {
+            return self.preview_related();
+        }
+
+        // Build the kubectl argument list for the current preview mode.
+        // Namespace (-n) and --context must come BEFORE the `--` end-of-flags
+        // separator; anything after `--` is treated as a resource name by kubectl.
+        // Logs modes fall back to describe for anything but pods; mode 5 shows
+        // the previous container's logs, the ones that explain a crash.
+        let is_pod = matches!(self.kind, ResourceKind::Pod);
+        let mut args: Vec<&str> = match mode {
+            1 => vec!["get", self.kind.as_str(), "-o", "yaml"],
+            2 if is_pod => vec!["logs", "--tail=100"],
+            5 if is_pod => vec!["logs", "--previous", "--tail=100"],
+            _ => vec!["describe", self.kind.as_str()],
+        };
+
         if !self.namespace.is_empty() {
-            args.extend_from_slice(&["-n", &self.namespace]);
+            args.push("-n");
+            args.push(&self.namespace);
         }
+
+        // Target the correct cluster in multi-context mode
         if !self.context.is_empty() {
-            args.extend_from_slice(&["--context", &self.context]);
+            args.push("--context");
+            args.push(&self.context);
         }
 
+        args.push("--");
+        args.push(&self.name);
+
+        match std::process::Command::new("kubectl").args(&args).output() {
+            Ok(out) => {
+                let header = match (mode, args[0]) {
+                    (1, _) => format!("── YAML: {}/{} ──\n", self.kind.as_str(), self.name),
+                    (2, "logs") => format!("── LOGS: {} (last 100) ──\n", self.name),
+                    (5, "logs") => {
+                        format!("── PREVIOUS LOGS: {} (last 100) ──\n", self.name)
+                    }
+                    _ => format!("── DESCRIBE: {}/{} ──\n", self.kind.as_str(), self.name),
+                };
+                let header = format!("{header}{}", detail_line(&self.detail()));
+                let mut body = if out.status.success() {
+                    String::from_utf8_lossy(&out.stdout).to_string()
+                } else {
+                    format!("[kubectl error]\n{}", String::from_utf8_lossy(&out.stderr))
+                };
+                if args[0] == "describe" && out.status.success() {
+                    body.push_str(&self.recent_events_section());
+                }
+                ItemPreview::AnsiText(format!("{header}{body}"))
+            }
+            Err(e) => ItemPreview::Text(format!(
+                "[Error running kubectl]\n{e}\n\nIs kubectl in your PATH?"
+            )),
+        }
+    }
+
+    /// Full event list appended to the describe preview of troubled items;
+    /// empty for healthy ones or when there is nothing to add.
+    fn recent_events_section(&self) -> String {
+        let Some(args) = describe_events_args(self) else {
+            return String::new();
+        };
+        match std::process::Command::new("kubectl").args(&args).output() {
+            Ok(out) if out.status.success() && !out.stdout.trim_ascii().is_empty() => format!(
+                "\n── RECENT EVENTS ──\n{}",
+                String::from_utf8_lossy(&out.stdout)
+            ),
+            _ => String::new(),
+        }
+    }
+
+    /// Preview helper: fetch events related to this resource via kubectl.
+    fn preview_events(&self) -> ItemPreview {
+        let args = events_args(self);
         let header = format!("── EVENTS: {}/{} ──\n", self.kind.as_str(), self.name);
         match std::process::Command::new("kubectl").args(&args).output() {
             Ok(out) => {
@@ -370,6 +1960,61 @@ impl K8sItem {
         }
     }
 
+    /// Preview helper: owner chain and owned resources, drawn as a small tree.
+    fn preview_related(&self) -> ItemPreview {
+        let run = |args: &[String]| -> Result<String, String> {
+            let mut cmd = std::process::Command::new("kubectl");
+            if !self.context.is_empty() {
+                cmd.args(["--context", &self.context]);
+            }
+            match cmd.args(args).output() {
+                Ok(out) if out.status.success() => {
+                    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+                }
+                Ok(out) => Err(String::from_utf8_lossy(&out.stderr).trim().to_string()),
+                Err(e) => Err(format!("{e} (is kubectl in your PATH?)")),
+            }
+        };
+
+        let mut out = format!("── RELATED: {}/{} ──\n", self.kind.as_str(), self.name);
+        let owner = self.owner();
+        if owner.is_empty() {
+            out.push_str("owner: none\n");
+        }
+        for (label, args, owner) in related_summary(self) {
+            let listed = run(&args).map(|body| match owner {
+                Some(owner) => owned_by(&body, &owner),
+                None => body,
+            });
+            match (label, listed) {
+                ("owner", Ok(parent)) if parent.trim_matches('/').is_empty() => {
+                    out.push_str(&format!("owner: {owner}\n"));
+                }
+                ("owner", Ok(parent)) => {
+                    out.push_str(&format!("owner: {owner} → {parent}\n"));
+                }
+                ("replicasets", Ok(body)) => {
+                    let sets: Vec<&str> = body.lines().collect();
+                    out.push_str(&format!("replicasets ({}):\n", sets.len()));
+                    let pods = run(&owned_args("pods", &self.namespace)).unwrap_or_default();
+                    for (i, rs) in sets.iter().enumerate() {
+                        let last = i + 1 == sets.len();
+                        out.push_str(&format!("  {} {rs}\n", if last { "└" } else { "├" }));
+                        let owned = owned_by(&pods, &format!("ReplicaSet/{rs}"));
+                        let indent = if last { "      " } else { "  │   " };
+                        out.push_str(&tree_lines(owned.lines(), indent));
+                    }
+                }
+                (_, Ok(body)) => {
+                    out.push_str(&format!("{label} ({}):\n", body.lines().count()));
+                    out.push_str(&tree_lines(body.lines(), "  "));
+                }
+                (_, Err(e)) => out.push_str(&format!("{label}: [kubectl error] {e}\n")),
+            }
+        }
+        ItemPreview::AnsiText(out)
+    }
+
     /// Machine-parseable output string for piping.
     /// In multi-cluster mode, prefixed with the context: "ctx:kind/ns/name"
     pub fn output_str(&self) -> String {
@@ -386,6 +2031,31 @@ impl K8sItem {
     }
 }
 
+/// Identity equality: two items are equal when they refer to the same resource
+/// (kind, namespace, name, context). Live state (status, age) is deliberately
+/// ignored, so "same resource, new status" compares equal — this is what dedup
+/// and identity maps want, but it is NOT a full value comparison.
+impl PartialEq for K8sItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.namespace == other.namespace
+            && self.name == other.name
+            && self.context == other.context
+    }
+}
+
+impl Eq for K8sItem {}
+
+/// Hashes the same identity fields as [`PartialEq`], keeping `Hash` consistent with `Eq`.
+impl std::hash::Hash for K8sItem {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        self.namespace.hash(state);
+        self.name.hash(state);
+        self.context.hash(state);
+    }
+}
+
 /// Pick a consistent color for a cluster context name based on a hash of the name.
 /// Ensures the same context always gets the same color across all items.
 pub fn context_color(ctx: &str) -> Color {
@@ -409,136 +2079,166 @@ impl SkimItem for K8sItem {
     /// The text skim fuzzy-matches against — plain, no color.
     /// In multi-cluster mode the context name is included so users can search by cluster.
     fn text(&self) -> Cow<'_, str> {
-        let state = self.state.read().unwrap();
-        let ctx_prefix = if self.context.is_empty() {
-            String::new()
-        } else {
-            format!("{}/", self.context)
-        };
-        let ns_prefix = if self.namespace.is_empty() {
-            String::new()
-        } else {
-            format!("{}/", self.namespace)
-        };
-        let name_truncated = truncate_name(&self.name, 31);
-        Cow::Owned(format!(
-            "{:<8} {}{}{} {} {}",
-            self.kind.as_str(),
-            ctx_prefix,
-            ns_prefix,
-            name_truncated,
-            state.status,
-            state.age,
-        ))
+        Cow::Owned(self.text_with(columns()))
     }
 
-    /// Colored display shown in the skim list.
-    /// In multi-cluster mode a context prefix is shown before the namespace/name,
-    /// colored distinctly per cluster.
+    /// Colored display shown in the skim list, fitted to the cached list width.
     fn display(&self, _context: DisplayContext) -> Line<'_> {
-        let state = self.state.read().unwrap();
-        let status_color = StatusHealth::classify(&state.status).color();
-
-        let ns_prefix = if self.namespace.is_empty() {
-            String::new()
-        } else {
-            format!("{}/", self.namespace)
-        };
-
-        let mut spans = vec![Span::styled(
-            format!("{:<8} ", self.kind.as_str()),
-            Style::default().fg(self.kind.color()),
-        )];
+        self.row_line(list_width())
+    }
 
-        // Context prefix — only shown in multi-cluster mode
-        if !self.context.is_empty() {
-            spans.push(Span::styled(
-                format!("{}/", self.context),
-                Style::default().fg(context_color(&self.context)),
-            ));
+    /// Preview pane content — mode cycles via ctrl-p
+    /// (describe → yaml → logs → events → related).
+    /// Passes --context when the item belongs to a non-default cluster, and
+    /// soft-wraps the text to the pane width (or `--describe-width`).
+    /// Skim calls this from a background thread; blocking is fine here.
+    fn preview(&self, context: PreviewContext) -> ItemPreview {
+        let wrap = preview_wrap_width(context.width);
+        match self.preview_raw() {
+            ItemPreview::AnsiText(text) => ItemPreview::AnsiText(wrap_preview(&text, wrap)),
+            other => other,
         }
+    }
 
-        spans.push(Span::styled(ns_prefix, Style::default().fg(Color::Cyan)));
-        let name_col = {
-            let t = truncate_name(&self.name, 31);
-            if t.len() < 32 {
-                format!("{t:<32} ")
-            } else {
-                format!("{t} ")
-            }
-        };
-        spans.push(Span::styled(name_col, Style::default().fg(Color::White)));
-        spans.push(Span::styled(
-            format!("{:<17} ", state.status),
-            Style::default().fg(status_color),
-        ));
-        spans.push(Span::styled(
-            state.age.clone(),
-            Style::default().fg(Color::DarkGray),
-        ));
+    /// What gets written to stdout when this item is selected
+    fn output(&self) -> Cow<'_, str> {
+        Cow::Owned(self.output_str())
+    }
+}
 
-        Line::from(spans)
+/// `--output json` shape: `kind`, `namespace`, `name`, `status`, `age` (as
+/// shown in the list) and `context`.
+impl serde::Serialize for K8sItem {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let state = self.state.read().unwrap();
+        let mut item = serializer.serialize_struct("K8sItem", 6)?;
+        item.serialize_field("kind", self.kind.as_str())?;
+        item.serialize_field("namespace", &self.namespace)?;
+        item.serialize_field("name", &self.name)?;
+        item.serialize_field("status", &state.current_status())?;
+        item.serialize_field("age", &shown_age(&state))?;
+        item.serialize_field("context", &self.context)?;
+        item.end()
     }
+}
 
-    /// Preview pane content — mode cycles via ctrl-p (describe → yaml → logs → events).
-    /// Passes --context when the item belongs to a non-default cluster.
-    /// Skim calls this from a background thread; blocking is fine here.
-    fn preview(&self, _context: PreviewContext) -> ItemPreview {
-        let mode = crate::actions::current_preview_mode();
+// Slots of a row's columns, as built by [`K8sItem::row_line_with`].
+const KIND: usize = 0;
+const CONTEXT: usize = 1;
+const NAMESPACE: usize = 2;
+const NAME: usize = 3;
+const STATUS: usize = 4;
+const AGE: usize = 5;
+const RESTARTED: usize = 6;
+const LABELS: usize = 7;
+const USAGE: usize = 8;
 
-        // Mode 3 = events: uses a completely different command structure.
-        if mode == 3 {
-            return self.preview_events();
+/// The slots a row's spans are drawn from, in `columns` order.
+fn slot_order(columns: &Columns) -> Vec<usize> {
+    let mut slots = Vec::with_capacity(9);
+    for column in columns.order() {
+        match column {
+            Column::Kind => slots.push(KIND),
+            Column::Namespace => slots.extend([CONTEXT, NAMESPACE]),
+            Column::Name if !columns.shows(Column::Namespace) => slots.extend([CONTEXT, NAME]),
+            Column::Name => slots.push(NAME),
+            Column::Status => slots.push(STATUS),
+            Column::Age => slots.push(AGE),
         }
+    }
+    slots.extend([USAGE, RESTARTED, LABELS]);
+    slots
+}
 
-        // Build the kubectl argument list for the current preview mode.
-        // Namespace (-n) and --context must come BEFORE the `--` end-of-flags
-        // separator; anything after `--` is treated as a resource name by kubectl.
-        let mut args: Vec<&str> = if mode == 2 && matches!(self.kind, ResourceKind::Pod) {
-            vec!["logs", "--tail=100"]
+/// Shrink the row columns (kind, context, namespace, name, status, age,
+/// restart marker, label chips, usage) until they fit in `max` columns.
+/// See [`K8sItem::row_line`].
+fn fit_row(cols: &mut [String; 9], max: usize) {
+    let width = |cols: &[String; 9]| {
+        cols.iter()
+            .map(String::as_str)
+            .map(UnicodeWidthStr::width)
+            .sum::<usize>()
+    };
+    let room_for =
+        |cols: &[String; 9], col: usize| max.saturating_sub(width(cols) - cols[col].width());
+
+    for col in [LABELS, USAGE, RESTARTED, AGE] {
+        if width(cols) > max {
+            cols[col].clear();
+        }
+    }
+    if width(cols) > max {
+        // Keep at least one character and the ellipsis, or drop the status.
+        let room = room_for(cols, STATUS);
+        cols[STATUS] = if room >= 2 {
+            fit_text(cols[STATUS].trim_end(), room)
         } else {
-            match mode {
-                1 => vec!["get", self.kind.as_str(), "-o", "yaml"],
-                _ => vec!["describe", self.kind.as_str()],
-            }
+            String::new()
         };
-
-        if !self.namespace.is_empty() {
-            args.push("-n");
-            args.push(&self.namespace);
+    }
+    if width(cols) > max {
+        for col in [KIND, NAME] {
+            cols[col] = format!("{} ", cols[col].trim_end());
         }
-
-        // Target the correct cluster in multi-context mode
-        if !self.context.is_empty() {
-            args.push("--context");
-            args.push(&self.context);
+    }
+    for col in [CONTEXT, NAMESPACE] {
+        if width(cols) > max {
+            cols[col].clear();
         }
+    }
+    if width(cols) > max {
+        let room = room_for(cols, NAME);
+        cols[NAME] = fit_text(cols[NAME].trim_end(), room);
+    }
+}
 
-        args.push("--");
-        args.push(&self.name);
+// ─── Info rows ────────────────────────────────────────────────────────────────
 
-        match std::process::Command::new("kubectl").args(&args).output() {
-            Ok(out) => {
-                let header = match mode {
-                    1 => format!("── YAML: {}/{} ──\n", self.kind.as_str(), self.name),
-                    2 => format!("── LOGS: {} (last 100) ──\n", self.name),
-                    _ => format!("── DESCRIBE: {}/{} ──\n", self.kind.as_str(), self.name),
-                };
-                let body = if out.status.success() {
-                    String::from_utf8_lossy(&out.stdout).to_string()
-                } else {
-                    format!("[kubectl error]\n{}", String::from_utf8_lossy(&out.stderr))
-                };
-                ItemPreview::AnsiText(format!("{header}{body}"))
-            }
-            Err(e) => ItemPreview::Text(format!(
-                "[Error running kubectl]\n{e}\n\nIs kubectl in your PATH?"
-            )),
+/// A non-resource row in the list, such as the "connecting…" placeholder sent
+/// before the first batch. It never downcasts to [`K8sItem`], so actions skip
+/// it if it is selected. The text is shared so it can be replaced once the
+/// real items arrive (skim's list is append-only).
+#[derive(Debug, Clone)]
+pub struct InfoItem {
+    text: Arc<RwLock<String>>,
+}
+
+impl InfoItem {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: Arc::new(RwLock::new(text.into())),
         }
     }
 
-    /// What gets written to stdout when this item is selected
+    /// Replace the row's text; skim picks it up on the next redraw.
+    pub fn set_text(&self, text: impl Into<String>) {
+        *self.text.write().unwrap() = text.into();
+    }
+
+    pub fn current_text(&self) -> String {
+        self.text.read().unwrap().clone()
+    }
+}
+
+impl SkimItem for InfoItem {
+    fn text(&self) -> Cow<'_, str> {
+        Cow::Owned(self.current_text())
+    }
+
+    fn display(&self, _context: DisplayContext) -> Line<'_> {
+        Line::from(Span::styled(
+            self.current_text(),
+            Style::default().fg(Color::DarkGray),
+        ))
+    }
+
+    fn preview(&self, _context: PreviewContext) -> ItemPreview {
+        ItemPreview::Text(self.current_text())
+    }
+
     fn output(&self) -> Cow<'_, str> {
-        Cow::Owned(self.output_str())
+        Cow::Owned(self.current_text())
     }
 }
@@ -1,5 +1,9 @@
 use anyhow::{Context, Result};
-use kube::{config::KubeConfigOptions, Client};
+use kube::{
+    config::{KubeConfigOptions, Kubeconfig, NamedContext},
+    Client,
+};
+use std::path::{Path, PathBuf};
 
 /// Build a `kube::Client` for a specific named kubeconfig context.
 /// If `kubeconfig` is Some, reads from that file; otherwise uses the default
@@ -27,24 +31,162 @@ pub async fn build_client_for_context(
     Client::try_from(config).context("Failed to build Kubernetes client")
 }
 
+/// `--kubeconfig`'s file when given, else the default ($KUBECONFIG or
+/// ~/.kube/config).
+fn read_kubeconfig(kubeconfig: Option<&str>) -> Option<Kubeconfig> {
+    match kubeconfig {
+        Some(path) => Kubeconfig::read_from(path).ok(),
+        None => Kubeconfig::read().ok(),
+    }
+}
+
 /// Return the current context name from kubeconfig (for display in the header).
 pub fn current_context() -> String {
-    kube::config::Kubeconfig::read()
-        .ok()
+    current_context_in(None)
+}
+
+/// [`current_context`] of `--kubeconfig`'s file when given.
+pub fn current_context_in(kubeconfig: Option<&str>) -> String {
+    read_kubeconfig(kubeconfig)
         .and_then(|cfg| cfg.current_context)
         .unwrap_or_else(|| "unknown".to_string())
 }
 
-/// Return all context names from kubeconfig, sorted alphabetically.
+/// Return the default namespace configured for `context` in kubeconfig (or
+/// `--kubeconfig`'s file when given), if any.
+pub fn context_namespace(context: &str, kubeconfig: Option<&str>) -> Option<String> {
+    read_kubeconfig(kubeconfig)?
+        .contexts
+        .into_iter()
+        .find(|c| c.name == context)
+        .and_then(|c| c.context)
+        .and_then(|c| c.namespace)
+        .filter(|ns| !ns.is_empty())
+}
+
+/// Return all context names from kubeconfig, sorted alphabetically. A name
+/// defined in several merged files is listed once (see [`find_context_collisions`]).
 pub fn list_contexts() -> Vec<String> {
     let mut ctxs: Vec<String> = kube::config::Kubeconfig::read()
         .ok()
         .map(|cfg| cfg.contexts.into_iter().map(|c| c.name).collect())
         .unwrap_or_default();
     ctxs.sort();
+    ctxs.dedup();
     ctxs
 }
 
+/// Split the `--context`s asked for into those kubeconfig defines (in the
+/// order given, each once) and those it doesn't.
+pub fn select_contexts(requested: &[String], available: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut known: Vec<String> = Vec::new();
+    let mut unknown: Vec<String> = Vec::new();
+    for name in requested {
+        let bucket = if available.contains(name) {
+            &mut known
+        } else {
+            &mut unknown
+        };
+        if !bucket.contains(name) {
+            bucket.push(name.clone());
+        }
+    }
+    (known, unknown)
+}
+
+// ─── Merged kubeconfigs ───────────────────────────────────────────────────────
+
+/// A context name defined by more than one of the kubeconfig files merged
+/// from `$KUBECONFIG`, with different cluster or user. The first file wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextCollision {
+    pub name: String,
+    /// Files defining the name, in merge order.
+    pub files: Vec<PathBuf>,
+}
+
+/// The files `$KUBECONFIG` lists, in merge order (empty when it is unset).
+pub fn kubeconfig_paths() -> Vec<PathBuf> {
+    std::env::var_os("KUBECONFIG")
+        .map(|v| {
+            std::env::split_paths(&v)
+                .filter(|p| !p.as_os_str().is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read each kubeconfig file, skipping the ones that can't be read.
+pub fn load_kubeconfigs(paths: &[PathBuf]) -> Vec<(PathBuf, Kubeconfig)> {
+    paths
+        .iter()
+        .filter_map(|p| Kubeconfig::read_from(p).ok().map(|kc| (p.clone(), kc)))
+        .collect()
+}
+
+/// Whether two same-named contexts point at a different cluster or user.
+fn contexts_differ(a: &NamedContext, b: &NamedContext) -> bool {
+    match (&a.context, &b.context) {
+        (Some(a), Some(b)) => a.cluster != b.cluster || a.user != b.user,
+        (None, None) => false,
+        _ => true,
+    }
+}
+
+/// Context names that resolve differently depending on which file wins the merge.
+pub fn find_context_collisions(configs: &[(PathBuf, Kubeconfig)]) -> Vec<ContextCollision> {
+    let mut collisions: Vec<ContextCollision> = Vec::new();
+    for (i, (path, kc)) in configs.iter().enumerate() {
+        for ctx in &kc.contexts {
+            if collisions.iter().any(|c| c.name == ctx.name) {
+                continue;
+            }
+            let clashing: Vec<&Path> = configs[i + 1..]
+                .iter()
+                .filter(|(_, other)| {
+                    other
+                        .contexts
+                        .iter()
+                        .any(|o| o.name == ctx.name && contexts_differ(ctx, o))
+                })
+                .map(|(p, _)| p.as_path())
+                .collect();
+            if !clashing.is_empty() {
+                collisions.push(ContextCollision {
+                    name: ctx.name.clone(),
+                    files: std::iter::once(path.as_path())
+                        .chain(clashing)
+                        .map(Path::to_path_buf)
+                        .collect(),
+                });
+            }
+        }
+    }
+    collisions
+}
+
+/// One-line warning for a collision, naming the file whose definition is used.
+pub fn collision_warning(collision: &ContextCollision) -> String {
+    let others: Vec<String> = collision.files[1..]
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect();
+    format!(
+        "[kuberift] warning: context '{}' is defined differently in {} and {}; using the one from {}",
+        collision.name,
+        collision.files[0].display(),
+        others.join(", "),
+        collision.files[0].display(),
+    )
+}
+
+/// Warn about context names that collide across the files in `$KUBECONFIG`.
+pub fn warn_context_collisions() {
+    for collision in find_context_collisions(&load_kubeconfigs(&kubeconfig_paths())) {
+        eprintln!("{}", collision_warning(&collision));
+    }
+}
+
 /// Persist the last-used context to `~/.config/kuberift/last_context`.
 /// Sets 0o700 on the directory and 0o600 on the file on Unix.
 pub fn save_last_context(context: &str) {
@@ -80,3 +222,19 @@ pub fn load_last_context() -> Option<String> {
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
 }
+
+/// Delete the saved last-used context so the next launch falls back to
+/// kubeconfig's current-context. Returns `true` if a saved context was removed.
+pub fn clear_last_context() -> bool {
+    let Some(path) = dirs::config_dir().map(|d| d.join("kuberift").join("last_context")) else {
+        return false;
+    };
+    match std::fs::remove_file(&path) {
+        Ok(()) => true,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+        Err(e) => {
+            eprintln!("[kuberift] warning: cannot remove saved context: {e}");
+            false
+        }
+    }
+}
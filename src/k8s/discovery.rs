@@ -4,6 +4,10 @@ use kube::{
     discovery::{verbs, ApiResource, Discovery, Scope},
     Client,
 };
+use std::fmt::Write;
+
+use crate::items::ResourceKind;
+use crate::k8s::resources::ALL_KINDS;
 
 /// A CRD (or other non-built-in API resource) discovered at runtime.
 #[derive(Debug, Clone)]
@@ -68,6 +72,141 @@ pub async fn discover_crds(client: &Client) -> Result<Vec<DiscoveredCrd>> {
     Ok(crds)
 }
 
+// ─── Served API resources (--api-resources) ──────────────────────────────────
+
+/// A resource the cluster lists and watches, as reported by discovery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServedResource {
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+    pub plural: String,
+}
+
+impl ServedResource {
+    /// `group/version`, or just `version` for the core group.
+    pub fn api_version(&self) -> String {
+        if self.group.is_empty() {
+            self.version.clone()
+        } else {
+            format!("{}/{}", self.group, self.version)
+        }
+    }
+}
+
+/// One row of the `--api-resources` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiSupport {
+    /// Short kind name for built-ins (`deploy`), plural for CRDs.
+    pub name: String,
+    /// `None` when the cluster doesn't serve it.
+    pub api_version: Option<String>,
+    pub custom: bool,
+}
+
+/// API group and plural a built-in kind is watched through.
+pub fn builtin_api(kind: &ResourceKind) -> Option<(&'static str, &'static str)> {
+    Some(match kind {
+        ResourceKind::Pod => ("", "pods"),
+        ResourceKind::Service => ("", "services"),
+        ResourceKind::ConfigMap => ("", "configmaps"),
+        ResourceKind::Secret => ("", "secrets"),
+        ResourceKind::Namespace => ("", "namespaces"),
+        ResourceKind::Node => ("", "nodes"),
+        ResourceKind::PersistentVolume => ("", "persistentvolumes"),
+        ResourceKind::PersistentVolumeClaim => ("", "persistentvolumeclaims"),
+        ResourceKind::Event => ("", "events"),
+        ResourceKind::Deployment => ("apps", "deployments"),
+        ResourceKind::StatefulSet => ("apps", "statefulsets"),
+        ResourceKind::DaemonSet => ("apps", "daemonsets"),
+        ResourceKind::Job => ("batch", "jobs"),
+        ResourceKind::CronJob => ("batch", "cronjobs"),
+        ResourceKind::Ingress => ("networking.k8s.io", "ingresses"),
+        ResourceKind::HorizontalPodAutoscaler => ("autoscaling", "horizontalpodautoscalers"),
+        ResourceKind::Endpoints => ("", "endpoints"),
+        ResourceKind::ServiceAccount => ("", "serviceaccounts"),
+        ResourceKind::Role => ("rbac.authorization.k8s.io", "roles"),
+        ResourceKind::RoleBinding => ("rbac.authorization.k8s.io", "rolebindings"),
+        ResourceKind::Custom(_) => return None,
+    })
+}
+
+/// Every resource the cluster serves with LIST and WATCH, at its preferred version.
+pub async fn served_resources(client: &Client) -> Result<Vec<ServedResource>> {
+    let discovery = Discovery::new(client.clone()).run().await?;
+    let mut served = Vec::new();
+    for group in discovery.groups() {
+        for (ar, caps) in group.recommended_resources() {
+            if caps.supports_operation(verbs::LIST) && caps.supports_operation(verbs::WATCH) {
+                served.push(ServedResource {
+                    group: ar.group,
+                    version: ar.version,
+                    kind: ar.kind,
+                    plural: ar.plural,
+                });
+            }
+        }
+    }
+    Ok(served)
+}
+
+/// Match `served` against the kinds kf knows: one row per built-in kind
+/// (served or not), then one per other served resource (CRDs), by name.
+pub fn cross_reference(served: &[ServedResource]) -> Vec<ApiSupport> {
+    let mut rows: Vec<ApiSupport> = ALL_KINDS
+        .iter()
+        .filter_map(|kind| {
+            let (group, plural) = builtin_api(kind)?;
+            Some(ApiSupport {
+                name: kind.as_str().to_string(),
+                api_version: served
+                    .iter()
+                    .find(|r| r.group == group && r.plural == plural)
+                    .map(ServedResource::api_version),
+                custom: false,
+            })
+        })
+        .collect();
+    let mut custom: Vec<ApiSupport> = served
+        .iter()
+        .filter(|r| !is_builtin(&r.group, &r.plural))
+        .map(|r| ApiSupport {
+            name: r.plural.clone(),
+            api_version: Some(r.api_version()),
+            custom: true,
+        })
+        .collect();
+    custom.sort_by(|a, b| a.name.cmp(&b.name));
+    rows.extend(custom);
+    rows
+}
+
+/// Aligned `KIND  API VERSION  STATUS` table.
+pub fn render_api_resources(rows: &[ApiSupport]) -> String {
+    let name_w = rows.iter().map(|r| r.name.len()).max().unwrap_or(0).max(4);
+    let api_w = rows
+        .iter()
+        .map(|r| r.api_version.as_deref().map_or(1, str::len))
+        .max()
+        .unwrap_or(0)
+        .max(11);
+    let mut out = format!("{:<name_w$}  {:<api_w$}  STATUS\n", "KIND", "API VERSION");
+    for row in rows {
+        let status = match (&row.api_version, row.custom) {
+            (None, _) => "not served",
+            (Some(_), false) => "served",
+            (Some(_), true) => "served (custom)",
+        };
+        let _ = writeln!(
+            out,
+            "{:<name_w$}  {:<api_w$}  {status}",
+            row.name,
+            row.api_version.as_deref().unwrap_or("-"),
+        );
+    }
+    out
+}
+
 /// Extract status from a `DynamicObject` by checking `.status.conditions[]`
 /// for a condition with `type == "Ready"`, falling back to `.status.phase`.
 pub fn dynamic_status(obj: &DynamicObject) -> String {
@@ -101,3 +240,99 @@ pub fn dynamic_status(obj: &DynamicObject) -> String {
 
     "Unknown".to_string()
 }
+
+// ─── VerticalPodAutoscaler ───────────────────────────────────────────────────
+
+/// API group, version and kind of the VPA CRD (`kf vpa`).
+pub const VPA_GROUP: &str = "autoscaling.k8s.io";
+pub const VPA_VERSION: &str = "v1";
+pub const VPA_KIND: &str = "VerticalPodAutoscaler";
+/// Plural used as the `ResourceKind::Custom` name for VPAs.
+pub const VPA_PLURAL: &str = "verticalpodautoscalers";
+
+/// A recommendation this many times above what the VPA may apply counts as undersized.
+const UNDERSIZED_FACTOR: f64 = 1.5;
+
+/// Status extractor for a discovered CRD: curated for kinds we know, the
+/// generic conditions/phase lookup otherwise.
+pub fn status_fn_for(crd: &DiscoveredCrd) -> fn(&DynamicObject) -> String {
+    if crd.group == VPA_GROUP && crd.kind_name == VPA_KIND {
+        vpa_status
+    } else {
+        dynamic_status
+    }
+}
+
+/// Summarise a VPA's recommendation for its first container, e.g.
+/// `rec cpu=250m mem=256Mi`. When the uncapped recommendation greatly exceeds
+/// the target the VPA is allowed to apply (`maxAllowed` is too low), the
+/// workload is undersized: `Undersized(cpu 2>500m)`. `Pending` until the
+/// recommender has produced anything.
+pub fn vpa_status(obj: &DynamicObject) -> String {
+    let recs = obj
+        .data
+        .pointer("/status/recommendation/containerRecommendations")
+        .and_then(|r| r.as_array())
+        .filter(|r| !r.is_empty());
+    let Some(recs) = recs else {
+        return "Pending".to_string();
+    };
+
+    for rec in recs {
+        for resource in ["cpu", "memory"] {
+            let target = rec
+                .pointer(&format!("/target/{resource}"))
+                .and_then(|q| q.as_str());
+            let uncapped = rec
+                .pointer(&format!("/uncappedTarget/{resource}"))
+                .and_then(|q| q.as_str());
+            if let (Some(target), Some(uncapped)) = (target, uncapped) {
+                if let (Some(t), Some(u)) = (parse_quantity(target), parse_quantity(uncapped)) {
+                    if t > 0.0 && u > t * UNDERSIZED_FACTOR {
+                        let short = if resource == "memory" { "mem" } else { "cpu" };
+                        return format!("Undersized({short} {uncapped}>{target})");
+                    }
+                }
+            }
+        }
+    }
+
+    let first = &recs[0];
+    let get = |resource: &str| {
+        first
+            .pointer(&format!("/target/{resource}"))
+            .and_then(|q| q.as_str())
+            .unwrap_or("?")
+            .to_string()
+    };
+    let more = if recs.len() > 1 {
+        format!(" +{}", recs.len() - 1)
+    } else {
+        String::new()
+    };
+    format!("rec cpu={} mem={}{more}", get("cpu"), get("memory"))
+}
+
+/// Parse a Kubernetes quantity (`250m`, `2`, `256Mi`, `1G`, `1e3`) into a plain number.
+pub fn parse_quantity(s: &str) -> Option<f64> {
+    const SUFFIXES: &[(&str, f64)] = &[
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+    ];
+    let s = s.trim();
+    for (suffix, factor) in SUFFIXES {
+        if let Some(num) = s.strip_suffix(suffix) {
+            return num.parse::<f64>().ok().map(|n| n * factor);
+        }
+    }
+    s.parse().ok()
+}
@@ -0,0 +1,323 @@
+//! `--watch-file` — resources read from a `kubectl get -o json` dump instead
+//! of a cluster, re-read whenever the file changes.
+
+use anyhow::Result;
+use k8s_openapi::api::{
+    apps::v1::{DaemonSet, Deployment, StatefulSet},
+    autoscaling::v2::HorizontalPodAutoscaler,
+    batch::v1::{CronJob, Job},
+    core::v1::{
+        ConfigMap, Endpoints, Event, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod,
+        Secret, Service, ServiceAccount,
+    },
+    networking::v1::Ingress,
+    rbac::v1::{Role, RoleBinding},
+};
+use kube::{api::DynamicObject, Resource, ResourceExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use skim::SkimItemSender;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use crate::items::{ItemState, K8sItem, ResourceKind};
+use crate::k8s::resources::{
+    cronjob_status, daemonset_restarted, daemonset_status, deploy_restarted, deploy_status,
+    endpoints_status, event_status, hpa_status, ingress_status, job_status, namespace_excluded,
+    namespace_status, node_status, pod_hooks, pv_status, pvc_status, role_status,
+    rolebinding_status, secret_status, service_status, serviceaccount_status,
+    statefulset_restarted, statefulset_status, terminating_hidden, InitBatch, KindHooks,
+    WatchOptions, ALL_KINDS,
+};
+
+/// How often the dump file's modification time is checked.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// One object of a dump, ready for the list.
+#[derive(Debug, Clone)]
+pub struct DumpEntry {
+    pub kind: ResourceKind,
+    pub namespace: String,
+    pub name: String,
+    pub state: ItemState,
+}
+
+// ─── Parsing ──────────────────────────────────────────────────────────────────
+
+/// Parse a dump: a `List` (as printed by `kubectl get … -o json`) or a single
+/// object. Objects of kinds not in `kinds` and objects that don't deserialize
+/// as their kind are skipped; only unreadable JSON is an error. Kinds kuberift
+/// doesn't model (custom resources, `ReplicaSet`, …) are kept as
+/// [`ResourceKind::Custom`] under their own kind name.
+pub fn parse_dump(text: &str, kinds: &[ResourceKind]) -> Result<Vec<DumpEntry>> {
+    let root: Value = serde_json::from_str(text)?;
+    let objects = match root {
+        Value::Object(mut map) if map.contains_key("items") => match map.remove("items") {
+            Some(Value::Array(items)) => items,
+            _ => anyhow::bail!("\"items\" is not a list"),
+        },
+        object @ Value::Object(_) => vec![object],
+        _ => anyhow::bail!("expected a JSON object or List"),
+    };
+    Ok(objects
+        .into_iter()
+        .filter_map(dump_entry)
+        .filter(|entry| kind_requested(&entry.kind, kinds))
+        .collect())
+}
+
+/// Whether an entry of `kind` is wanted. A kind without a built-in model is
+/// kept when no kind filter narrowed the list (every built-in kind is
+/// requested), or when asked for by name or plural (`kf widgets`), as CRD
+/// discovery matches them.
+fn kind_requested(kind: &ResourceKind, kinds: &[ResourceKind]) -> bool {
+    let ResourceKind::Custom(name) = kind else {
+        return kinds.contains(kind);
+    };
+    let plural = format!("{name}s");
+    ALL_KINDS.iter().all(|k| kinds.contains(k))
+        || kinds.iter().any(|k| {
+            matches!(k, ResourceKind::Custom(wanted)
+                if wanted.eq_ignore_ascii_case(name) || wanted.eq_ignore_ascii_case(&plural))
+        })
+}
+
+fn dump_entry(object: Value) -> Option<DumpEntry> {
+    let kind = object.get("kind")?.as_str()?.to_string();
+    match kind.as_str() {
+        "Pod" => typed_entry(object, ResourceKind::Pod, pod_hooks()),
+        "Service" => typed_entry(
+            object,
+            ResourceKind::Service,
+            KindHooks::new(service_status),
+        ),
+        "Deployment" => typed_entry(
+            object,
+            ResourceKind::Deployment,
+            KindHooks {
+                restarted: deploy_restarted,
+                ..KindHooks::new(deploy_status)
+            },
+        ),
+        "StatefulSet" => typed_entry(
+            object,
+            ResourceKind::StatefulSet,
+            KindHooks {
+                restarted: statefulset_restarted,
+                ..KindHooks::new(statefulset_status)
+            },
+        ),
+        "DaemonSet" => typed_entry(
+            object,
+            ResourceKind::DaemonSet,
+            KindHooks {
+                restarted: daemonset_restarted,
+                ..KindHooks::new(daemonset_status)
+            },
+        ),
+        "ConfigMap" => typed_entry(
+            object,
+            ResourceKind::ConfigMap,
+            KindHooks::new(|_: &ConfigMap| "ConfigMap".to_string()),
+        ),
+        "Secret" => typed_entry(object, ResourceKind::Secret, KindHooks::new(secret_status)),
+        "Ingress" => typed_entry(
+            object,
+            ResourceKind::Ingress,
+            KindHooks::new(ingress_status),
+        ),
+        "Node" => typed_entry(object, ResourceKind::Node, KindHooks::new(node_status)),
+        "Namespace" => typed_entry(
+            object,
+            ResourceKind::Namespace,
+            KindHooks::new(namespace_status),
+        ),
+        "PersistentVolume" => typed_entry(
+            object,
+            ResourceKind::PersistentVolume,
+            KindHooks::new(pv_status),
+        ),
+        "PersistentVolumeClaim" => typed_entry(
+            object,
+            ResourceKind::PersistentVolumeClaim,
+            KindHooks::new(pvc_status),
+        ),
+        "Job" => typed_entry(object, ResourceKind::Job, KindHooks::new(job_status)),
+        "CronJob" => typed_entry(
+            object,
+            ResourceKind::CronJob,
+            KindHooks::new(cronjob_status),
+        ),
+        "Event" => typed_entry(object, ResourceKind::Event, KindHooks::new(event_status)),
+        "Endpoints" => typed_entry(
+            object,
+            ResourceKind::Endpoints,
+            KindHooks::new(endpoints_status),
+        ),
+        "ServiceAccount" => typed_entry(
+            object,
+            ResourceKind::ServiceAccount,
+            KindHooks::new(serviceaccount_status),
+        ),
+        "Role" => typed_entry(object, ResourceKind::Role, KindHooks::new(role_status)),
+        "RoleBinding" => typed_entry(
+            object,
+            ResourceKind::RoleBinding,
+            KindHooks::new(rolebinding_status),
+        ),
+        "HorizontalPodAutoscaler" => typed_entry(
+            object,
+            ResourceKind::HorizontalPodAutoscaler,
+            KindHooks::new(hpa_status),
+        ),
+        _ => typed_entry(
+            object,
+            ResourceKind::Custom(kind),
+            KindHooks::new(other_status),
+        ),
+    }
+}
+
+/// Status of an object of a kind without a built-in model: its
+/// `status.phase`, or `-` when it has none.
+fn other_status(obj: &DynamicObject) -> String {
+    obj.data
+        .pointer("/status/phase")
+        .and_then(Value::as_str)
+        .unwrap_or("-")
+        .to_string()
+}
+
+fn typed_entry<T>(object: Value, kind: ResourceKind, hooks: KindHooks<T>) -> Option<DumpEntry>
+where
+    T: Resource + DeserializeOwned + 'static,
+{
+    let r: T = serde_json::from_value(object).ok()?;
+    Some(DumpEntry {
+        kind,
+        namespace: r.meta().namespace.clone().unwrap_or_default(),
+        name: r.name_any(),
+        state: hooks.state(&r),
+    })
+}
+
+/// Whether `entry` passes the namespace scope, `--exclude-namespaces` and
+/// `--hide-terminating`.
+/// Label selectors are not applied to dumps.
+fn in_scope(entry: &DumpEntry, opts: &WatchOptions) -> bool {
+    if terminating_hidden(&entry.kind, &entry.state.status, opts) {
+        return false;
+    }
+    if entry.namespace.is_empty() {
+        return true;
+    }
+    let namespaces = opts.namespaces();
+    if !namespaces.is_empty() && !namespaces.contains(&entry.namespace) {
+        return false;
+    }
+    !namespace_excluded(&entry.namespace, &opts.exclude_namespaces)
+}
+
+// ─── Reloading ────────────────────────────────────────────────────────────────
+
+/// Items shown from the dump so far, keyed by kind, namespace and name, so a
+/// reload updates rows in place like a watcher reconnect does.
+#[derive(Debug, Default)]
+pub struct DumpState {
+    seen: HashMap<(ResourceKind, String, String), Arc<RwLock<ItemState>>>,
+}
+
+impl DumpState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a freshly parsed dump: rows already shown take the new state,
+    /// rows missing from it are marked `[DELETED]`, and the items that are
+    /// new are returned for sending to skim.
+    pub fn apply(&mut self, entries: Vec<DumpEntry>, context: &str) -> Vec<K8sItem> {
+        let mut present = HashSet::new();
+        let mut added = Vec::new();
+        for entry in entries {
+            let key = (
+                entry.kind.clone(),
+                entry.namespace.clone(),
+                entry.name.clone(),
+            );
+            present.insert(key.clone());
+            if let Some(existing) = self.seen.get(&key) {
+                *existing.write().unwrap() = entry.state;
+            } else {
+                let state = Arc::new(RwLock::new(entry.state));
+                self.seen.insert(key, state.clone());
+                added.push(K8sItem::new_live(
+                    entry.kind,
+                    entry.namespace,
+                    entry.name,
+                    context,
+                    state,
+                ));
+            }
+        }
+        for (key, state) in &self.seen {
+            if !present.contains(key) {
+                state.write().unwrap().status = "[DELETED]".to_string();
+            }
+        }
+        added
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Show the dump at `path` and re-read it whenever its modification time
+/// changes, until skim closes the channel. A read that fails — e.g. a sync
+/// job caught mid-write — keeps the last good state and is retried on the
+/// next poll. Fails only if the first read does.
+pub async fn watch_dump_file(
+    path: PathBuf,
+    tx: SkimItemSender,
+    kinds: Vec<ResourceKind>,
+    opts: WatchOptions,
+) -> Result<()> {
+    let read = |path: &Path| -> Result<Vec<DumpEntry>> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(parse_dump(&text, &kinds)?
+            .into_iter()
+            .filter(|entry| in_scope(entry, &opts))
+            .collect())
+    };
+
+    let mut last_modified = modified(&path);
+    let entries =
+        read(&path).map_err(|e| anyhow::anyhow!("--watch-file {}: {e}", path.display()))?;
+
+    let mut state = DumpState::new();
+    let batch = InitBatch::new(tx, 1, &opts, &kinds);
+    batch.add(state.apply(entries, &opts.context));
+    batch.session_done();
+    batch.run(opts.init_timeout).await;
+
+    while !batch.shutdown.is_triggered() {
+        tokio::select! {
+            () = tokio::time::sleep(POLL_INTERVAL) => {}
+            () = batch.shutdown.wait() => break,
+        }
+        let now_modified = modified(&path);
+        if now_modified == last_modified {
+            continue;
+        }
+        if let Ok(entries) = read(&path) {
+            last_modified = now_modified;
+            // Sent straight away (sorted) now that the first batch is out.
+            batch.add(state.apply(entries, &opts.context));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! `--metrics` — CPU and memory usage from `kubectl top`, shown on pod and
+//! node rows and refreshed in the background.
+
+use std::collections::{HashMap, HashSet};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::items::{K8sItem, ResourceKind};
+use crate::k8s::resources::parse_namespaces;
+use crate::top::{top_args, TopTarget};
+
+/// How often usage is re-read from metrics-server.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+// ─── Fetching ─────────────────────────────────────────────────────────────────
+
+/// Parse one line of `kubectl top pod|node --no-headers` into its key, CPU
+/// and memory. The key is the name, or `namespace/name` for an
+/// all-namespaces pod line. Pod lines are `NAME CPU MEMORY` (with a leading
+/// `NAMESPACE` under `-A`); node lines add a percentage after each of CPU and
+/// memory. `None` for any other shape.
+pub fn parse_top_line(line: &str) -> Option<(String, String, String)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (key, cpu, memory) = match fields.as_slice() {
+        [name, cpu, memory] => ((*name).to_string(), cpu, memory),
+        [ns, name, cpu, memory] => (format!("{ns}/{name}"), cpu, memory),
+        [name, cpu, _, memory, _] => ((*name).to_string(), cpu, memory),
+        _ => return None,
+    };
+    Some((key, (*cpu).to_string(), (*memory).to_string()))
+}
+
+/// Usage of every pod or node `kubectl top` reports in `context`, keyed like
+/// [`usage_key`]: `namespace/name` for pods, the name for nodes. Pods are
+/// read from `namespace` when given, from every namespace otherwise. Empty
+/// for other kinds and whenever kubectl fails (e.g. no metrics-server), so
+/// the column is simply left out.
+pub fn fetch_top(
+    kind: &ResourceKind,
+    namespace: Option<&str>,
+    context: &str,
+) -> HashMap<String, (String, String)> {
+    let target = match kind {
+        ResourceKind::Pod => TopTarget::Pods,
+        ResourceKind::Node => TopTarget::Nodes,
+        _ => return HashMap::new(),
+    };
+    let mut args = top_args(target, namespace, context);
+    args.push("--no-headers".to_string());
+    let out = match Command::new("kubectl").args(&args).output() {
+        Ok(out) if out.status.success() => out,
+        _ => return HashMap::new(),
+    };
+    String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .filter_map(parse_top_line)
+        .map(|(key, cpu, memory)| {
+            let key = match (target, namespace) {
+                (TopTarget::Pods, Some(ns)) if !key.contains('/') => format!("{ns}/{key}"),
+                _ => key,
+            };
+            (key, (cpu, memory))
+        })
+        .collect()
+}
+
+// ─── Applying ─────────────────────────────────────────────────────────────────
+
+/// Key of `item` in a [`fetch_top`] map.
+pub fn usage_key(item: &K8sItem) -> String {
+    if item.namespace().is_empty() {
+        item.name().to_string()
+    } else {
+        format!("{}/{}", item.namespace(), item.name())
+    }
+}
+
+/// The usage text shown on a row, e.g. `cpu 250m mem 512Mi`.
+pub fn usage_text(cpu: &str, memory: &str) -> String {
+    format!("cpu {cpu} mem {memory}")
+}
+
+/// Store the usage of each pod and node among `items`; an item missing from
+/// its map (not yet scraped, or gone) loses its usage.
+pub fn apply_usage(
+    items: &[K8sItem],
+    pods: &HashMap<String, (String, String)>,
+    nodes: &HashMap<String, (String, String)>,
+) {
+    for item in items {
+        let usage = match item.kind() {
+            ResourceKind::Pod => pods,
+            ResourceKind::Node => nodes,
+            _ => continue,
+        };
+        item.state().write().unwrap().usage = usage
+            .get(&usage_key(item))
+            .map(|(cpu, memory)| usage_text(cpu, memory))
+            .unwrap_or_default();
+    }
+}
+
+/// The pods and nodes listed in a session (across all its contexts), whose
+/// usage is kept up to date.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    /// `-n` as given; several comma-separated namespaces are read
+    /// cluster-wide.
+    namespace: Option<String>,
+    items: Mutex<Vec<K8sItem>>,
+}
+
+impl UsageTracker {
+    pub fn new(namespace: Option<String>) -> Self {
+        Self {
+            namespace,
+            items: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Start tracking the pods and nodes among `items`.
+    pub fn track(&self, items: &[K8sItem]) {
+        let mut tracked = self.items.lock().unwrap();
+        tracked.extend(
+            items
+                .iter()
+                .filter(|item| matches!(item.kind(), ResourceKind::Pod | ResourceKind::Node))
+                .cloned(),
+        );
+    }
+
+    /// Re-read usage for every tracked context and store it on the tracked
+    /// items. Deleted items stop being tracked. Blocks on kubectl.
+    pub fn refresh(&self) {
+        let items: Vec<K8sItem> = {
+            let mut tracked = self.items.lock().unwrap();
+            tracked.retain(|item| item.status() != "[DELETED]");
+            tracked.clone()
+        };
+        let namespaces = self
+            .namespace
+            .as_deref()
+            .map(parse_namespaces)
+            .unwrap_or_default();
+        let scope = match namespaces.as_slice() {
+            [ns] => Some(ns.as_str()),
+            _ => None,
+        };
+        let contexts: HashSet<&str> = items.iter().map(K8sItem::context).collect();
+        for context in contexts {
+            let in_context: Vec<K8sItem> = items
+                .iter()
+                .filter(|item| item.context() == context)
+                .cloned()
+                .collect();
+            let fetch = |kind: ResourceKind, namespace: Option<&str>| {
+                if in_context.iter().any(|item| *item.kind() == kind) {
+                    fetch_top(&kind, namespace, context)
+                } else {
+                    HashMap::new()
+                }
+            };
+            let pods = fetch(ResourceKind::Pod, scope);
+            let nodes = fetch(ResourceKind::Node, None);
+            apply_usage(&in_context, &pods, &nodes);
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod client;
 pub mod discovery;
+pub mod dump;
+pub mod metrics;
 pub mod resources;
@@ -1,17 +1,19 @@
 use anyhow::Result;
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use k8s_openapi::{
     api::{
         apps::v1::{DaemonSet, Deployment, StatefulSet},
+        autoscaling::v2::HorizontalPodAutoscaler,
         batch::v1::{CronJob, Job},
         core::v1::{
-            ConfigMap, Event, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod,
-            Secret, Service,
+            ConfigMap, ContainerStatus, Endpoints, Event, Namespace, Node, PersistentVolume,
+            PersistentVolumeClaim, Pod, PodTemplateSpec, Secret, Service, ServiceAccount,
         },
         networking::v1::Ingress,
+        rbac::v1::{Role, RoleBinding},
     },
     apimachinery::pkg::apis::meta::v1::ObjectMeta,
-    jiff::{SpanRound, Timestamp, Unit},
+    jiff::{tz::TimeZone, SpanRound, Timestamp, Unit},
 };
 use kube::{
     api::Api,
@@ -28,12 +30,16 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc, Mutex, RwLock,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
-use tokio::sync::Notify;
+use tokio::sync::{watch, Notify};
 
-use crate::items::{ItemState, K8sItem, ResourceKind, SortField};
-use crate::k8s::discovery::{dynamic_status, DiscoveredCrd};
+use crate::items::{
+    summarize, InfoItem, ItemState, K8sItem, Layout, ResourceKind, SortField,
+    RESTART_WARNING_THRESHOLD,
+};
+use crate::k8s::discovery::{status_fn_for, DiscoveredCrd};
+use crate::k8s::metrics::{UsageTracker, REFRESH_INTERVAL};
 
 /// All resource kinds to watch when no filter is given.
 pub const ALL_KINDS: &[ResourceKind] = &[
@@ -41,7 +47,9 @@ pub const ALL_KINDS: &[ResourceKind] = &[
     ResourceKind::Deployment,
     ResourceKind::StatefulSet,
     ResourceKind::DaemonSet,
+    ResourceKind::HorizontalPodAutoscaler,
     ResourceKind::Service,
+    ResourceKind::Endpoints,
     ResourceKind::Ingress,
     ResourceKind::Job,
     ResourceKind::CronJob,
@@ -51,309 +59,469 @@ pub const ALL_KINDS: &[ResourceKind] = &[
     ResourceKind::PersistentVolumeClaim,
     ResourceKind::Namespace,
     ResourceKind::Node,
+    ResourceKind::ServiceAccount,
+    ResourceKind::Role,
+    ResourceKind::RoleBinding,
     ResourceKind::Event,
 ];
 
+/// Namespaces hidden by `--no-system`.
+pub const SYSTEM_NAMESPACES: &[&str] = &["kube-system", "kube-public", "kube-node-lease"];
+
+/// Whether an item in `namespace` is hidden by `--exclude-namespaces`.
+/// Cluster-scoped items (empty namespace) are never excluded.
+pub fn namespace_excluded(namespace: &str, excluded: &[String]) -> bool {
+    !namespace.is_empty() && excluded.iter().any(|ns| ns == namespace)
+}
+
+/// Namespaces in a `--namespace` value: comma-separated, trimmed, with empty
+/// entries and repeats dropped (`"app, app-system"` → `["app", "app-system"]`).
+pub fn parse_namespaces(value: &str) -> Vec<String> {
+    let mut namespaces: Vec<String> = Vec::new();
+    for ns in value.split(',').map(str::trim).filter(|ns| !ns.is_empty()) {
+        if !namespaces.iter().any(|seen| seen == ns) {
+            namespaces.push(ns.to_string());
+        }
+    }
+    namespaces
+}
+
+/// Status of a pod whose deletion has started.
+pub const TERMINATING: &str = "Terminating";
+
+/// Whether `--hide-terminating` keeps an item of `kind` with `status` out of
+/// the list. Only pods are hidden; a namespace being deleted stays visible.
+pub fn terminating_hidden(kind: &ResourceKind, status: &str, opts: &WatchOptions) -> bool {
+    opts.hide_terminating && *kind == ResourceKind::Pod && status == TERMINATING
+}
+
+/// Consecutive watch failures tolerated per kind when `--max-retries` isn't given.
+pub const DEFAULT_MAX_RETRIES: u32 = 10;
+
+/// Settings for one `watch_resources` session, shared by every watcher it spawns.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Display label attached to every item (empty string in single-cluster mode).
+    pub context: String,
+    /// Restrict namespaced kinds to this namespace, or to a comma-separated
+    /// list of them (cluster-scoped kinds ignore it).
+    pub namespace: Option<String>,
+    pub label_selector: Option<String>,
+    /// Order of the initial batch.
+    pub sort_field: SortField,
+    /// Consecutive failures after which a kind's watcher gives up (0 = retry forever).
+    pub max_retries: u32,
+    /// List each kind once and stop instead of watching (used by `--count`).
+    pub snapshot: bool,
+    /// Objects per page for the initial (re)list; `None` keeps kube's default.
+    pub page_size: Option<u32>,
+    /// Order the initial batch by context first and head each context's
+    /// items with a separator row (`--group-by-context`).
+    pub group_by_context: bool,
+    /// Leave out everything that exists at startup and list only objects
+    /// added, changed or deleted afterwards (`--changes-only`).
+    pub changes_only: bool,
+    /// Hide namespaced items in these namespaces (`--exclude-namespaces`).
+    pub exclude_namespaces: Vec<String>,
+    /// Flip the initial batch's order (`--reverse`).
+    pub sort_reverse: bool,
+    /// skim layout the list is drawn in; batches are sent in the order that
+    /// keeps the sort's "first" items at the top of it.
+    pub layout: Layout,
+    /// Warn when a kind with items gets no events for this long
+    /// (`--stale-after`); `None` disables the check.
+    pub stale_after: Option<Duration>,
+    /// Leave out pods that are shutting down (`--hide-terminating`).
+    pub hide_terminating: bool,
+    /// How long the first render waits for every watcher's initial list
+    /// (`--init-timeout`); `None` waits for all of them.
+    pub init_timeout: Option<Duration>,
+    /// Show `kubectl top` usage on pod and node rows (`--metrics`).
+    pub metrics: bool,
+}
+
+impl WatchOptions {
+    /// The namespaces of [`Self::namespace`]; empty means all of them.
+    pub fn namespaces(&self) -> Vec<String> {
+        self.namespace
+            .as_deref()
+            .map(parse_namespaces)
+            .unwrap_or_default()
+    }
+
+    /// Watchers one kind needs: one per namespace for a namespaced kind
+    /// scoped to several, otherwise one.
+    fn watchers_per_kind(&self, namespaced: bool) -> usize {
+        if namespaced {
+            self.namespaces().len().max(1)
+        } else {
+            1
+        }
+    }
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            context: String::new(),
+            namespace: None,
+            label_selector: None,
+            sort_field: SortField::default(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            snapshot: false,
+            page_size: None,
+            group_by_context: false,
+            changes_only: false,
+            exclude_namespaces: Vec::new(),
+            sort_reverse: false,
+            layout: Layout::default(),
+            stale_after: None,
+            hide_terminating: false,
+            init_timeout: Some(INIT_TIMEOUT),
+            metrics: false,
+        }
+    }
+}
+
+/// State shared by the watcher tasks of one session (one cluster context).
+struct WatchShared {
+    opts: WatchOptions,
+    tx: SkimItemSender,
+    /// Where each watcher's first list goes; may be shared with other sessions.
+    batch: Arc<InitBatch>,
+    done_count: AtomicUsize,
+    total_watchers: usize,
+    /// Items this session contributed to the initial batch.
+    loaded: AtomicUsize,
+    /// How many of them were critical / warnings when listed.
+    critical: AtomicUsize,
+    warning: AtomicUsize,
+    placeholder: InfoItem,
+    /// Last event seen per kind, for [`check_staleness`].
+    activity: Mutex<HashMap<ResourceKind, KindActivity>>,
+    /// Kinds whose failure row is shown. With `-n a,b` a kind has a watcher
+    /// per namespace, and they all fail alike.
+    failed_kinds: Mutex<HashSet<ResourceKind>>,
+}
+
+impl WatchShared {
+    /// Record that one watcher finished (or abandoned) its first initial list;
+    /// once every watcher has reported, the session counts as done.
+    fn mark_init_done(&self) {
+        let finished = self.done_count.fetch_add(1, Ordering::SeqCst) + 1;
+        if finished == self.total_watchers {
+            self.finish();
+        }
+    }
+
+    fn finish(&self) {
+        self.placeholder.set_text(self.settled_text());
+        self.batch.session_done();
+    }
+
+    /// Placeholder text once the initial lists are in.
+    fn settled_text(&self) -> String {
+        if self.opts.changes_only {
+            watching_text(&self.opts.context)
+        } else {
+            loaded_text(
+                &self.opts.context,
+                (
+                    self.loaded.load(Ordering::SeqCst),
+                    self.critical.load(Ordering::SeqCst),
+                    self.warning.load(Ordering::SeqCst),
+                ),
+            )
+        }
+    }
+
+    /// Whether `kind` has no failure row in this session yet; records it.
+    fn first_failure(&self, kind: &ResourceKind) -> bool {
+        self.failed_kinds.lock().unwrap().insert(kind.clone())
+    }
+
+    fn record_event(&self, kind: &ResourceKind, has_items: bool) {
+        self.activity.lock().unwrap().insert(
+            kind.clone(),
+            KindActivity {
+                last_event: Instant::now(),
+                has_items,
+            },
+        );
+    }
+}
+
 /// Watch the given resource kinds from the cluster, streaming live updates into skim.
-/// `context` is a display label attached to every item (empty string in single-cluster mode).
-/// Initial items from ALL watchers are collected into a shared buffer and sent as a single
+/// Initial items from ALL watchers are collected into an [`InitBatch`] and sent as a single
 /// globally-sorted (unhealthy first) batch once every watcher has completed its `InitDone`.
-/// Falls back to sending whatever was collected after 8 seconds to handle slow/failing watchers.
+/// Falls back to sending whatever was collected after `opts.init_timeout` (by default
+/// [`INIT_TIMEOUT`]) to handle slow/failing watchers, and stops every task early once skim
+/// closes the channel.
 /// Subsequent Apply/Delete events are streamed in real-time.
-/// Automatically reconnects on watch failures via `default_backoff`.
-#[allow(clippy::too_many_arguments)]
+/// Automatically reconnects on watch failures via `default_backoff`, giving up on a kind
+/// after `opts.max_retries` consecutive failures.
 pub async fn watch_resources(
     client: Client,
     tx: SkimItemSender,
     kinds: &[ResourceKind],
     crds: &[DiscoveredCrd],
-    context: &str,
-    namespace: Option<&str>,
-    label_selector: Option<&str>,
-    sort_field: SortField,
+    opts: WatchOptions,
 ) -> Result<()> {
-    let total_watchers = kinds.len() + crds.len();
-    let global_init: Arc<Mutex<Vec<K8sItem>>> = Arc::new(Mutex::new(Vec::new()));
-    let done_count: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
-    let all_init_done: Arc<Notify> = Arc::new(Notify::new());
-
-    // Coordinator task: waits for all watchers to finish initial list (or 8s timeout),
-    // then globally sorts and sends the full initial batch to skim.
-    {
-        let global_init = global_init.clone();
-        let tx_coord = tx.clone();
-        let all_init_done = all_init_done.clone();
+    let batch = Arc::new(InitBatch::new(tx, 1, &opts, kinds));
+    let coordinator = {
+        let batch = batch.clone();
+        let timeout = opts.init_timeout;
         tokio::spawn(async move {
-            tokio::select! {
-                () = all_init_done.notified() => {}
-                () = tokio::time::sleep(Duration::from_secs(8)) => {}
-            }
-            let mut buf = global_init.lock().unwrap();
-            sort_items(&mut buf, sort_field);
-            let sorted: Vec<Arc<dyn skim::SkimItem>> = buf
-                .drain(..)
-                .map(|item| Arc::new(item) as Arc<dyn skim::SkimItem>)
-                .collect();
-            if !sorted.is_empty() {
-                let _ = tx_coord.send(sorted);
-            }
-        });
+            batch.run(timeout).await;
+            batch.refresh_usage().await;
+        })
+    };
+    let snapshot = opts.snapshot;
+    watch_resources_into(client, batch, kinds, crds, opts).await?;
+    // In snapshot mode every watcher has stopped after its first list; make sure
+    // the initial batch has been sent before the caller drains the channel.
+    if snapshot {
+        let _ = coordinator.await;
+    }
+    Ok(())
+}
+
+/// Like [`watch_resources`], but contributes this session's initial lists to
+/// a caller-owned `batch` — so several contexts can share one sorted initial
+/// batch — and leaves running the batch's coordinator to the caller.
+pub async fn watch_resources_into(
+    client: Client,
+    batch: Arc<InitBatch>,
+    kinds: &[ResourceKind],
+    crds: &[DiscoveredCrd],
+    opts: WatchOptions,
+) -> Result<()> {
+    let tx = batch.tx.clone();
+    // Placeholder row so the list isn't silently empty until the first batch.
+    let placeholder = InfoItem::new(connecting_text(&opts.context));
+    let _ = tx.send(vec![
+        Arc::new(placeholder.clone()) as Arc<dyn skim::SkimItem>
+    ]);
+
+    let total_watchers = kinds
+        .iter()
+        .map(|kind| opts.watchers_per_kind(!kind.is_cluster_scoped()))
+        .chain(
+            crds.iter()
+                .map(|crd| opts.watchers_per_kind(crd.namespaced)),
+        )
+        .sum();
+    let shared = Arc::new(WatchShared {
+        opts,
+        tx,
+        batch,
+        done_count: AtomicUsize::new(0),
+        total_watchers,
+        loaded: AtomicUsize::new(0),
+        critical: AtomicUsize::new(0),
+        warning: AtomicUsize::new(0),
+        placeholder,
+        activity: Mutex::new(HashMap::new()),
+        failed_kinds: Mutex::new(HashSet::new()),
+    });
+    if shared.total_watchers == 0 {
+        shared.finish();
     }
 
     let mut tasks = Vec::new();
 
     for kind in kinds {
         let c = client.clone();
-        let t = tx.clone();
+        let sh = shared.clone();
         let k = kind.clone();
-        let ctx = context.to_string();
-        let ns = namespace.map(str::to_string);
-        let ls = label_selector.map(str::to_string);
-        let gi = global_init.clone();
-        let dc = done_count.clone();
-        let aid = all_init_done.clone();
 
         tasks.push(tokio::spawn(async move {
             let result = match k {
-                ResourceKind::Pod => {
-                    watch_typed::<Pod, _>(
-                        c,
-                        t,
-                        ResourceKind::Pod,
-                        pod_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
-                    )
-                    .await
-                }
+                ResourceKind::Pod => watch_typed(c, sh, ResourceKind::Pod, true, pod_hooks()).await,
                 ResourceKind::Service => {
-                    watch_typed::<Service, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Service,
-                        service_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(service_status),
                     )
                     .await
                 }
                 ResourceKind::Deployment => {
-                    watch_typed::<Deployment, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Deployment,
-                        deploy_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks {
+                            restarted: deploy_restarted,
+                            ..KindHooks::new(deploy_status)
+                        },
                     )
                     .await
                 }
                 ResourceKind::StatefulSet => {
-                    watch_typed::<StatefulSet, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::StatefulSet,
-                        statefulset_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks {
+                            restarted: statefulset_restarted,
+                            ..KindHooks::new(statefulset_status)
+                        },
                     )
                     .await
                 }
                 ResourceKind::DaemonSet => {
-                    watch_typed::<DaemonSet, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::DaemonSet,
-                        daemonset_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks {
+                            restarted: daemonset_restarted,
+                            ..KindHooks::new(daemonset_status)
+                        },
                     )
                     .await
                 }
                 ResourceKind::ConfigMap => {
-                    watch_typed::<ConfigMap, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::ConfigMap,
-                        |_| "ConfigMap".to_string(),
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(|_: &ConfigMap| "ConfigMap".to_string()),
                     )
                     .await
                 }
                 ResourceKind::Secret => {
-                    watch_typed::<Secret, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Secret,
-                        secret_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(secret_status),
                     )
                     .await
                 }
                 ResourceKind::Ingress => {
-                    watch_typed::<Ingress, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Ingress,
-                        ingress_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(ingress_status),
                     )
                     .await
                 }
                 // Cluster-scoped resources always use Api::all regardless of --namespace
                 ResourceKind::Node => {
-                    watch_typed::<Node, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Node,
-                        node_status,
-                        ctx,
-                        None,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        false,
+                        KindHooks::new(node_status),
                     )
                     .await
                 }
                 ResourceKind::Namespace => {
-                    watch_typed::<Namespace, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Namespace,
-                        namespace_status,
-                        ctx,
-                        None,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        false,
+                        KindHooks::new(namespace_status),
                     )
                     .await
                 }
                 // Cluster-scoped — namespace ignored
                 ResourceKind::PersistentVolume => {
-                    watch_typed::<PersistentVolume, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::PersistentVolume,
-                        pv_status,
-                        ctx,
-                        None,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        false,
+                        KindHooks::new(pv_status),
                     )
                     .await
                 }
                 ResourceKind::PersistentVolumeClaim => {
-                    watch_typed::<PersistentVolumeClaim, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::PersistentVolumeClaim,
-                        pvc_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(pvc_status),
                     )
                     .await
                 }
                 ResourceKind::Job => {
-                    watch_typed::<Job, _>(
-                        c,
-                        t,
-                        ResourceKind::Job,
-                        job_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
-                    )
-                    .await
+                    watch_typed(c, sh, ResourceKind::Job, true, KindHooks::new(job_status)).await
                 }
                 ResourceKind::CronJob => {
-                    watch_typed::<CronJob, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::CronJob,
-                        cronjob_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(cronjob_status),
                     )
                     .await
                 }
                 ResourceKind::Event => {
-                    watch_typed::<Event, _>(
+                    watch_typed(
                         c,
-                        t,
+                        sh,
                         ResourceKind::Event,
-                        event_status,
-                        ctx,
-                        ns,
-                        ls.clone(),
-                        gi,
-                        dc,
-                        total_watchers,
-                        aid,
+                        true,
+                        KindHooks::new(event_status),
+                    )
+                    .await
+                }
+                ResourceKind::HorizontalPodAutoscaler => {
+                    watch_typed(
+                        c,
+                        sh,
+                        ResourceKind::HorizontalPodAutoscaler,
+                        true,
+                        KindHooks::new(hpa_status),
+                    )
+                    .await
+                }
+                ResourceKind::Endpoints => {
+                    watch_typed(
+                        c,
+                        sh,
+                        ResourceKind::Endpoints,
+                        true,
+                        KindHooks::new(endpoints_status),
+                    )
+                    .await
+                }
+                ResourceKind::ServiceAccount => {
+                    watch_typed(
+                        c,
+                        sh,
+                        ResourceKind::ServiceAccount,
+                        true,
+                        KindHooks::new(serviceaccount_status),
+                    )
+                    .await
+                }
+                ResourceKind::Role => {
+                    watch_typed(c, sh, ResourceKind::Role, true, KindHooks::new(role_status)).await
+                }
+                ResourceKind::RoleBinding => {
+                    watch_typed(
+                        c,
+                        sh,
+                        ResourceKind::RoleBinding,
+                        true,
+                        KindHooks::new(rolebinding_status),
                     )
                     .await
                 }
@@ -372,161 +540,670 @@ pub async fn watch_resources(
     // Spawn dynamic watchers for each discovered CRD.
     for crd in crds {
         let c = client.clone();
-        let t = tx.clone();
-        let ctx = context.to_string();
-        let ns = namespace.map(str::to_string);
-        let ls = label_selector.map(str::to_string);
-        let gi = global_init.clone();
-        let dc = done_count.clone();
-        let aid = all_init_done.clone();
+        let sh = shared.clone();
         let kind = ResourceKind::Custom(crd.plural.clone());
         let ar = crd.api_resource.clone();
         let namespaced = crd.namespaced;
+        let status_fn = status_fn_for(crd);
 
         tasks.push(tokio::spawn(async move {
-            if let Err(e) = watch_dynamic(
-                c,
-                t,
-                kind,
-                ar,
-                namespaced,
-                dynamic_status,
-                ctx,
-                ns,
-                ls,
-                gi,
-                dc,
-                total_watchers,
-                aid,
-            )
-            .await
-            {
+            if let Err(e) = watch_dynamic(c, sh, kind, ar, namespaced, status_fn).await {
                 eprintln!("\n[kuberift] {e}");
             }
         }));
     }
 
+    let checker = shared
+        .opts
+        .stale_after
+        .map(|window| tokio::spawn(check_staleness(shared.clone(), window)));
+
     for task in tasks {
         if let Err(e) = task.await {
             eprintln!("[kuberift] warning: watcher task panicked: {e}");
         }
     }
+    if let Some(checker) = checker {
+        checker.abort();
+    }
 
     Ok(())
 }
 
+// ─── Connecting placeholder ──────────────────────────────────────────────────
+
+/// Placeholder text shown until the first batch arrives.
+pub fn connecting_text(context: &str) -> String {
+    if context.is_empty() {
+        "(connecting to cluster…)".to_string()
+    } else {
+        format!("({context}: connecting…)")
+    }
+}
+
+/// What the placeholder turns into once the initial batch has been sent: the
+/// [`summarize`] counts, e.g. `(items:142 crit:3 warn:11)`. skim's header is
+/// fixed at launch, so this live row is where the summary is kept current.
+pub fn loaded_text(context: &str, (total, critical, warning): (usize, usize, usize)) -> String {
+    let summary = format!("items:{total} crit:{critical} warn:{warning}");
+    if context.is_empty() {
+        format!("({summary})")
+    } else {
+        format!("({context}: {summary})")
+    }
+}
+
+/// Text of the row left in place of a kind whose watcher gave up after too
+/// many consecutive failures. An info row, so it can't be acted on or
+/// exported like a resource.
+pub fn unavailable_text(kind: &ResourceKind, context: &str) -> String {
+    if context.is_empty() {
+        format!("({} unavailable: watch gave up)", kind.as_str())
+    } else {
+        format!("({context}: {} unavailable: watch gave up)", kind.as_str())
+    }
+}
+
+/// Text of the row left in place of a kind whose API the cluster doesn't
+/// serve (e.g. `autoscaling/v2` on an old cluster).
+pub fn unsupported_text(kind: &ResourceKind, context: &str) -> String {
+    if context.is_empty() {
+        format!(
+            "({} unsupported: not served by this cluster)",
+            kind.as_str()
+        )
+    } else {
+        format!(
+            "({context}: {} unsupported: not served by this cluster)",
+            kind.as_str()
+        )
+    }
+}
+
+/// Placeholder text in `--changes-only` mode once the watchers are listening.
+pub fn watching_text(context: &str) -> String {
+    if context.is_empty() {
+        "(watching for changes…)".to_string()
+    } else {
+        format!("({context}: watching for changes…)")
+    }
+}
+
+// ─── Stale watch detection ───────────────────────────────────────────────────
+
+/// Default `--stale-after` window, in minutes: off. Kinds such as ConfigMaps,
+/// Secrets and Namespaces routinely go hours without an event, so a quiet
+/// watch only means something to a user who knows what their cluster churns.
+pub const DEFAULT_STALE_AFTER_MINS: u64 = 0;
+
+/// Longest wait between two staleness checks.
+const STALE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// When a kind's watcher last received an event, and whether it had items then.
+#[derive(Debug, Clone, Copy)]
+pub struct KindActivity {
+    pub last_event: Instant,
+    pub has_items: bool,
+}
+
+/// Whether a watch looks stale: the apiserver can stop sending events
+/// without an error. Kinds with nothing listed are never stale — a quiet
+/// empty kind is expected.
+pub fn is_stale(activity: KindActivity, now: Instant, window: Duration) -> bool {
+    activity.has_items && now.saturating_duration_since(activity.last_event) >= window
+}
+
+/// Short names of the kinds in `activity` that look stale, sorted.
+pub fn stale_kinds(
+    activity: &HashMap<ResourceKind, KindActivity>,
+    now: Instant,
+    window: Duration,
+) -> Vec<String> {
+    let mut kinds: Vec<String> = activity
+        .iter()
+        .filter(|(_, a)| is_stale(**a, now, window))
+        .map(|(kind, _)| kind.as_str().to_string())
+        .collect();
+    kinds.sort();
+    kinds
+}
+
+/// `settled` (the placeholder text) with a warning about the `stale` kinds.
+pub fn stale_text(settled: &str, stale: &[String], window: Duration) -> String {
+    let secs = window.as_secs();
+    let window = if secs % 60 == 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{secs}s")
+    };
+    format!(
+        "{settled}  ⚠ no events for {} in {window}; the watch may be stale, refresh to be sure",
+        stale.join(", ")
+    )
+}
+
+/// Every so often, put a warning on the session's placeholder row while any
+/// kind looks stale, and take it off again once events flow.
+async fn check_staleness(shared: Arc<WatchShared>, window: Duration) {
+    let tick = window.min(STALE_CHECK_INTERVAL);
+    let mut warned = false;
+    loop {
+        tokio::select! {
+            () = tokio::time::sleep(tick) => {}
+            () = shared.batch.shutdown.wait() => break,
+        }
+        // The placeholder still says "connecting" until the first lists are in.
+        if shared.done_count.load(Ordering::SeqCst) < shared.total_watchers {
+            continue;
+        }
+        let stale = stale_kinds(&shared.activity.lock().unwrap(), Instant::now(), window);
+        if !stale.is_empty() {
+            shared
+                .placeholder
+                .set_text(stale_text(&shared.settled_text(), &stale, window));
+            warned = true;
+        } else if warned {
+            shared.placeholder.set_text(shared.settled_text());
+            warned = false;
+        }
+    }
+}
+
+// ─── Initial batch ───────────────────────────────────────────────────────────
+
+/// Collects the first list of every watcher in one or more sessions (one per
+/// context) and sends them to skim as a single sorted batch once every
+/// session reports, or when the timeout passes — so one slow cluster can't
+/// hold back the rest. Lists that arrive after that are sorted and sent on
+/// their own.
+pub struct InitBatch {
+    tx: SkimItemSender,
+    state: Mutex<BatchState>,
+    sessions_left: AtomicUsize,
+    all_done: Notify,
+    sort_field: SortField,
+    sort_reverse: bool,
+    layout: Layout,
+    group_by_context: bool,
+    /// Every pod is watched (pods are among the kinds and no `-l` hides
+    /// some), so PVCs can be checked for use.
+    checks_pvcs: bool,
+    /// Claims of the terminating pods `--hide-terminating` leaves out of the
+    /// list, which still hold their PVCs.
+    hidden_claims: Mutex<HashSet<ClaimKey>>,
+    /// Pods and nodes whose usage is shown, with `--metrics`.
+    usage: Option<Arc<UsageTracker>>,
+    /// Triggered by whichever task first finds skim's channel closed; shared
+    /// by every session feeding this batch.
+    pub shutdown: Shutdown,
+}
+
+#[derive(Default)]
+struct BatchState {
+    items: Vec<K8sItem>,
+    flushed: bool,
+}
+
+impl InitBatch {
+    /// A batch that waits for `sessions` calls to [`InitBatch::session_done`].
+    pub fn new(
+        tx: SkimItemSender,
+        sessions: usize,
+        opts: &WatchOptions,
+        kinds: &[ResourceKind],
+    ) -> Self {
+        let batch = Self {
+            tx,
+            state: Mutex::new(BatchState::default()),
+            sessions_left: AtomicUsize::new(sessions),
+            all_done: Notify::new(),
+            sort_field: opts.sort_field,
+            sort_reverse: opts.sort_reverse,
+            layout: opts.layout,
+            group_by_context: opts.group_by_context,
+            checks_pvcs: kinds.contains(&ResourceKind::Pod) && opts.label_selector.is_none(),
+            hidden_claims: Mutex::new(HashSet::new()),
+            usage: opts
+                .metrics
+                .then(|| Arc::new(UsageTracker::new(opts.namespace.clone()))),
+            shutdown: Shutdown::new(),
+        };
+        if sessions == 0 {
+            batch.all_done.notify_one();
+        }
+        batch
+    }
+
+    /// Add one watcher's first list: buffered until the batch is sent, sent
+    /// straight away after that.
+    pub fn add(&self, items: Vec<K8sItem>) {
+        self.track_usage(&items);
+        let mut state = self.state.lock().unwrap();
+        if !state.flushed {
+            state.items.extend(items);
+            return;
+        }
+        drop(state);
+        self.send(items);
+    }
+
+    /// Record the claims of a pod kept out of the initial list, so the PVCs
+    /// it mounts aren't marked unused.
+    pub fn add_hidden_claims(&self, context: &str, namespace: &str, claims: Vec<String>) {
+        let mut hidden = self.hidden_claims.lock().unwrap();
+        hidden.extend(
+            claims
+                .into_iter()
+                .map(|claim| (context.to_string(), namespace.to_string(), claim)),
+        );
+    }
+
+    /// Record that one session has finished (or given up on) its initial lists.
+    pub fn session_done(&self) {
+        let previous = self
+            .sessions_left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+        if previous == Ok(1) {
+            self.all_done.notify_one();
+        }
+    }
+
+    /// Wait for every session (or `timeout`, if any), then send the sorted batch.
+    pub async fn run(&self, timeout: Option<Duration>) -> InitWait {
+        let waited = wait_for_init(&self.all_done, &self.shutdown, timeout).await;
+        if waited == InitWait::Closed {
+            return waited;
+        }
+        let items = {
+            let mut state = self.state.lock().unwrap();
+            state.flushed = true;
+            std::mem::take(&mut state.items)
+        };
+        // A pod list cut short by the timeout would make every PVC look unused.
+        if self.checks_pvcs && waited == InitWait::AllDone {
+            mark_unused_pvcs(&items, &self.hidden_claims.lock().unwrap());
+        }
+        self.send(items);
+        waited
+    }
+
+    /// Keep the usage of `items` up to date, with `--metrics`.
+    pub fn track_usage(&self, items: &[K8sItem]) {
+        if let Some(usage) = &self.usage {
+            usage.track(items);
+        }
+    }
+
+    /// With `--metrics`, re-read the usage of every tracked pod and node
+    /// each [`REFRESH_INTERVAL`] until skim closes; returns at once otherwise.
+    pub async fn refresh_usage(&self) {
+        let Some(usage) = &self.usage else {
+            return;
+        };
+        while !self.shutdown.is_triggered() {
+            let usage = usage.clone();
+            let _ = tokio::task::spawn_blocking(move || usage.refresh()).await;
+            tokio::select! {
+                () = tokio::time::sleep(REFRESH_INTERVAL) => {}
+                () = self.shutdown.wait() => break,
+            }
+        }
+    }
+
+    fn send(&self, mut items: Vec<K8sItem>) {
+        if items.is_empty() {
+            return;
+        }
+        let mut sorted: Vec<Arc<dyn skim::SkimItem>> = if self.group_by_context {
+            group_by_context(items)
+        } else {
+            sort_items_with(&mut items, self.sort_field, self.sort_reverse);
+            items
+                .into_iter()
+                .map(|item| Arc::new(item) as Arc<dyn skim::SkimItem>)
+                .collect()
+        };
+        if self.layout == Layout::Reverse {
+            sorted.reverse();
+        }
+        if self.tx.send(sorted).is_err() {
+            self.shutdown.trigger();
+        }
+    }
+}
+
+// ─── Session shutdown ────────────────────────────────────────────────────────
+
+/// How long the coordinator waits for every watcher's first list before
+/// sending whatever it has, unless `--init-timeout` says otherwise.
+pub const INIT_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// One-way "skim has gone away" flag shared by the coordinator and watchers
+/// of a session, so they stop promptly instead of waiting for their next
+/// event or the init timeout.
+#[derive(Debug)]
+pub struct Shutdown {
+    tx: watch::Sender<bool>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self {
+            tx: watch::channel(false).0,
+        }
+    }
+
+    /// Signal every current and future waiter.
+    pub fn trigger(&self) {
+        self.tx.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.tx.borrow()
+    }
+
+    /// Resolve once [`Shutdown::trigger`] has been called (immediately if it already was).
+    pub async fn wait(&self) {
+        let mut rx = self.tx.subscribe();
+        let _ = rx.wait_for(|closed| *closed).await;
+    }
+}
+
+/// Why the coordinator stopped waiting for the initial lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitWait {
+    AllDone,
+    TimedOut,
+    Closed,
+}
+
+/// Wait until every watcher reports its first list, the session shuts down,
+/// or `timeout` elapses — whichever comes first. Without a timeout only the
+/// first two end the wait.
+pub async fn wait_for_init(
+    all_done: &Notify,
+    shutdown: &Shutdown,
+    timeout: Option<Duration>,
+) -> InitWait {
+    let timed_out = async {
+        match timeout {
+            Some(timeout) => tokio::time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::select! {
+        () = all_done.notified() => InitWait::AllDone,
+        () = shutdown.wait() => InitWait::Closed,
+        () = timed_out => InitWait::TimedOut,
+    }
+}
+
+// ─── Watcher configuration ───────────────────────────────────────────────────
+
+/// Build the `watcher::Config`s for one kind: namespace and label filters plus
+/// the list page size. Field selectors can't OR namespaces together, so a
+/// namespaced kind scoped to several namespaces gets one config (and one
+/// watcher) per namespace. Reconnects resume from the last seen
+/// resourceVersion; a full re-list (paginated by `page_size`) only happens
+/// when it has expired.
+pub fn watcher_configs(opts: &WatchOptions, namespaced: bool) -> Vec<watcher::Config> {
+    let namespaces = if namespaced {
+        opts.namespaces()
+    } else {
+        Vec::new()
+    };
+    let mut base = watcher::Config::default();
+    if let Some(sel) = opts.label_selector.as_deref() {
+        base = base.labels(sel);
+    }
+    if let Some(size) = opts.page_size {
+        base = base.page_size(size);
+    }
+    if namespaces.is_empty() {
+        return vec![base];
+    }
+    namespaces
+        .iter()
+        .map(|ns| base.clone().fields(&format!("metadata.namespace={ns}")))
+        .collect()
+}
+
+// ─── Retry budget ────────────────────────────────────────────────────────────
+
+/// Consecutive-failure counter for one watcher. Reset on every successful
+/// `InitDone`; once failures exceed `max` the watcher stops retrying.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryCounter {
+    failures: u32,
+    max: u32,
+}
+
+impl RetryCounter {
+    /// `max == 0` means unlimited retries.
+    pub fn new(max: u32) -> Self {
+        Self { failures: 0, max }
+    }
+
+    /// Record a failure. Returns `true` when the retry budget is exhausted.
+    pub fn fail(&mut self) -> bool {
+        self.failures = self.failures.saturating_add(1);
+        self.max > 0 && self.failures > self.max
+    }
+
+    /// Clear the failure streak after a successful (re)list.
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+}
+
+// ─── Watch error classification ──────────────────────────────────────────────
+
+/// HTTP status code of the apiserver response behind a watch failure, if any.
+fn watch_error_code(err: &watcher::Error) -> Option<u16> {
+    match err {
+        watcher::Error::InitialListFailed(kube::Error::Api(resp))
+        | watcher::Error::WatchStartFailed(kube::Error::Api(resp))
+        | watcher::Error::WatchFailed(kube::Error::Api(resp)) => Some(resp.code),
+        _ => None,
+    }
+}
+
+/// Whether an apiserver status code means the resource type itself isn't served
+/// (404 on a cluster-wide list, 405 for an unsupported verb) rather than a
+/// transient failure that `default_backoff` should keep retrying.
+pub fn is_api_not_served(code: Option<u16>) -> bool {
+    matches!(code, Some(404 | 405))
+}
+
 // ─── Generic typed watcher ───────────────────────────────────────────────────
 
+/// Time extractor for kinds that have no such time.
+fn no_time<T>(_: &T) -> Option<i64> {
+    None
+}
+
+fn no_claims<T>(_: &T) -> Vec<String> {
+    Vec::new()
+}
+
+fn no_detail<T>(_: &T) -> String {
+    String::new()
+}
+
+/// How the shared watcher loop reads one kind's objects into an
+/// [`ItemState`]. [`KindHooks::new`] takes the status extractor and leaves
+/// the rest empty; kinds with more to show override fields on top of it.
+pub struct KindHooks<T> {
+    pub status: fn(&T) -> String,
+    /// When `rollout restart` last restarted the object; see [`restart_marker`].
+    pub restarted: fn(&T) -> Option<i64>,
+    /// When a container last crashed; see [`with_recent_crash`].
+    pub crashed: fn(&T) -> Option<i64>,
+    /// PVCs the object mounts, for [`mark_unused_pvcs`].
+    pub claims: fn(&T) -> Vec<String>,
+    /// Why the object is unhealthy; see [`pod_detail`].
+    pub detail: fn(&T) -> String,
+}
+
+impl<T: Resource> KindHooks<T> {
+    pub fn new(status: fn(&T) -> String) -> Self {
+        Self {
+            status,
+            restarted: no_time,
+            crashed: no_time,
+            claims: no_claims,
+            detail: no_detail,
+        }
+    }
+
+    /// The list row state of `r`.
+    pub fn state(&self, r: &T) -> ItemState {
+        ItemState {
+            status: (self.status)(r),
+            age: resource_age(r.meta()),
+            restarted_at: (self.restarted)(r),
+            crashed_at: (self.crashed)(r),
+            owner: owner_ref(r.meta()),
+            controlled: has_controller(r.meta()),
+            labels: labels_of(r.meta()),
+            created: created_epoch(r.meta()),
+            claims: (self.claims)(r),
+            detail: (self.detail)(r),
+            usage: String::new(),
+        }
+    }
+}
+
+/// Hooks for pods: status and last crash, mounted claims and failure detail.
+pub fn pod_hooks() -> KindHooks<Pod> {
+    KindHooks {
+        crashed: pod_crashed_at,
+        claims: pod_claims,
+        detail: pod_detail,
+        ..KindHooks::new(pod_base_status)
+    }
+}
+
 /// Watch all resources of type `T` across all namespaces.
 ///
 /// Lifecycle:
 /// - `Init`      → new watch cycle starting; clear the init buffer.
 /// - `InitApply` → existing object; buffer it.
-/// - `InitDone`  → on first init: push to global buffer and signal coordinator;
+/// - `InitDone`  → on first init: hand to the [`InitBatch`] and signal completion;
 ///   on reconnects: sort locally and send directly to skim.
 /// - `Apply`     → live add/modify; send immediately.
 /// - `Delete`    → live deletion; send with `[DELETED]` status so it's visible.
 ///
-/// The watcher reconnects automatically on failures via `default_backoff()`.
+/// Every event is timestamped per kind so [`check_staleness`] can tell when a
+/// watch has gone quiet.
+///
+/// The watcher reconnects automatically on failures via `default_backoff()` until
+/// `max_retries` consecutive failures, then emits an "unavailable" info row and stops.
 /// The loop exits cleanly when skim closes the channel (send returns Err).
-#[allow(clippy::too_many_arguments)]
-async fn watch_typed<T, F>(
+async fn watch_typed<T>(
     client: Client,
-    tx: SkimItemSender,
+    shared: Arc<WatchShared>,
     kind: ResourceKind,
-    status_fn: F,
-    context: String,
-    namespace: Option<String>,
-    label_selector: Option<String>,
-    global_init: Arc<Mutex<Vec<K8sItem>>>,
-    done_count: Arc<AtomicUsize>,
-    total_watchers: usize,
-    all_init_done: Arc<Notify>,
+    namespaced: bool,
+    hooks: KindHooks<T>,
 ) -> Result<()>
 where
     T: Resource<DynamicType = ()> + DeserializeOwned + Clone + Send + Sync + Debug + 'static,
-    F: Fn(&T) -> String,
 {
     let api: Api<T> = Api::all(client);
-    watch_stream(
-        api,
-        tx,
-        kind,
-        status_fn,
-        context,
-        namespace,
-        label_selector,
-        global_init,
-        done_count,
-        total_watchers,
-        all_init_done,
-    )
-    .await
+    watch_stream(api, shared, kind, namespaced, hooks).await
 }
 
 /// Watch a CRD/dynamic resource using `DynamicObject`.
-#[allow(clippy::too_many_arguments)]
-pub async fn watch_dynamic(
+/// For cluster-scoped CRDs the namespace filter is ignored.
+async fn watch_dynamic(
     client: Client,
-    tx: SkimItemSender,
+    shared: Arc<WatchShared>,
     kind: ResourceKind,
     api_resource: kube::discovery::ApiResource,
     namespaced: bool,
     status_fn: fn(&kube::api::DynamicObject) -> String,
-    context: String,
-    namespace: Option<String>,
-    label_selector: Option<String>,
-    global_init: Arc<Mutex<Vec<K8sItem>>>,
-    done_count: Arc<AtomicUsize>,
-    total_watchers: usize,
-    all_init_done: Arc<Notify>,
 ) -> Result<()> {
     let api: Api<kube::api::DynamicObject> = Api::all_with(client, &api_resource);
-    // For cluster-scoped CRDs, ignore the namespace filter.
-    let ns = if namespaced { namespace } else { None };
-    watch_stream(
-        api,
-        tx,
-        kind,
-        status_fn,
-        context,
-        ns,
-        label_selector,
-        global_init,
-        done_count,
-        total_watchers,
-        all_init_done,
-    )
-    .await
+    watch_stream(api, shared, kind, namespaced, KindHooks::new(status_fn)).await
 }
 
 /// Core watcher loop shared by typed and dynamic watchers.
-#[allow(clippy::too_many_arguments)]
-async fn watch_stream<T, F>(
+async fn watch_stream<T>(
     api: Api<T>,
-    tx: SkimItemSender,
+    shared: Arc<WatchShared>,
     kind: ResourceKind,
-    status_fn: F,
-    context: String,
-    namespace: Option<String>,
-    label_selector: Option<String>,
-    global_init: Arc<Mutex<Vec<K8sItem>>>,
-    done_count: Arc<AtomicUsize>,
-    total_watchers: usize,
-    all_init_done: Arc<Notify>,
+    namespaced: bool,
+    hooks: KindHooks<T>,
 ) -> Result<()>
 where
     T: Resource + DeserializeOwned + Clone + Send + Sync + Debug + 'static,
-    F: Fn(&T) -> String,
 {
-    let mut watcher_config = match namespace.as_deref() {
-        Some(ns) => watcher::Config::default().fields(&format!("metadata.namespace={ns}")),
-        None => watcher::Config::default(),
-    };
-    if let Some(sel) = label_selector.as_deref() {
-        watcher_config = watcher_config.labels(sel);
-    }
-    let mut stream = pin!(watcher(api, watcher_config).default_backoff());
+    let watchers = watcher_configs(&shared.opts, namespaced)
+        .into_iter()
+        .map(|config| {
+            let events = watcher(api.clone(), config).default_backoff();
+            handle_events(events, shared.clone(), kind.clone(), &hooks)
+        });
+    futures::future::join_all(watchers).await;
+    Ok(())
+}
+
+/// Run one kind's event loop over `events` as a single-watcher session
+/// feeding `batch` — the same handling a live watcher gets, without a cluster.
+pub async fn watch_event_stream<T, S>(
+    events: S,
+    batch: Arc<InitBatch>,
+    kind: ResourceKind,
+    opts: WatchOptions,
+    hooks: KindHooks<T>,
+) where
+    T: Resource + Clone + Debug + 'static,
+    S: Stream<Item = Result<watcher::Event<T>, watcher::Error>>,
+{
+    let placeholder = InfoItem::new(connecting_text(&opts.context));
+    let shared = Arc::new(WatchShared {
+        opts,
+        tx: batch.tx.clone(),
+        batch,
+        done_count: AtomicUsize::new(0),
+        total_watchers: 1,
+        loaded: AtomicUsize::new(0),
+        critical: AtomicUsize::new(0),
+        warning: AtomicUsize::new(0),
+        placeholder,
+        activity: Mutex::new(HashMap::new()),
+        failed_kinds: Mutex::new(HashSet::new()),
+    });
+    handle_events(events, shared, kind, &hooks).await;
+}
+
+/// Turn a kind's watch events into skim items: buffer the first list for the
+/// shared initial batch, stream later additions, and update known items in place.
+async fn handle_events<T, S>(
+    events: S,
+    shared: Arc<WatchShared>,
+    kind: ResourceKind,
+    hooks: &KindHooks<T>,
+) where
+    T: Resource + Clone + Debug + 'static,
+    S: Stream<Item = Result<watcher::Event<T>, watcher::Error>>,
+{
+    let opts = &shared.opts;
+    let tx = &shared.tx;
+    let context = opts.context.as_str();
+    let mut stream = pin!(events);
+    let mut retries = RetryCounter::new(opts.max_retries);
 
     // Buffer for initial items so we can sort before the first render.
     let mut init_batch: Vec<K8sItem> = Vec::new();
@@ -541,7 +1218,17 @@ where
     // resources deleted while disconnected during a reconnect.
     let mut init_keys: HashSet<(String, String)> = HashSet::new();
 
-    while let Some(event) = stream.next().await {
+    loop {
+        // Stop as soon as any task of this session finds skim gone, even while
+        // this watcher is still waiting on its own initial list.
+        let event = tokio::select! {
+            event = stream.next() => event,
+            () = shared.batch.shutdown.wait() => break,
+        };
+        let Some(event) = event else { break };
+        if event.is_ok() {
+            shared.record_event(&kind, !seen.is_empty() || !init_batch.is_empty());
+        }
         match event {
             // ── Init cycle start ──────────────────────────────────────────────
             Ok(watcher::Event::Init) => {
@@ -553,29 +1240,40 @@ where
             // ── Existing object during initial list ───────────────────────────
             Ok(watcher::Event::InitApply(r)) => {
                 let ns = r.meta().namespace.clone().unwrap_or_default();
+                if namespace_excluded(&ns, &opts.exclude_namespaces) {
+                    continue;
+                }
                 let name = r.name_any();
-                let status = status_fn(&r);
-                let age = resource_age(r.meta());
+                let new_state = hooks.state(&r);
                 let key = (ns.clone(), name.clone());
+                if terminating_hidden(&kind, &new_state.status, opts) {
+                    // Not added to init_keys, so InitDone marks a row that is
+                    // already shown [DELETED]. Its claims still count as mounted.
+                    shared
+                        .batch
+                        .add_hidden_claims(context, &ns, new_state.claims);
+                    continue;
+                }
 
                 init_keys.insert(key.clone());
 
                 if let Some(existing) = seen.get(&key) {
                     // Resource already tracked from a previous watch cycle —
                     // update its state in-place so the existing skim entry refreshes.
-                    let mut state = existing.write().unwrap();
-                    state.status = status;
-                    state.age = age;
+                    refresh_state(&mut existing.write().unwrap(), new_state);
+                } else if opts.changes_only {
+                    // Already there when we (re)listed — not a change, leave it out.
                 } else {
-                    let item_state = Arc::new(RwLock::new(ItemState { status, age }));
+                    let item_state = Arc::new(RwLock::new(new_state));
                     seen.insert(key, item_state.clone());
-                    let item = K8sItem::new_live(kind.clone(), ns, name, &context, item_state);
+                    let item = K8sItem::new_live(kind.clone(), ns, name, context, item_state);
                     if in_init {
                         init_batch.push(item);
                     } else if tx
                         .send(vec![Arc::new(item) as Arc<dyn skim::SkimItem>])
                         .is_err()
                     {
+                        shared.batch.shutdown.trigger();
                         break;
                     }
                 }
@@ -592,57 +1290,70 @@ where
                 }
 
                 if first_init_done {
-                    // Reconnect: sort and send only NEW items directly.
-                    init_batch.sort_by_key(|item| {
-                        let status = item.status();
-                        std::cmp::Reverse(status_priority(&status))
-                    });
+                    // Reconnect: sort and send only NEW items directly, in the
+                    // same order the initial batch used.
+                    sort_items_with(&mut init_batch, opts.sort_field, opts.sort_reverse);
+                    if opts.layout == Layout::Reverse {
+                        init_batch.reverse();
+                    }
+                    shared.batch.track_usage(&init_batch);
                     let sorted: Vec<Arc<dyn skim::SkimItem>> = init_batch
                         .drain(..)
                         .map(|item| Arc::new(item) as Arc<dyn skim::SkimItem>)
                         .collect();
                     if !sorted.is_empty() && tx.send(sorted).is_err() {
+                        shared.batch.shutdown.trigger();
                         break;
                     }
                 } else {
-                    // First init: push into the shared global buffer. The coordinator
-                    // task in watch_resources will do a single globally-sorted send
-                    // once all watchers (or the 8s timeout) complete.
-                    {
-                        let mut buf = global_init.lock().unwrap();
-                        buf.extend(init_batch.drain(..));
-                    }
-                    let finished = done_count.fetch_add(1, Ordering::SeqCst) + 1;
-                    if finished >= total_watchers {
-                        all_init_done.notify_one();
-                    }
+                    // First init: hand the list to the InitBatch, which does a single
+                    // globally-sorted send once all watchers (or the timeout) complete.
+                    let (total, critical, warning) = summarize(&init_batch);
+                    shared.loaded.fetch_add(total, Ordering::SeqCst);
+                    shared.critical.fetch_add(critical, Ordering::SeqCst);
+                    shared.warning.fetch_add(warning, Ordering::SeqCst);
+                    shared.batch.add(std::mem::take(&mut init_batch));
+                    shared.mark_init_done();
                     first_init_done = true;
                 }
                 in_init = false;
+                retries.reset();
+                if opts.snapshot {
+                    break;
+                }
             }
 
             // ── Live add / update ─────────────────────────────────────────────
             Ok(watcher::Event::Apply(r)) => {
                 let ns = r.meta().namespace.clone().unwrap_or_default();
+                if namespace_excluded(&ns, &opts.exclude_namespaces) {
+                    continue;
+                }
                 let name = r.name_any();
-                let status = status_fn(&r);
-                let age = resource_age(r.meta());
+                let new_state = hooks.state(&r);
                 let key = (ns.clone(), name.clone());
+                if terminating_hidden(&kind, &new_state.status, opts) {
+                    // Going away shortly: drop a row that is shown, add none.
+                    if let Some(existing) = seen.get(&key) {
+                        existing.write().unwrap().status = "[DELETED]".to_string();
+                    }
+                    continue;
+                }
 
                 if let Some(existing) = seen.get(&key) {
                     // Existing resource — update state in-place.
-                    let mut state = existing.write().unwrap();
-                    state.status = status;
-                    state.age = age;
+                    refresh_state(&mut existing.write().unwrap(), new_state);
                 } else {
                     // New resource appeared after init — send to skim.
-                    let item_state = Arc::new(RwLock::new(ItemState { status, age }));
+                    let item_state = Arc::new(RwLock::new(new_state));
                     seen.insert(key, item_state.clone());
-                    let item = K8sItem::new_live(kind.clone(), ns, name, &context, item_state);
+                    let item = K8sItem::new_live(kind.clone(), ns, name, context, item_state);
+                    shared.batch.track_usage(std::slice::from_ref(&item));
                     if tx
                         .send(vec![Arc::new(item) as Arc<dyn skim::SkimItem>])
                         .is_err()
                     {
+                        shared.batch.shutdown.trigger();
                         break;
                     }
                 }
@@ -651,23 +1362,76 @@ where
             // ── Live deletion ─────────────────────────────────────────────────
             Ok(watcher::Event::Delete(r)) => {
                 let ns = r.meta().namespace.clone().unwrap_or_default();
+                if namespace_excluded(&ns, &opts.exclude_namespaces) {
+                    continue;
+                }
                 let name = r.name_any();
                 let key = (ns, name);
 
                 if let Some(existing) = seen.get(&key) {
                     existing.write().unwrap().status = "[DELETED]".to_string();
+                    // No new item sent — existing skim item updates via shared state.
+                } else if opts.changes_only {
+                    // Never listed, so the deletion itself is the change to show.
+                    let item_state = Arc::new(RwLock::new(ItemState {
+                        status: "[DELETED]".to_string(),
+                        ..hooks.state(&r)
+                    }));
+                    seen.insert(key.clone(), item_state.clone());
+                    let (ns, name) = key;
+                    let item = K8sItem::new_live(kind.clone(), ns, name, context, item_state);
+                    if tx
+                        .send(vec![Arc::new(item) as Arc<dyn skim::SkimItem>])
+                        .is_err()
+                    {
+                        shared.batch.shutdown.trigger();
+                        break;
+                    }
                 }
-                // No new item sent — existing skim item updates via shared state.
             }
 
             // ── Watch error — default_backoff handles retry ───────────────────
             Err(e) => {
-                eprintln!("[kuberift] watch error ({}): {e}", kind.as_str());
+                let text = if is_api_not_served(watch_error_code(&e)) {
+                    // Retrying can't help: the apiserver doesn't serve this API.
+                    eprintln!(
+                        "[kuberift] {} is not served by this cluster — skipping",
+                        kind.as_str()
+                    );
+                    unsupported_text(&kind, context)
+                } else {
+                    eprintln!("[kuberift] watch error ({}): {e}", kind.as_str());
+                    if !retries.fail() {
+                        continue;
+                    }
+                    eprintln!(
+                        "[kuberift] giving up on {} after {} consecutive watch failures",
+                        kind.as_str(),
+                        retries.failures()
+                    );
+                    unavailable_text(&kind, context)
+                };
+                if !first_init_done {
+                    shared.mark_init_done();
+                }
+                if shared.first_failure(&kind) {
+                    let row = InfoItem::new(text);
+                    let _ = tx.send(vec![Arc::new(row) as Arc<dyn skim::SkimItem>]);
+                }
+                break;
             }
         }
     }
+}
 
-    Ok(())
+/// Take the state of a re-read object, keeping the creation time first seen
+/// and the `--metrics` usage, which the watcher doesn't read.
+fn refresh_state(state: &mut ItemState, new: ItemState) {
+    *state = ItemState {
+        created: state.created,
+        usage: std::mem::take(&mut state.usage),
+        ..new
+    };
 }
 
 // ─── Status priority (lower = shown first) ───────────────────────────────────
@@ -676,8 +1440,15 @@ pub fn status_priority(status: &str) -> u8 {
     crate::items::StatusHealth::classify(status).priority()
 }
 
-/// Sort a batch of items by the given field.
+/// Sort a batch of items by the given field. Skim lists the last item of a
+/// batch nearest the top, so "first" orders (unhealthy, newest) sort last.
 pub fn sort_items(items: &mut [K8sItem], field: SortField) {
+    sort_items_with(items, field, false);
+}
+
+/// [`sort_items`], flipped when `reverse` is set. Items whose age is unknown
+/// (`?`) stay at the far end of an age sort in either direction.
+pub fn sort_items_with(items: &mut [K8sItem], field: SortField, reverse: bool) {
     match field {
         SortField::Health => {
             items.sort_by_key(|item| {
@@ -690,27 +1461,139 @@ pub fn sort_items(items: &mut [K8sItem], field: SortField) {
         SortField::Kind => items.sort_by(|a, b| a.kind().as_str().cmp(b.kind().as_str())),
         SortField::Status => items.sort_by_key(K8sItem::status),
         SortField::Age => {
-            // Age strings like "1d", "5h", "30m" — sort alphabetically
-            // (imprecise, but the RwLock-based age is a display string, not a timestamp)
-            items.sort_by(|a, b| {
-                let a_state = a.state().read().unwrap();
-                let b_state = b.state().read().unwrap();
-                a_state.age.cmp(&b_state.age)
-            });
+            sort_by_age(items, reverse);
+            return;
+        }
+    }
+    if reverse {
+        items.reverse();
+    }
+}
+
+/// Oldest first, so the newest items end up at the top in skim; `reverse`
+/// puts the oldest there instead. Ages are compared as durations via
+/// [`parse_age`], so `2m` sorts before `10m` and `9h` before `10h`.
+fn sort_by_age(items: &mut [K8sItem], reverse: bool) {
+    items.sort_by_cached_key(|item| {
+        let age = parse_age(&item.state().read().unwrap().age);
+        let known = age.is_some();
+        let age = age.unwrap_or_default();
+        (known, if reverse { age } else { Duration::MAX - age })
+    });
+}
+
+// ─── Unused PVCs ─────────────────────────────────────────────────────────────
+
+/// Status given to a `Bound` PVC that no watched pod mounts.
+pub const UNUSED_PVC_STATUS: &str = "Bound (unused)";
+
+/// Names of the PVCs `pod` mounts.
+pub fn pod_claims(pod: &Pod) -> Vec<String> {
+    pod.spec
+        .as_ref()
+        .and_then(|s| s.volumes.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.persistent_volume_claim.as_ref())
+        .map(|pvc| pvc.claim_name.clone())
+        .collect()
+}
+
+/// A claim mounted by a pod: its context, namespace and PVC name.
+pub type ClaimKey = (String, String, String);
+
+/// Mark every `Bound` PVC in `items` that no pod in `items` mounts, nor any
+/// claim in `also_mounted`, as [`UNUSED_PVC_STATUS`]. Only meaningful when
+/// `items` holds the complete pod list for the PVCs' namespaces. The mark is
+/// a snapshot of the first list: later pod and PVC events don't update it.
+pub fn mark_unused_pvcs(items: &[K8sItem], also_mounted: &HashSet<ClaimKey>) {
+    let mut mounted: HashSet<ClaimKey> = items
+        .iter()
+        .filter(|item| matches!(item.kind(), ResourceKind::Pod))
+        .flat_map(|pod| {
+            pod.claims().into_iter().map(|claim| {
+                (
+                    pod.context().to_string(),
+                    pod.namespace().to_string(),
+                    claim,
+                )
+            })
+        })
+        .collect();
+    mounted.extend(also_mounted.iter().cloned());
+    for pvc in items
+        .iter()
+        .filter(|item| matches!(item.kind(), ResourceKind::PersistentVolumeClaim))
+    {
+        let key = (
+            pvc.context().to_string(),
+            pvc.namespace().to_string(),
+            pvc.name().to_string(),
+        );
+        if !mounted.contains(&key) {
+            let mut state = pvc.state().write().unwrap();
+            if state.status == "Bound" {
+                state.status = UNUSED_PVC_STATUS.to_string();
+            }
         }
     }
 }
 
+// ─── Grouping by context (--group-by-context) ────────────────────────────────
+
+/// Sort by context, then unhealthy first, then namespace and name.
+pub fn sort_grouped(items: &mut [K8sItem]) {
+    items.sort_by_cached_key(|item| {
+        (
+            item.context().to_string(),
+            std::cmp::Reverse(status_priority(&item.status())),
+            item.namespace().to_string(),
+            item.name().to_string(),
+        )
+    });
+}
+
+/// The row that opens a context's group.
+pub fn context_separator(context: &str) -> InfoItem {
+    InfoItem::new(format!("── {context} ──"))
+}
+
+/// Sort `items` with [`sort_grouped`] and insert a [`context_separator`]
+/// before each context's first item. Separators don't downcast to
+/// [`K8sItem`], so selecting one is a no-op.
+pub fn group_by_context(mut items: Vec<K8sItem>) -> Vec<Arc<dyn skim::SkimItem>> {
+    sort_grouped(&mut items);
+    let mut out: Vec<Arc<dyn skim::SkimItem>> = Vec::with_capacity(items.len() + 1);
+    let mut current: Option<String> = None;
+    for item in items {
+        if current.as_deref() != Some(item.context()) {
+            current = Some(item.context().to_string());
+            out.push(Arc::new(context_separator(item.context())));
+        }
+        out.push(Arc::new(item));
+    }
+    out
+}
+
 // ─── Per-resource status extractors ──────────────────────────────────────────
 
+/// A pod's status as of now: [`pod_base_status`], noting a container crash
+/// within the last few minutes (see [`with_recent_crash`]).
 pub fn pod_status(pod: &Pod) -> String {
+    with_recent_crash(&pod_base_status(pod), pod_crashed_at(pod))
+}
+
+/// A pod's status without the recent-crash note, which goes stale as time
+/// passes; watchers store this and [`pod_crashed_at`] instead.
+pub fn pod_base_status(pod: &Pod) -> String {
     if pod.metadata.deletion_timestamp.is_some() {
-        return "Terminating".to_string();
+        return TERMINATING.to_string();
     }
     let Some(status) = &pod.status else {
         return "Unknown".to_string();
     };
-    // Container-level waiting reasons (CrashLoopBackOff, etc.)
+    // Container-level waiting reasons (CrashLoopBackOff, etc.), with the
+    // pod's restart count
     if let Some(css) = &status.container_statuses {
         for cs in css {
             if let Some(state) = &cs.state {
@@ -719,16 +1602,17 @@ pub fn pod_status(pod: &Pod) -> String {
                         // "PodInitializing" = main container waiting for init containers —
                         // skip it and let the init container block below compute Init:X/Y
                         if reason != "ContainerCreating" && reason != "PodInitializing" {
-                            return reason.clone();
+                            return with_restart_count(reason.clone(), css);
                         }
                     }
                 }
                 if let Some(terminated) = &state.terminated {
                     if terminated.exit_code != 0 {
-                        return terminated
+                        let reason = terminated
                             .reason
                             .clone()
                             .unwrap_or_else(|| "Error".to_string());
+                        return with_restart_count(reason, css);
                     }
                 }
             }
@@ -761,10 +1645,120 @@ pub fn pod_status(pod: &Pod) -> String {
             return format!("Init:{done}/{total}");
         }
     }
-    status
+    let phase = status
         .phase
         .clone()
-        .unwrap_or_else(|| "Unknown".to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+    if phase == "Running" {
+        // An ephemeral container still running means a `kubectl debug` session.
+        if status
+            .ephemeral_container_statuses
+            .iter()
+            .flatten()
+            .any(|cs| cs.state.as_ref().is_some_and(|s| s.running.is_some()))
+        {
+            return DEBUG_STATUS.to_string();
+        }
+        // Long since recovered, but restarting often.
+        if let Some(css) = &status.container_statuses {
+            if total_restarts(css) > RESTART_WARNING_THRESHOLD {
+                return with_restart_count(phase, css);
+            }
+        }
+    }
+    phase
+}
+
+/// The message behind an unhealthy pod's status, e.g. `Back-off pulling
+/// image "registry/foo:bar"`: the first waiting or failed container's state
+/// message (init containers first), else the pod's own (`Evicted` pods).
+/// Flattened to one line; empty when there is nothing to explain.
+pub fn pod_detail(pod: &Pod) -> String {
+    let Some(status) = &pod.status else {
+        return String::new();
+    };
+    let container_message = status
+        .init_container_statuses
+        .iter()
+        .flatten()
+        .chain(status.container_statuses.iter().flatten())
+        .filter_map(|cs| cs.state.as_ref())
+        .find_map(|state| {
+            let waiting = state.waiting.as_ref().and_then(|w| w.message.clone());
+            let failed = state
+                .terminated
+                .as_ref()
+                .filter(|t| t.exit_code != 0)
+                .and_then(|t| t.message.clone());
+            waiting.or(failed)
+        });
+    container_message
+        .or_else(|| status.message.clone())
+        .map(|m| m.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+}
+
+/// Status of a running pod with an active `kubectl debug` container.
+pub const DEBUG_STATUS: &str = "Running (debug)";
+
+/// `reason` followed by the restarts summed over `statuses`, e.g.
+/// `CrashLoopBackOff (x7)`; just `reason` when nothing has restarted.
+fn with_restart_count(reason: String, statuses: &[ContainerStatus]) -> String {
+    let restarts = total_restarts(statuses);
+    if restarts > 0 {
+        format!("{reason} (x{restarts})")
+    } else {
+        reason
+    }
+}
+
+/// Restarts summed over `statuses`; negative counts are treated as zero.
+fn total_restarts(statuses: &[ContainerStatus]) -> u64 {
+    statuses
+        .iter()
+        .map(|cs| u64::try_from(cs.restart_count).unwrap_or(0))
+        .sum()
+}
+
+/// Containers that terminated within this window still flag their pod.
+const RECENT_CRASH_SECS: i64 = 5 * 60;
+
+/// When the pod's most recently restarted container last terminated, in Unix
+/// seconds; `None` when no container has restarted.
+pub fn pod_crashed_at(pod: &Pod) -> Option<i64> {
+    pod.status
+        .as_ref()?
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter(|cs| cs.restart_count > 0)
+        .filter_map(|cs| {
+            cs.last_state
+                .as_ref()?
+                .terminated
+                .as_ref()?
+                .finished_at
+                .as_ref()
+                .map(|t| t.0.as_second())
+        })
+        .max()
+}
+
+/// `status` as of now: a running pod whose container crashed (at
+/// `crashed_at`, Unix seconds) within [`RECENT_CRASH_SECS`] gets a
+/// `(restarted 2m ago)` note after its status, e.g. `Running (x12) (restarted
+/// 2m ago)`, so it doesn't pass as healthy. Worked out on every read, so the
+/// note ages and clears without a new watch event.
+pub fn with_recent_crash(status: &str, crashed_at: Option<i64>) -> String {
+    let running = status == "Running" || status.starts_with("Running (");
+    let age = crashed_at
+        .filter(|&ts| running && Timestamp::now().as_second() - ts <= RECENT_CRASH_SECS)
+        .and_then(|ts| Timestamp::from_second(ts).ok())
+        .and_then(age_since);
+    match age {
+        Some(age) => format!("{status} (restarted {age} ago)"),
+        None => status.to_string(),
+    }
 }
 
 pub fn service_status(svc: &Service) -> String {
@@ -775,6 +1769,9 @@ pub fn service_status(svc: &Service) -> String {
         .to_string()
 }
 
+/// `ready/desired`, e.g. `3/3`. Mid-rollout — fewer replicas updated than
+/// desired, old ones still around, or some unavailable — the progression
+/// follows: `1/3 (updated 2/3, available 1, unavailable 2)`.
 pub fn deploy_status(d: &Deployment) -> String {
     let ready = d
         .status
@@ -782,7 +1779,23 @@ pub fn deploy_status(d: &Deployment) -> String {
         .and_then(|s| s.ready_replicas)
         .unwrap_or(0);
     let desired = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
-    format!("{ready}/{desired}")
+    let ratio = format!("{ready}/{desired}");
+    // Older controllers and hand-written objects don't report updatedReplicas.
+    let Some((status, updated)) = d
+        .status
+        .as_ref()
+        .and_then(|s| Some((s, s.updated_replicas?)))
+    else {
+        return ratio;
+    };
+    let available = status.available_replicas.unwrap_or(0);
+    let unavailable = status.unavailable_replicas.unwrap_or(0);
+    if updated >= desired && status.replicas.unwrap_or(0) <= updated && unavailable == 0 {
+        return ratio;
+    }
+    format!(
+        "{ratio} (updated {updated}/{desired}, available {available}, unavailable {unavailable})"
+    )
 }
 
 pub fn statefulset_status(sts: &StatefulSet) -> String {
@@ -801,6 +1814,53 @@ pub fn daemonset_status(ds: &DaemonSet) -> String {
     format!("{ready}/{desired}")
 }
 
+/// `current/desired` replicas, followed by the first resource metric's
+/// current average utilization when the HPA reports one: `3/5 (cpu 80%)`.
+pub fn hpa_status(hpa: &HorizontalPodAutoscaler) -> String {
+    let Some(status) = &hpa.status else {
+        return "0/0".to_string();
+    };
+    let current = status.current_replicas.unwrap_or(0);
+    let ratio = format!("{current}/{}", status.desired_replicas);
+    let utilization = status.current_metrics.iter().flatten().find_map(|m| {
+        let resource = m.resource.as_ref()?;
+        Some((
+            resource.name.as_str(),
+            resource.current.average_utilization?,
+        ))
+    });
+    match utilization {
+        Some((name, percent)) => format!("{ratio} ({name} {percent}%)"),
+        None => ratio,
+    }
+}
+
+/// Ready addresses across all subsets: `3 ready`, or `0 ready` for a
+/// service with no backends.
+pub fn endpoints_status(ep: &Endpoints) -> String {
+    let ready: usize = ep
+        .subsets
+        .iter()
+        .flatten()
+        .map(|subset| subset.addresses.as_ref().map_or(0, Vec::len))
+        .sum();
+    format!("{ready} ready")
+}
+
+/// Number of secrets the service account lists.
+pub fn serviceaccount_status(sa: &ServiceAccount) -> String {
+    format!("{} secrets", sa.secrets.as_ref().map_or(0, Vec::len))
+}
+
+pub fn role_status(role: &Role) -> String {
+    format!("{} rules", role.rules.as_ref().map_or(0, Vec::len))
+}
+
+/// Number of users, groups and service accounts the binding grants its role to.
+pub fn rolebinding_status(rb: &RoleBinding) -> String {
+    format!("{} subjects", rb.subjects.as_ref().map_or(0, Vec::len))
+}
+
 pub fn secret_status(s: &Secret) -> String {
     s.type_.clone().unwrap_or_else(|| "Opaque".to_string())
 }
@@ -816,21 +1876,52 @@ pub fn ingress_status(ing: &Ingress) -> String {
         .to_string()
 }
 
+/// Prefix of the labels that give a node its roles (`…/control-plane`).
+const NODE_ROLE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
+
+/// `Ready`, `NotReady` or `Unknown` from the Ready condition, followed by the
+/// node's roles and kubelet version when known: `Ready (control-plane, v1.29.2)`.
 pub fn node_status(node: &Node) -> String {
-    node.status
+    let ready = node
+        .status
         .as_ref()
         .and_then(|s| s.conditions.as_ref())
         .and_then(|conds| conds.iter().find(|c| c.type_ == "Ready"))
-        .map_or_else(
-            || "Unknown".to_string(),
-            |c| {
-                if c.status == "True" {
-                    "Ready".to_string()
-                } else {
-                    "NotReady".to_string()
-                }
-            },
-        )
+        .map_or("Unknown", |c| {
+            if c.status == "True" {
+                "Ready"
+            } else {
+                "NotReady"
+            }
+        });
+    let mut details = node_roles(node);
+    if let Some(version) = node
+        .status
+        .as_ref()
+        .and_then(|s| s.node_info.as_ref())
+        .map(|info| info.kubelet_version.as_str())
+        .filter(|v| !v.is_empty())
+    {
+        details.push(version.to_string());
+    }
+    if details.is_empty() {
+        ready.to_string()
+    } else {
+        format!("{ready} ({})", details.join(", "))
+    }
+}
+
+/// Roles from `node-role.kubernetes.io/<role>` labels, sorted.
+fn node_roles(node: &Node) -> Vec<String> {
+    let mut roles: Vec<String> = node
+        .labels()
+        .keys()
+        .filter_map(|key| key.strip_prefix(NODE_ROLE_LABEL_PREFIX))
+        .filter(|role| !role.is_empty())
+        .map(str::to_string)
+        .collect();
+    roles.sort();
+    roles
 }
 
 pub fn namespace_status(ns: &Namespace) -> String {
@@ -897,31 +1988,171 @@ pub fn event_status(event: &Event) -> String {
     }
 }
 
+// ─── Owner helper ─────────────────────────────────────────────────────────────
+
+/// Controlling owner of a resource as `Kind/name` (e.g. `ReplicaSet/api-7d9f8b6c5`),
+/// falling back to the first owner reference; empty when it has none.
+pub fn owner_ref(meta: &ObjectMeta) -> String {
+    let refs = meta.owner_references.as_deref().unwrap_or_default();
+    refs.iter()
+        .find(|r| r.controller == Some(true))
+        .or_else(|| refs.first())
+        .map(|r| format!("{}/{}", r.kind, r.name))
+        .unwrap_or_default()
+}
+
+/// Whether a resource has a controlling owner (`controller: true`), which
+/// recreates it when it is deleted.
+pub fn has_controller(meta: &ObjectMeta) -> bool {
+    meta.owner_references
+        .iter()
+        .flatten()
+        .any(|r| r.controller == Some(true))
+}
+
 // ─── Age helper ───────────────────────────────────────────────────────────────
 
 pub fn resource_age(meta: &ObjectMeta) -> String {
     meta.creation_timestamp
         .as_ref()
-        .and_then(|t| {
-            Timestamp::now()
-                .since(t.0)
-                .ok()
-                .and_then(|dur| {
-                    dur.round(
-                        SpanRound::new()
-                            .largest(Unit::Day)
-                            .days_are_24_hours()
-                            .smallest(Unit::Minute),
-                    )
-                    .ok()
-                })
-                .map(
-                    |dur| match (dur.get_days(), dur.get_hours(), dur.get_minutes()) {
-                        (d, _, _) if d > 0 => format!("{d}d"),
-                        (_, h, _) if h > 0 => format!("{h}h"),
-                        (_, _, m) => format!("{m}m"),
-                    },
-                )
-        })
+        .and_then(|t| age_since(t.0))
         .unwrap_or_else(|| "?".to_string())
 }
+
+/// `metadata.labels` as `(key, value)` pairs, sorted by key.
+pub fn labels_of(meta: &ObjectMeta) -> Vec<(String, String)> {
+    meta.labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+/// Creation time in Unix seconds, if the object has one.
+pub fn created_epoch(meta: &ObjectMeta) -> Option<i64> {
+    meta.creation_timestamp.as_ref().map(|t| t.0.as_second())
+}
+
+/// Ages of at least this many days are shown in weeks (or years).
+const WEEKS_FROM_DAYS: i64 = 28;
+
+/// Compact elapsed time since `t` ("30m", "5h", "2d", "7w", "1y2mo"), or
+/// `None` if it can't be computed. Days run up to four weeks, weeks up to a
+/// calendar year, then years and months.
+pub fn age_since(t: Timestamp) -> Option<String> {
+    let now = Timestamp::now();
+    let dur = now
+        .since(t)
+        .ok()?
+        .round(
+            SpanRound::new()
+                .largest(Unit::Day)
+                .days_are_24_hours()
+                .smallest(Unit::Minute),
+        )
+        .ok()?;
+    let age = match (dur.get_days(), dur.get_hours(), dur.get_minutes()) {
+        (d, _, _) if d >= WEEKS_FROM_DAYS => {
+            let calendar = now
+                .to_zoned(TimeZone::UTC)
+                .since((Unit::Year, &t.to_zoned(TimeZone::UTC)))
+                .ok()?;
+            match (calendar.get_years(), calendar.get_months()) {
+                (0, _) => format!("{}w", d / 7),
+                (y, 0) => format!("{y}y"),
+                (y, mo) => format!("{y}y{mo}mo"),
+            }
+        }
+        (d, _, _) if d > 0 => format!("{d}d"),
+        (_, h, _) if h > 0 => format!("{h}h"),
+        (_, _, m) => format!("{m}m"),
+    };
+    Some(age)
+}
+
+/// Invert [`age_since`]'s format back into a duration, so ages can be compared
+/// without storing timestamps. Accepts `y`/`mo`/`w`/`d`/`h`/`m`/`s` units and
+/// compound forms like `1d4h` or `1y2mo` (a year counts 365 days, a month
+/// 30); returns `None` for `?` or anything unparseable.
+///
+/// Precision is limited to what the display string kept: `resource_age` rounds
+/// to the minute and then shows only the largest unit, so `2d` stands for
+/// anything from 2 to just under 3 days.
+pub fn parse_age(s: &str) -> Option<Duration> {
+    const DAY: u64 = 24 * 60 * 60;
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let mut total: u64 = 0;
+    let mut digits = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let n: u64 = digits.parse().ok()?;
+        digits.clear();
+        let unit = match c {
+            'y' => 365 * DAY,
+            'm' if chars.next_if_eq(&'o').is_some() => 30 * DAY,
+            'w' => 7 * DAY,
+            'd' => DAY,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        total = total.checked_add(n.checked_mul(unit)?)?;
+    }
+    // A trailing number without a unit is malformed.
+    if !digits.is_empty() {
+        return None;
+    }
+    Some(Duration::from_secs(total))
+}
+
+// ─── Rollout restart marker ──────────────────────────────────────────────────
+
+/// Annotation `kubectl rollout restart` stamps on the pod template.
+pub const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+
+/// Restarts older than this are no longer flagged in the list.
+const RECENT_RESTART_SECS: i64 = 60 * 60;
+
+/// The pod template's `restartedAt` annotation in Unix seconds; `None` when
+/// it is missing or unreadable.
+pub fn restarted_at(template: &PodTemplateSpec) -> Option<i64> {
+    template
+        .metadata
+        .as_ref()
+        .and_then(|m| m.annotations.as_ref())
+        .and_then(|a| a.get(RESTARTED_AT_ANNOTATION))
+        .and_then(|ts| ts.parse::<Timestamp>().ok())
+        .map(Timestamp::as_second)
+}
+
+/// `↺ 2m ago` marker for a restart within [`RECENT_RESTART_SECS`] of now;
+/// empty otherwise. Worked out when the row is drawn, so the marker keeps
+/// ageing and disappears without the workload changing again.
+pub fn restart_marker(restarted_at: Option<i64>) -> String {
+    restarted_at
+        .filter(|&ts| Timestamp::now().as_second() - ts <= RECENT_RESTART_SECS)
+        .and_then(|ts| Timestamp::from_second(ts).ok())
+        .and_then(age_since)
+        .map(|age| format!("↺ {age} ago"))
+        .unwrap_or_default()
+}
+
+pub fn deploy_restarted(d: &Deployment) -> Option<i64> {
+    d.spec.as_ref().and_then(|s| restarted_at(&s.template))
+}
+
+pub fn statefulset_restarted(sts: &StatefulSet) -> Option<i64> {
+    sts.spec.as_ref().and_then(|s| restarted_at(&s.template))
+}
+
+pub fn daemonset_restarted(ds: &DaemonSet) -> Option<i64> {
+    ds.spec.as_ref().and_then(|s| restarted_at(&s.template))
+}
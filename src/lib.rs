@@ -16,3 +16,7 @@ pub mod cli;
 pub mod config;
 pub mod items;
 pub mod k8s;
+pub mod output;
+pub mod rollout;
+pub mod top;
+pub mod ui;
@@ -11,24 +11,50 @@ use clap::{CommandFactory, Parser};
 use clap_complete::generate;
 use crossterm::event::{KeyCode, KeyModifiers};
 use kuberift::actions::{
-    action_delete, action_describe, action_edit, action_exec, action_logs, action_portforward,
-    action_rollout_restart, action_scale, action_yaml, install_preview_toggle, preview_toggle_path,
-    runtime_dir,
+    action_apply_prompt, action_copy_yaml, action_cordon, action_delete, action_delete_matching,
+    action_describe, action_drain, action_drift, action_edit, action_exec, action_force_delete,
+    action_jsonpath, action_logs, action_logs_smart, action_portforward, action_raw_watch,
+    action_report, action_restart_container, action_rollout_pause, action_rollout_restart,
+    action_rollout_resume, action_scale, action_show_commands, action_uncordon, action_yaml,
+    install_preview_toggle, install_preview_toggle_preserving, parse_kubectl_version, runtime_dir,
+    set_kubectl_version, should_pause, wait_for_key, SelectorDelete, MIN_KUBECTL_VERSION,
+};
+use kuberift::cli::{
+    add_label_requirement, apply_exclusions, ignored_namespace_notice, kind_label,
+    parse_resource_kinds, status_footer, validate_label_selector, Args, EffectiveConfig,
+};
+use kuberift::config::{config_path, load_config, load_status_overrides};
+use kuberift::items::{
+    color_legend, count_matching, demo_items, kind_visible, set_age_format, set_columns,
+    set_compact_status, set_describe_width, set_list_width_source, set_name_width, set_show_labels,
+    set_status_overrides, set_theme, theme_preview, toggle_hidden, K8sItem, OutputFormat,
+    ResourceKind, SortField,
 };
-use kuberift::cli::Args;
-use kuberift::config::load_config;
-use kuberift::items::{K8sItem, ResourceKind, SortField};
 #[allow(unused_imports)]
 use kuberift::k8s::{
     client::{
-        build_client_for_context, current_context, list_contexts, load_last_context,
-        save_last_context,
+        build_client_for_context, clear_last_context, context_namespace, current_context_in,
+        list_contexts, load_last_context, save_last_context, select_contexts,
+        warn_context_collisions,
+    },
+    discovery::{
+        cross_reference, discover_crds, render_api_resources, served_resources, DiscoveredCrd,
     },
-    discovery::{discover_crds, DiscoveredCrd},
-    resources::{watch_resources, ALL_KINDS},
+    dump::watch_dump_file,
+    resources::{
+        parse_namespaces, sort_items_with, watch_resources, watch_resources_into, InitBatch,
+        WatchOptions, ALL_KINDS,
+    },
+};
+use kuberift::output::{color_enabled, render_table};
+use kuberift::rollout::run_rollout;
+use kuberift::top::run_top;
+use kuberift::ui::{
+    build_pick_options, build_skim_options, dedup_selection, grouped_json_output, instance_stage,
+    json_output, key_help, kind_choice_label, kind_choices, list_columns, pick_output, ListView,
 };
 use skim::prelude::*;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::HashSet, io::IsTerminal, sync::Arc, time::Duration};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -49,41 +75,247 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
-    // Warn early if kubectl is missing — preview and all actions will fail without it.
-    if std::process::Command::new("kubectl")
-        .args(["version", "--client"])
-        .stdout(std::process::Stdio::null())
+    if let Some(theme) = args.theme {
+        set_theme(theme);
+    }
+
+    // Color legend — print and exit before any cluster I/O.
+    // Shown in the --theme palette, so presets can be compared side by side.
+    if args.help_colors {
+        print!("{}", color_legend(std::io::stdout().is_terminal()));
+        return Ok(());
+    }
+    if args.theme_preview {
+        print!("{}", theme_preview(std::io::stdout().is_terminal()));
+        return Ok(());
+    }
+
+    // Forget the saved ctrl-x context — no cluster I/O needed.
+    if args.forget_context {
+        if clear_last_context() {
+            println!("✓ saved context forgotten; kf will use kubeconfig's current-context");
+        } else {
+            println!("no saved context");
+        }
+        return Ok(());
+    }
+
+    // Warn early if kubectl is missing — preview and all actions will fail without it —
+    // or older than the argument builders expect. The version is cached for them.
+    match std::process::Command::new("kubectl")
+        .args(["version", "--client", "-o", "json"])
         .stderr(std::process::Stdio::null())
-        .status()
-        .is_err()
+        .output()
     {
-        eprintln!(
+        Err(_) => eprintln!(
             "[kuberift] warning: kubectl not found in PATH.\n\
              Preview and all actions (logs, exec, delete, …) will be unavailable.\n\
              Install kubectl: https://kubernetes.io/docs/tasks/tools/"
-        );
+        ),
+        Ok(out) => {
+            let version = parse_kubectl_version(&String::from_utf8_lossy(&out.stdout));
+            set_kubectl_version(version);
+            if let Some(version) = version.filter(|v| *v < MIN_KUBECTL_VERSION) {
+                eprintln!(
+                    "[kuberift] warning: kubectl {version} is older than {MIN_KUBECTL_VERSION}; \
+                     some actions may pass flags it doesn't understand."
+                );
+            }
+        }
     }
 
     // Load config file (~/.config/kuberift/config.toml) and merge with CLI args.
     let config = load_config();
+    let overrides = args.config_overrides(&config);
     args.merge_with_config(&config);
+    // The second stage of --two-stage is an ordinary --pick.
+    args.pick |= args.two_stage;
+
+    // Effective configuration — print and exit before any cluster I/O.
+    if args.print_config {
+        let context = if args.all_contexts {
+            "all contexts".to_string()
+        } else if args.multi_context() {
+            args.context.join(", ")
+        } else {
+            startup_context(&args)
+        };
+        let effective = EffectiveConfig {
+            config_file: config_path().filter(|path| path.exists()),
+            overrides,
+            ..EffectiveConfig::new(&args, &config, &context)
+        };
+        print!("{}", effective.render());
+        return Ok(());
+    }
+    if let Some(selector) = args.label.as_deref() {
+        validate_label_selector(selector)?;
+    }
+    if let Some(flag) = args.single_context_flag() {
+        anyhow::bail!("{flag} works on one cluster; give --context only once");
+    }
+    if args.kubeconfig.is_none() {
+        warn_context_collisions();
+    }
+    set_name_width(args.name_width);
+    set_age_format(args.age_format);
+    set_show_labels(args.show_labels);
+    if let Some(columns) = args.columns.clone() {
+        set_columns(columns);
+    }
+    set_compact_status(args.compact_status);
+    set_status_overrides(load_status_overrides());
+    set_list_width_source(terminal_list_width);
+    watch_terminal_resize();
+    if let Some(width) = args.describe_width {
+        set_describe_width(width);
+    }
 
     // Write the preview-toggle shell script and reset mode to 0 (describe)
     install_preview_toggle();
 
-    let kinds: Vec<ResourceKind> = args.resource_filter().unwrap_or_else(|| ALL_KINDS.to_vec());
+    if let Some(arg) = args.resource.as_deref() {
+        let (resolved, rejected) = parse_resource_kinds(arg);
+        if resolved.is_empty() {
+            eprintln!("[kuberift] error: no valid resource kind in '{arg}'; showing all kinds");
+        } else {
+            for token in rejected {
+                eprintln!("[kuberift] ignoring invalid resource kind '{token}'");
+            }
+        }
+    }
+    if let Some(arg) = args.exclude.as_deref() {
+        for token in parse_resource_kinds(arg).1 {
+            eprintln!("[kuberift] ignoring invalid --exclude kind '{token}'");
+        }
+    }
+    let kinds: Vec<ResourceKind> = apply_exclusions(
+        &args.resource_filter().unwrap_or_else(|| ALL_KINDS.to_vec()),
+        &args.excluded_kinds(),
+    );
+    if kinds.is_empty() {
+        anyhow::bail!("--exclude leaves no resource kind to show");
+    }
+    if let Some(notice) = ignored_namespace_notice(&kinds, args.namespace.as_deref()) {
+        eprintln!("[kuberift] {notice}");
+    }
 
-    let kind_label = if kinds.len() == 1 {
-        kinds[0].as_str().to_string()
-    } else {
-        "all".to_string()
-    };
+    let kind_label = kind_label(&kinds);
 
     let editor = config.general.editor.clone();
-    let sort_field = SortField::parse(&config.ui.default_sort);
+    let sort_field = args
+        .sort
+        .unwrap_or_else(|| SortField::parse(&config.ui.default_sort));
 
-    if args.all_contexts {
-        run_all_contexts(&args, &kinds, &kind_label, &editor, sort_field)
+    if args.count {
+        return run_count(&args, &kinds).await;
+    }
+
+    if args.summary {
+        let mut items = snapshot_items(&args, &kinds).await?;
+        if items.is_empty() {
+            eprintln!("[kuberift] no resources found");
+            return Ok(());
+        }
+        sort_items_with(&mut items, sort_field, args.reverse);
+        print!("{}", render_table(&items, color_enabled()));
+        return Ok(());
+    }
+
+    if let Some(path) = args.report.as_deref().filter(|_| args.report_all) {
+        let mut items = snapshot_items(&args, &kinds).await?;
+        if items.is_empty() {
+            eprintln!("[kuberift] no resources found");
+            return Ok(());
+        }
+        sort_items_with(&mut items, sort_field, args.reverse);
+        return action_report(&items.iter().collect::<Vec<_>>(), path);
+    }
+
+    if args.api_resources {
+        let client =
+            build_client_for_context(&startup_context(&args), args.kubeconfig.as_deref()).await?;
+        print!(
+            "{}",
+            render_api_resources(&cross_reference(&served_resources(&client).await?))
+        );
+        return Ok(());
+    }
+
+    if let Some(target) = args.top {
+        return run_top(
+            target,
+            args.namespace.as_deref(),
+            &startup_context(&args),
+            args.sort_by.unwrap_or_default(),
+            args.top_n,
+        );
+    }
+
+    if let Some(name) = args.rollout.as_deref() {
+        if args.resource.is_some() && kinds != [ResourceKind::Deployment] {
+            anyhow::bail!(
+                "--rollout follows a deployment; drop the resource argument or use `deploy`"
+            );
+        }
+        let ctx = startup_context(&args);
+        let namespace = single_namespace(&args, &ctx, "--rollout")?;
+        let client = build_client_for_context(&ctx, args.kubeconfig.as_deref()).await?;
+        return run_rollout(client, &namespace, name).await;
+    }
+
+    if let Some(field) = args.delete_selector.as_deref() {
+        // The CLI flag conflicts with --delete-selector, but read_only from
+        // config.toml is only merged in above.
+        if args.read_only {
+            anyhow::bail!("read-only mode: --delete-selector is disabled");
+        }
+        let ([kind], Some(_)) = (kinds.as_slice(), args.resource.as_ref()) else {
+            anyhow::bail!(
+                "--delete-selector needs a single resource kind, \
+                 e.g. `kf pods --delete-selector status.phase=Failed`"
+            );
+        };
+        let ctx = startup_context(&args);
+        let namespace = if kind.is_cluster_scoped() {
+            String::new()
+        } else {
+            single_namespace(&args, &ctx, "--delete-selector")?
+        };
+        return action_delete_matching(&SelectorDelete {
+            kind: kind.as_str().to_string(),
+            namespace,
+            context: ctx,
+            label: args.label.clone(),
+            field: Some(field.to_string()),
+            kubeconfig: args.kubeconfig.clone(),
+        });
+    }
+
+    if args.two_stage {
+        let items = snapshot_items(&args, &kinds).await?;
+        let choices = kind_choices(&items, &kinds);
+        if choices.is_empty() {
+            eprintln!("[kuberift] no resources found");
+            return Ok(());
+        }
+        let Some((kinds, kind_label)) = instance_stage(pick_kind_choice(&choices)?) else {
+            return Ok(());
+        };
+        return run_single_context(
+            &args,
+            &kinds,
+            &kind_label,
+            args.read_only,
+            args.no_crds,
+            &editor,
+            sort_field,
+        );
+    }
+
+    if args.multi_context() {
+        let contexts = watched_contexts(&args)?;
+        run_all_contexts(&args, &contexts, &kinds, &kind_label, &editor, sort_field)
     } else {
         run_single_context(
             &args,
@@ -97,8 +329,62 @@ async fn main() -> Result<()> {
     }
 }
 
+/// The one namespace a headless `flag` acts in: `-n`, else the namespace of
+/// context `ctx`. Several namespaces are refused.
+fn single_namespace(args: &Args, ctx: &str, flag: &str) -> Result<String> {
+    match args.namespace.as_deref().map(parse_namespaces).as_deref() {
+        None | Some([]) => Ok(context_namespace(ctx, args.kubeconfig.as_deref())
+            .unwrap_or_else(|| "default".to_string())),
+        Some([ns]) => Ok(ns.clone()),
+        Some(_) => anyhow::bail!("{flag} needs a single namespace"),
+    }
+}
+
+// ─── Terminal resize ──────────────────────────────────────────────────────────
+
+/// Columns a list row can use in the terminal as it is now.
+fn terminal_list_width() -> Option<usize> {
+    let (cols, _) = crossterm::terminal::size().ok()?;
+    Some(list_columns(usize::from(cols)))
+}
+
+/// Re-read the list width after every SIGWINCH, so rows are fitted to the
+/// resized terminal from the next redraw on.
+#[cfg(unix)]
+fn watch_terminal_resize() {
+    use tokio::signal::unix::{signal, SignalKind};
+    let Ok(mut resized) = signal(SignalKind::window_change()) else {
+        return;
+    };
+    tokio::spawn(async move {
+        while resized.recv().await.is_some() {
+            kuberift::items::invalidate_list_width();
+        }
+    });
+}
+
+/// No SIGWINCH off Unix: the width read at startup is kept.
+#[cfg(not(unix))]
+fn watch_terminal_resize() {}
+
 // ─── Single-cluster mode (with ctrl-x context switching) ─────────────────────
 
+/// Context to start in: `--context`, else the context saved by ctrl-x
+/// (skipped with `--use-kube-current`), else kubeconfig's current-context.
+fn startup_context(args: &Args) -> String {
+    args.context
+        .first()
+        .cloned()
+        .or_else(|| {
+            if args.use_kube_current {
+                None
+            } else {
+                load_last_context()
+            }
+        })
+        .unwrap_or_else(|| current_context_in(args.kubeconfig.as_deref()))
+}
+
 fn run_single_context(
     args: &Args,
     kinds: &[ResourceKind],
@@ -108,52 +394,89 @@ fn run_single_context(
     editor: &str,
     mut sort_field: SortField,
 ) -> Result<()> {
-    let mut active_ctx = args
-        .context
-        .clone()
-        .or_else(load_last_context)
-        .unwrap_or_else(current_context);
+    let mut active_ctx = startup_context(args);
     let kubeconfig = args.kubeconfig.as_deref();
-    let namespace = args.namespace.as_deref();
-    let label_selector = args.label.as_deref();
+    // Label filter; alt-t narrows it with a label of the selected resource.
+    let mut label_filter = args.label.clone();
+    // Namespace scope, toggled with alt-n between a single namespace and
+    // cluster-wide. Starts cluster-wide only when no namespace was requested.
+    let mut scoped_ns = args.namespace.clone();
+    let mut all_ns = scoped_ns.is_none();
+    // Exact or fuzzy matching, flipped with alt-m.
+    let mut exact = args.exact;
+    // Kinds hidden with alt-k; their watchers are not started.
+    let mut hidden: HashSet<ResourceKind> = HashSet::new();
 
     loop {
+        let namespace = if all_ns { None } else { scoped_ns.as_deref() };
+        let label_selector = label_filter.as_deref();
+        let kind_label_now = if hidden.is_empty() {
+            kind_label.to_string()
+        } else {
+            let mut names: Vec<&str> = hidden.iter().map(ResourceKind::as_str).collect();
+            names.sort_unstable();
+            format!("{kind_label} (hiding {})", names.join(","))
+        };
         let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
 
         let ctx_for_watcher = active_ctx.clone();
         let tx_k8s = tx.clone();
         let kinds_clone = kinds.to_vec();
+        let hidden_clone = hidden.clone();
         let kubeconfig_owned = kubeconfig.map(str::to_string);
-        let namespace_owned = namespace.map(str::to_string);
-        let label_owned = label_selector.map(str::to_string);
-        let sf = sort_field;
+        let opts = WatchOptions {
+            context: String::new(),
+            namespace: namespace.map(str::to_string),
+            label_selector: label_selector.map(str::to_string),
+            sort_field,
+            max_retries: args.max_retries,
+            snapshot: false,
+            page_size: args.page_size,
+            group_by_context: false,
+            changes_only: args.changes_only,
+            exclude_namespaces: args.excluded_namespaces(),
+            sort_reverse: args.reverse,
+            layout: args.layout,
+            stale_after: args.stale_window(),
+            hide_terminating: args.hide_terminating,
+            init_timeout: args.init_window(),
+            metrics: args.metrics,
+        };
+        let watch_file = args.watch_file.clone();
         tokio::spawn(async move {
+            if let Some(path) = watch_file {
+                let visible: Vec<ResourceKind> = kinds_clone
+                    .iter()
+                    .filter(|k| kind_visible(k, &hidden_clone))
+                    .cloned()
+                    .collect();
+                if let Err(e) = watch_dump_file(path, tx_k8s, visible, opts).await {
+                    eprintln!("\n[kuberift] {e}");
+                }
+                return;
+            }
             match build_client_for_context(&ctx_for_watcher, kubeconfig_owned.as_deref()).await {
                 Ok(client) => {
                     let crds = resolve_crds(&client, &kinds_clone, no_crds).await;
                     let builtin_kinds: Vec<ResourceKind> = kinds_clone
                         .iter()
                         .filter(|k| !matches!(k, ResourceKind::Custom(_)))
+                        .filter(|k| kind_visible(k, &hidden_clone))
                         .cloned()
                         .collect();
-                    if let Err(e) = watch_resources(
-                        client,
-                        tx_k8s,
-                        &builtin_kinds,
-                        &crds,
-                        "",
-                        namespace_owned.as_deref(),
-                        label_owned.as_deref(),
-                        sf,
-                    )
-                    .await
+                    if let Err(e) =
+                        watch_resources(client, tx_k8s, &builtin_kinds, &crds, opts).await
                     {
                         eprintln!("\n[kuberift] {e}");
                     }
                 }
                 Err(e) => {
                     eprintln!("[kuberift] No cluster ({e}). Showing demo data.");
-                    if tx_k8s.send(demo_items()).is_err() {
+                    let demo = demo_items()
+                        .into_iter()
+                        .map(|item| Arc::new(item) as Arc<dyn SkimItem>)
+                        .collect();
+                    if tx_k8s.send(demo).is_err() {
                         eprintln!("[kuberift] warning: failed to send demo items to skim");
                     }
                 }
@@ -162,12 +485,33 @@ fn run_single_context(
 
         drop(tx);
 
-        let options = build_skim_options(&active_ctx, kind_label, true, read_only, namespace)?;
+        let footer = status_footer(namespace, label_selector, sort_field, None);
+        let view = ListView {
+            ctx_label: &active_ctx,
+            kind_label: &kind_label_now,
+            show_ctx_switch: true,
+            read_only,
+            namespace,
+            footer: &footer,
+            mouse: args.mouse,
+            height: args.height,
+            layout: args.layout,
+            exact,
+        };
+        let options = if args.pick {
+            build_pick_options(&view)?
+        } else {
+            build_skim_options(&view)?
+        };
         let output = Skim::run_with(options, Some(rx)).map_err(|e| anyhow::anyhow!("{e}"))?;
 
         if output.is_abort {
             break;
         }
+        if args.pick {
+            print_picked(&output, args.output)?;
+            break;
+        }
 
         let key = output.final_key;
         if key.code == KeyCode::Char('x') && key.modifiers == KeyModifiers::CONTROL {
@@ -175,35 +519,110 @@ fn run_single_context(
                 active_ctx = new_ctx;
                 save_last_context(&active_ctx);
             }
-            install_preview_toggle();
+            install_preview_toggle_preserving();
             continue;
         }
         if key.code == KeyCode::Char('o') && key.modifiers == KeyModifiers::CONTROL {
             if let Some(new_sort) = pick_sort(sort_field)? {
                 sort_field = new_sort;
             }
-            install_preview_toggle();
+            install_preview_toggle_preserving();
+            continue;
+        }
+        if key.code == KeyCode::Char('k') && key.modifiers == KeyModifiers::ALT {
+            let toggled = pick_kinds(kinds, &hidden)?;
+            let mut next = hidden.clone();
+            toggle_hidden(&mut next, &toggled);
+            if kinds.iter().any(|k| kind_visible(k, &next)) {
+                hidden = next;
+            } else {
+                eprintln!("[kuberift] at least one kind must stay visible");
+            }
+            install_preview_toggle_preserving();
+            continue;
+        }
+        if key.code == KeyCode::Char('t') && key.modifiers == KeyModifiers::ALT {
+            let labels = selected_items(&output)
+                .first()
+                .map(|item| item.labels())
+                .unwrap_or_default();
+            if labels.is_empty() {
+                eprintln!("[kuberift] the selected resource has no labels");
+            } else if let Some(requirement) = pick_label(&labels)? {
+                label_filter = Some(add_label_requirement(label_selector, &requirement));
+            }
+            install_preview_toggle_preserving();
+            continue;
+        }
+        if key.code == KeyCode::Char('m') && key.modifiers == KeyModifiers::ALT {
+            exact = !exact;
+            install_preview_toggle_preserving();
+            continue;
+        }
+        if key.code == KeyCode::Char('n') && key.modifiers == KeyModifiers::ALT {
+            if all_ns && scoped_ns.is_none() {
+                // Started cluster-wide: fall back to the context's namespace.
+                scoped_ns = Some(
+                    context_namespace(&active_ctx, kubeconfig)
+                        .unwrap_or_else(|| "default".to_string()),
+                );
+            }
+            all_ns = !all_ns;
+            install_preview_toggle_preserving();
             continue;
         }
 
-        dispatch(&output, read_only, editor)?;
-        install_preview_toggle();
+        if key.code == KeyCode::F(1) {
+            print!("{}", key_help(true));
+        } else {
+            dispatch(
+                &output,
+                args,
+                editor,
+                label_selector,
+                &active_ctx,
+                namespace,
+            )?;
+        }
+        let tty = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+        if should_pause(key, tty, args.no_pause) {
+            wait_for_key()?;
+        }
+        install_preview_toggle_preserving();
     }
 
     let _ = std::fs::remove_dir_all(runtime_dir());
     Ok(())
 }
 
-// ─── Multi-cluster mode (--all-contexts) ─────────────────────────────────────
+// ─── Multi-cluster mode (--all-contexts / several --context) ─────────────────
+
+/// Contexts watched together: all of kubeconfig's with `--all-contexts`,
+/// else the `--context`s it defines; unknown names are warned about and
+/// skipped.
+fn watched_contexts(args: &Args) -> Result<Vec<String>> {
+    let available = list_contexts();
+    if args.all_contexts {
+        return Ok(available);
+    }
+    let (known, unknown) = select_contexts(&args.context, &available);
+    for name in &unknown {
+        eprintln!("[kuberift] warning: context {name:?} is not in kubeconfig, skipping it");
+    }
+    if known.is_empty() {
+        anyhow::bail!("none of the --context names are in kubeconfig");
+    }
+    Ok(known)
+}
 
 fn run_all_contexts(
     args: &Args,
+    contexts: &[String],
     kinds: &[ResourceKind],
     kind_label: &str,
     editor: &str,
     sort_field: SortField,
 ) -> Result<()> {
-    let contexts = list_contexts();
     if contexts.is_empty() {
         eprintln!("[kuberift] No contexts found in kubeconfig.");
         return Ok(());
@@ -215,14 +634,51 @@ fn run_all_contexts(
     let label_selector = args.label.as_deref();
 
     let no_crds = args.no_crds;
-    for ctx_name in &contexts {
-        let tx_clone = tx.clone();
+    let base_opts = WatchOptions {
+        context: String::new(),
+        namespace: namespace.map(str::to_string),
+        label_selector: label_selector.map(str::to_string),
+        sort_field,
+        max_retries: args.max_retries,
+        snapshot: false,
+        page_size: args.page_size,
+        group_by_context: args.group_by_context,
+        changes_only: args.changes_only,
+        exclude_namespaces: args.excluded_namespaces(),
+        sort_reverse: args.reverse,
+        layout: args.layout,
+        stale_after: args.stale_window(),
+        hide_terminating: args.hide_terminating,
+        init_timeout: args.init_window(),
+        metrics: args.metrics,
+    };
+
+    // One initial batch for every context, sent after --context-timeout at the
+    // latest; slower clusters join the list as they finish loading.
+    let batch = Arc::new(InitBatch::new(
+        tx.clone(),
+        contexts.len(),
+        &base_opts,
+        kinds,
+    ));
+    {
+        let batch = batch.clone();
+        let timeout = Some(Duration::from_secs(args.context_timeout));
+        tokio::spawn(async move {
+            batch.run(timeout).await;
+            batch.refresh_usage().await;
+        });
+    }
+
+    for ctx_name in contexts {
+        let batch = batch.clone();
         let ctx_clone = ctx_name.clone();
         let kinds_clone = kinds.to_vec();
         let kubeconfig_owned = kubeconfig.map(str::to_string);
-        let namespace_owned = namespace.map(str::to_string);
-        let label_owned = label_selector.map(str::to_string);
-        let sf = sort_field;
+        let opts = WatchOptions {
+            context: ctx_name.clone(),
+            ..base_opts.clone()
+        };
 
         tokio::spawn(async move {
             match build_client_for_context(&ctx_clone, kubeconfig_owned.as_deref()).await {
@@ -233,23 +689,15 @@ fn run_all_contexts(
                         .filter(|k| !matches!(k, ResourceKind::Custom(_)))
                         .cloned()
                         .collect();
-                    if let Err(e) = watch_resources(
-                        client,
-                        tx_clone,
-                        &builtin_kinds,
-                        &crds,
-                        &ctx_clone,
-                        namespace_owned.as_deref(),
-                        label_owned.as_deref(),
-                        sf,
-                    )
-                    .await
+                    if let Err(e) =
+                        watch_resources_into(client, batch, &builtin_kinds, &crds, opts).await
                     {
                         eprintln!("[kuberift:{ctx_clone}] {e}");
                     }
                 }
                 Err(e) => {
                     eprintln!("[kuberift] Cannot connect to '{ctx_clone}': {e}");
+                    batch.session_done();
                 }
             }
         });
@@ -257,15 +705,146 @@ fn run_all_contexts(
 
     drop(tx);
 
-    let ctx_label = "all-contexts";
-    let options = build_skim_options(ctx_label, kind_label, false, args.read_only, namespace)?;
+    let ctx_label = if args.all_contexts {
+        "all-contexts".to_string()
+    } else {
+        contexts.join(",")
+    };
+    let footer = status_footer(namespace, label_selector, sort_field, None);
+    let view = ListView {
+        ctx_label: &ctx_label,
+        kind_label,
+        show_ctx_switch: false,
+        read_only: args.read_only,
+        namespace,
+        footer: &footer,
+        mouse: args.mouse,
+        height: args.height,
+        layout: args.layout,
+        exact: args.exact,
+    };
+    let options = if args.pick {
+        build_pick_options(&view)?
+    } else {
+        build_skim_options(&view)?
+    };
     let output = Skim::run_with(options, Some(rx)).map_err(|e| anyhow::anyhow!("{e}"))?;
 
     if output.is_abort {
         return Ok(());
     }
+    if args.pick {
+        print_picked(&output, args.output)?;
+        return Ok(());
+    }
+
+    if output.final_key.code == KeyCode::F(1) {
+        print!("{}", key_help(false));
+        return Ok(());
+    }
+    dispatch(&output, args, editor, label_selector, "", namespace)
+}
+
+// ─── Count mode (--count) ────────────────────────────────────────────────────
 
-    dispatch(&output, args.read_only, editor)
+/// List every requested kind once (in every context with `--all-contexts`),
+/// print how many resources match the filters, and exit.
+async fn run_count(args: &Args, kinds: &[ResourceKind]) -> Result<()> {
+    let items = snapshot_items(args, kinds).await?;
+    let count = count_matching(&items, args.min_severity);
+    println!("{count}");
+    if args.fail_if_nonzero && count > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// List every requested kind once, in the startup context (every context
+/// with `--all-contexts`), without opening skim.
+async fn snapshot_items(args: &Args, kinds: &[ResourceKind]) -> Result<Vec<K8sItem>> {
+    let contexts = if args.multi_context() {
+        watched_contexts(args)?
+    } else {
+        vec![startup_context(args)]
+    };
+
+    let mut items: Vec<K8sItem> = Vec::new();
+    for ctx in &contexts {
+        let client = build_client_for_context(ctx, args.kubeconfig.as_deref()).await?;
+        let crds = resolve_crds(&client, kinds, args.no_crds).await;
+        let builtin_kinds: Vec<ResourceKind> = kinds
+            .iter()
+            .filter(|k| !matches!(k, ResourceKind::Custom(_)))
+            .cloned()
+            .collect();
+        let opts = WatchOptions {
+            context: if args.multi_context() {
+                ctx.clone()
+            } else {
+                String::new()
+            },
+            namespace: args.namespace.clone(),
+            label_selector: args.label.clone(),
+            sort_field: SortField::default(),
+            max_retries: args.max_retries,
+            snapshot: true,
+            page_size: args.page_size,
+            group_by_context: false,
+            changes_only: false,
+            exclude_namespaces: args.excluded_namespaces(),
+            sort_reverse: false,
+            layout: args.layout,
+            stale_after: None,
+            hide_terminating: args.hide_terminating,
+            init_timeout: args.init_window(),
+            metrics: false,
+        };
+        let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+        watch_resources(client, tx, &builtin_kinds, &crds, opts).await?;
+        items.extend(rx.try_iter().flatten().filter_map(|item| {
+            let inner: &dyn SkimItem = &*item;
+            inner.as_any().downcast_ref::<K8sItem>().cloned()
+        }));
+    }
+    Ok(items)
+}
+
+// ─── Two-stage pick (--two-stage) ────────────────────────────────────────────
+
+/// Stage one of `--two-stage`: pick one of the kinds that have resources.
+fn pick_kind_choice(choices: &[(ResourceKind, usize)]) -> Result<Option<ResourceKind>> {
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    for (kind, count) in choices {
+        let label = kind_choice_label(kind, *count);
+        if tx
+            .send(vec![
+                Arc::new(KindItem(kind.clone(), label)) as Arc<dyn SkimItem>
+            ])
+            .is_err()
+        {
+            break;
+        }
+    }
+    drop(tx);
+
+    let options = SkimOptionsBuilder::default()
+        .header("Select a resource kind  (Esc to cancel)")
+        .prompt("kind ❯ ")
+        .height("40%")
+        .build()?;
+
+    let output = Skim::run_with(options, Some(rx)).map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    if output.is_abort {
+        return Ok(None);
+    }
+    Ok(output.selected_items.first().and_then(|matched| {
+        let inner: &dyn SkimItem = &*matched.item;
+        inner
+            .as_any()
+            .downcast_ref::<KindItem>()
+            .map(|k| k.0.clone())
+    }))
 }
 
 // ─── Context picker (ctrl-x) ──────────────────────────────────────────────────
@@ -304,6 +883,67 @@ fn pick_context() -> Result<Option<String>> {
     Ok(Some(output.selected_items[0].output().to_string()))
 }
 
+// ─── Kind visibility picker (alt-k) ──────────────────────────────────────────
+
+/// Multi-select of the watched kinds; returns the kinds whose visibility
+/// should flip (empty on Esc).
+fn pick_kinds(kinds: &[ResourceKind], hidden: &HashSet<ResourceKind>) -> Result<Vec<ResourceKind>> {
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    for kind in kinds {
+        let state = if kind_visible(kind, hidden) {
+            "shown"
+        } else {
+            "hidden"
+        };
+        let label = format!("{:<8} {state}", kind.as_str());
+        if tx
+            .send(vec![
+                Arc::new(KindItem(kind.clone(), label)) as Arc<dyn SkimItem>
+            ])
+            .is_err()
+        {
+            break;
+        }
+    }
+    drop(tx);
+
+    let options = SkimOptionsBuilder::default()
+        .header("Toggle kind visibility  (<tab> select, Enter apply, Esc cancel)")
+        .prompt("kinds ❯ ")
+        .multi(true)
+        .height("40%")
+        .build()?;
+
+    let output = Skim::run_with(options, Some(rx)).map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    if output.is_abort {
+        return Ok(Vec::new());
+    }
+
+    Ok(output
+        .selected_items
+        .iter()
+        .filter_map(|matched| {
+            let inner: &dyn SkimItem = &*matched.item;
+            inner
+                .as_any()
+                .downcast_ref::<KindItem>()
+                .map(|k| k.0.clone())
+        })
+        .collect())
+}
+
+struct KindItem(ResourceKind, String);
+
+impl SkimItem for KindItem {
+    fn text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.1)
+    }
+    fn output(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.1)
+    }
+}
+
 // ─── Sort picker (ctrl-o) ────────────────────────────────────────────────────
 
 fn pick_sort(current: SortField) -> Result<Option<SortField>> {
@@ -341,6 +981,35 @@ fn pick_sort(current: SortField) -> Result<Option<SortField>> {
     }
 }
 
+/// Pick one of `labels` as a `key=value` filter requirement.
+fn pick_label(labels: &[(String, String)]) -> Result<Option<String>> {
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    for (k, v) in labels {
+        if tx
+            .send(vec![
+                Arc::new(ContextItem(format!("{k}={v}"))) as Arc<dyn SkimItem>
+            ])
+            .is_err()
+        {
+            break;
+        }
+    }
+    drop(tx);
+
+    let options = SkimOptionsBuilder::default()
+        .header("Filter by label  (Esc to cancel)")
+        .prompt("label ❯ ")
+        .height("30%")
+        .build()?;
+
+    let output = Skim::run_with(options, Some(rx)).map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    if output.is_abort || output.selected_items.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(output.selected_items[0].output().to_string()))
+}
+
 struct SortItem(SortField, String);
 
 impl SkimItem for SortItem {
@@ -352,7 +1021,7 @@ impl SkimItem for SortItem {
     }
 }
 
-/// Minimal `SkimItem` wrapper for a kubeconfig context name string.
+/// Minimal `SkimItem` wrapper for a plain string row (a context name or a label).
 struct ContextItem(String);
 
 impl SkimItem for ContextItem {
@@ -364,82 +1033,78 @@ impl SkimItem for ContextItem {
     }
 }
 
-// ─── Shared skim options builder ──────────────────────────────────────────────
-
-// RST-006: return Result instead of panicking with .expect()
-fn build_skim_options(
-    ctx_label: &str,
-    kind_label: &str,
-    show_ctx_switch: bool,
-    read_only: bool,
-    namespace: Option<&str>,
-) -> Result<SkimOptions> {
-    let ctx_hint = if show_ctx_switch {
-        "  ctrl-x switch-ctx"
-    } else {
-        ""
-    };
-    let ro_hint = if read_only { "  [READ-ONLY]" } else { "" };
-    let ns_hint = namespace.map(|n| format!("  ns:{n}")).unwrap_or_default();
+// ─── Action dispatch ──────────────────────────────────────────────────────────
 
-    Ok(SkimOptionsBuilder::default()
-        .multi(true)
-        .preview(String::new())
-        .preview_window("right:50%")
-        .height("60%")
-        .header(format!(
-            "KubeRift  ctx:{ctx_label}{ns_hint}  res:{kind_label}{ro_hint}\n\
-             <tab> select  <enter> describe  ctrl-l logs  ctrl-e exec  \
-             ctrl-d delete  ctrl-f forward  ctrl-r restart  ctrl-s scale  \
-             ctrl-w edit  ctrl-y yaml  ctrl-o sort  ctrl-p cycle-preview{ctx_hint}",
-        ))
-        .prompt("❯ ")
-        .bind({
-            let mut binds = vec![
-                "ctrl-l:accept".to_string(),
-                "ctrl-e:accept".to_string(),
-                "ctrl-d:accept".to_string(),
-                "ctrl-f:accept".to_string(),
-                "ctrl-r:accept".to_string(),
-                "ctrl-s:accept".to_string(),
-                "ctrl-w:accept".to_string(),
-                "ctrl-y:accept".to_string(),
-                "ctrl-o:accept".to_string(),
-                format!(
-                    "ctrl-p:execute({})+refresh-preview",
-                    preview_toggle_path().display()
-                ),
-            ];
-            if show_ctx_switch {
-                binds.push("ctrl-x:accept".to_string());
-            }
-            binds
-        })
-        .build()?)
+/// The `K8sItem`s behind skim's selection (context and sort picker rows excluded).
+fn selected_items(output: &SkimOutput) -> Vec<&K8sItem> {
+    dedup_selection(
+        output
+            .selected_items
+            .iter()
+            .filter_map(|matched| {
+                let inner: &dyn SkimItem = &*matched.item;
+                inner.as_any().downcast_ref::<K8sItem>()
+            })
+            .collect(),
+    )
 }
 
-// ─── Action dispatch ──────────────────────────────────────────────────────────
+/// Print a `--pick` selection in the `--output` format.
+fn print_picked(output: &SkimOutput, format: OutputFormat) -> Result<()> {
+    let items = selected_items(output);
+    match format {
+        OutputFormat::Text => print!("{}", pick_output(&items)),
+        OutputFormat::Json => print!("{}", json_output(&items)?),
+        OutputFormat::GroupedJson => print!("{}", grouped_json_output(&items)?),
+    }
+    Ok(())
+}
 
 // RST-005: removed `async` — all action functions are synchronous
-fn dispatch(output: &SkimOutput, read_only: bool, editor: &str) -> Result<()> {
-    let items: Vec<&K8sItem> = output
-        .selected_items
-        .iter()
-        .filter_map(|matched| {
-            let inner: &dyn SkimItem = &*matched.item;
-            inner.as_any().downcast_ref::<K8sItem>()
-        })
-        .collect();
+/// `label_selector` is the view's active label filter, which alt-a deletes
+/// everything matching. `context` and `namespace` are the view's, which
+/// alt-i applies a manifest into; a selected item's own context wins.
+fn dispatch(
+    output: &SkimOutput,
+    args: &Args,
+    editor: &str,
+    label_selector: Option<&str>,
+    context: &str,
+    namespace: Option<&str>,
+) -> Result<()> {
+    let items = selected_items(output);
+
+    let read_only = args.read_only;
+    let key = output.final_key;
+    let ctrl = |c: char| key.code == KeyCode::Char(c) && key.modifiers == KeyModifiers::CONTROL;
+    let alt = |c: char| key.code == KeyCode::Char(c) && key.modifiers == KeyModifiers::ALT;
+
+    // File-based, so it runs whatever is selected.
+    if alt('i') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: apply is disabled");
+            return Ok(());
+        }
+        let context = items
+            .first()
+            .map(|item| item.context())
+            .filter(|ctx| !ctx.is_empty())
+            .unwrap_or(context);
+        // kubectl apply takes a single namespace.
+        let namespace = namespace.filter(|ns| !ns.contains(','));
+        return action_apply_prompt(context, namespace);
+    }
 
     if items.is_empty() {
         return Ok(());
     }
 
-    let key = output.final_key;
-    let ctrl = |c: char| key.code == KeyCode::Char(c) && key.modifiers == KeyModifiers::CONTROL;
-
     if ctrl('l') {
         action_logs(&items)?;
+    } else if alt('l') {
+        if let Some(item) = items.first() {
+            action_logs_smart(item)?;
+        }
     } else if ctrl('e') {
         if read_only {
             eprintln!("[kuberift] read-only mode: exec is disabled");
@@ -450,7 +1115,27 @@ fn dispatch(output: &SkimOutput, read_only: bool, editor: &str) -> Result<()> {
         if read_only {
             eprintln!("[kuberift] read-only mode: delete is disabled");
         } else {
-            action_delete(&items)?;
+            action_delete(&items, args.bulk_delete_threshold)?;
+        }
+    } else if alt('x') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: force delete is disabled");
+        } else {
+            action_force_delete(&items)?;
+        }
+    } else if alt('z') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: restart is disabled");
+        } else if let Some(item) = items.first() {
+            action_restart_container(item)?;
+        }
+    } else if alt('a') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: delete is disabled");
+        } else if let (Some(item), Some(label)) = (items.first(), label_selector) {
+            action_delete_matching(&SelectorDelete::like(item, label))?;
+        } else {
+            eprintln!("[kuberift] delete all matching needs a label filter (-l or alt-t)");
         }
     } else if ctrl('f') {
         if read_only {
@@ -464,6 +1149,28 @@ fn dispatch(output: &SkimOutput, read_only: bool, editor: &str) -> Result<()> {
         } else {
             action_rollout_restart(&items)?;
         }
+    } else if alt('p') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: rollout-pause is disabled");
+        } else {
+            action_rollout_pause(&items)?;
+        }
+    } else if alt('r') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: rollout-resume is disabled");
+        } else {
+            action_rollout_resume(&items)?;
+        }
+    } else if alt('o') || alt('u') || alt('e') {
+        if read_only {
+            eprintln!("[kuberift] read-only mode: cordon/uncordon/drain is disabled");
+        } else if alt('o') {
+            action_cordon(&items)?;
+        } else if alt('u') {
+            action_uncordon(&items)?;
+        } else {
+            action_drain(&items)?;
+        }
     } else if ctrl('s') {
         if read_only {
             eprintln!("[kuberift] read-only mode: scale is disabled");
@@ -478,6 +1185,26 @@ fn dispatch(output: &SkimOutput, read_only: bool, editor: &str) -> Result<()> {
         }
     } else if ctrl('y') {
         action_yaml(&items)?;
+    } else if alt('y') {
+        if let Some(item) = items.first() {
+            action_copy_yaml(item)?;
+        }
+    } else if alt('w') {
+        if let Some(item) = items.first() {
+            action_raw_watch(item)?;
+        }
+    } else if alt('c') {
+        action_show_commands(&items);
+    } else if alt('v') {
+        action_drift(&items)?;
+    } else if let Some(expr) = args.jsonpath.as_deref() {
+        action_jsonpath(&items, expr)?;
+    } else if let Some(path) = args.report.as_deref() {
+        action_report(&items, path)?;
+    } else if args.output == OutputFormat::Json {
+        print!("{}", json_output(&items)?);
+    } else if args.output == OutputFormat::GroupedJson {
+        print!("{}", grouped_json_output(&items)?);
     } else {
         action_describe(&items)?;
     }
@@ -489,7 +1216,7 @@ fn dispatch(output: &SkimOutput, read_only: bool, editor: &str) -> Result<()> {
 
 /// Discover CRDs from the cluster, filtered by the user's resource argument.
 /// - `no_crds` flag → return empty
-/// - Filter contains `Custom(s)` → discover and return only matching CRDs
+/// - Filter contains `Custom(s)` → discover and return only the CRDs matching one of them
 /// - No custom filter (all built-in or no filter) → discover and return all CRDs
 async fn resolve_crds(
     client: &kube::Client,
@@ -500,29 +1227,34 @@ async fn resolve_crds(
         return Vec::new();
     }
 
-    // Check if user asked for a specific CRD by name.
-    let crd_filter: Option<&str> = kinds.iter().find_map(|k| match k {
-        ResourceKind::Custom(s) => Some(s.as_str()),
-        _ => None,
-    });
+    // Check if user asked for specific CRDs by name.
+    let crd_filters: Vec<&str> = kinds
+        .iter()
+        .filter_map(|k| match k {
+            ResourceKind::Custom(s) => Some(s.as_str()),
+            _ => None,
+        })
+        .collect();
 
-    // If the user asked for a specific built-in resource (e.g. `kf pods`),
+    // If the user asked for specific built-in resources (e.g. `kf pods`),
     // don't waste time discovering CRDs.
-    if crd_filter.is_none() && kinds.len() < ALL_KINDS.len() && !kinds.is_empty() {
+    if crd_filters.is_empty() && kinds.len() < ALL_KINDS.len() && !kinds.is_empty() {
         return Vec::new();
     }
 
     match discover_crds(client).await {
         Ok(all) => {
-            if let Some(filter) = crd_filter {
+            if crd_filters.is_empty() {
+                all
+            } else {
                 all.into_iter()
                     .filter(|crd| {
-                        crd.plural.eq_ignore_ascii_case(filter)
-                            || crd.kind_name.eq_ignore_ascii_case(filter)
+                        crd_filters.iter().any(|filter| {
+                            crd.plural.eq_ignore_ascii_case(filter)
+                                || crd.kind_name.eq_ignore_ascii_case(filter)
+                        })
                     })
                     .collect()
-            } else {
-                all
             }
         }
         Err(e) => {
@@ -531,98 +1263,3 @@ async fn resolve_crds(
         }
     }
 }
-
-// ─── Demo data (no cluster) ───────────────────────────────────────────────────
-
-fn demo_items() -> Vec<Arc<dyn SkimItem>> {
-    vec![
-        Arc::new(K8sItem::new(
-            ResourceKind::Pod,
-            "production",
-            "api-server-7d9f8b6c5-xk2lp",
-            "CrashLoopBackOff",
-            "1h",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Pod,
-            "staging",
-            "frontend-5c7d8e9f0-ab1cd",
-            "Pending",
-            "5m",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Pod,
-            "production",
-            "worker-6f8b9c4d7-mn3qr",
-            "Running",
-            "2d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Deployment,
-            "production",
-            "api-server",
-            "2/3",
-            "2d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Deployment,
-            "staging",
-            "frontend",
-            "0/1",
-            "5m",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Service,
-            "production",
-            "api-service",
-            "ClusterIP",
-            "2d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::ConfigMap,
-            "production",
-            "app-config",
-            "ConfigMap",
-            "2d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Secret,
-            "production",
-            "api-tls",
-            "kubernetes.io/tls",
-            "30d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Node,
-            "",
-            "kind-control-plane",
-            "Ready",
-            "7d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Namespace,
-            "",
-            "production",
-            "Active",
-            "30d",
-            "",
-        )),
-        Arc::new(K8sItem::new(
-            ResourceKind::Namespace,
-            "",
-            "staging",
-            "Active",
-            "10d",
-            "",
-        )),
-    ]
-}
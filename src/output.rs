@@ -0,0 +1,100 @@
+//! `--summary` headless mode — the resources as an aligned table that reads
+//! like `kubectl get`, with the status column colored by health.
+
+use std::fmt::Write;
+use std::io::IsTerminal;
+
+use crate::items::{ansi_fg, K8sItem, StatusHealth};
+
+/// Column titles, in order. `CONTEXT` leads them only when some item
+/// belongs to a named context (`--all-contexts`).
+pub const HEADERS: [&str; 5] = ["NAMESPACE", "KIND", "NAME", "STATUS", "AGE"];
+
+/// Index of the status column within [`HEADERS`].
+const STATUS: usize = 3;
+
+/// Whether to color the table: stdout is a terminal and `NO_COLOR` is unset
+/// or empty (<https://no-color.org>).
+pub fn color_enabled() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
+
+/// The cells of `item`'s row, in [`HEADERS`] order.
+pub fn table_cells(item: &K8sItem) -> [String; 5] {
+    [
+        item.namespace().to_string(),
+        item.kind().as_str().to_string(),
+        item.name().to_string(),
+        item.status(),
+        item.shown_age(),
+    ]
+}
+
+/// Width of each column: its widest cell or title, in characters.
+pub fn column_widths(headers: &[&str], rows: &[Vec<String>]) -> Vec<usize> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    widths
+}
+
+/// One line of the table: cells padded to `widths` and separated by three
+/// spaces; the last column isn't padded. With `ansi`, the status cell (at
+/// `status_col`) is colored by its [`StatusHealth`].
+pub fn format_row(
+    cells: &[String],
+    widths: &[usize],
+    status_col: Option<usize>,
+    ansi: bool,
+) -> String {
+    let last = cells.len().saturating_sub(1);
+    let mut line = String::new();
+    for (i, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        let pad = if i == last {
+            0
+        } else {
+            width.saturating_sub(cell.chars().count())
+        };
+        if ansi && status_col == Some(i) {
+            let color = StatusHealth::classify(cell).color();
+            let _ = write!(line, "\x1b[{}m{cell}\x1b[0m", ansi_fg(color));
+        } else {
+            line.push_str(cell);
+        }
+        if i != last {
+            line.push_str(&" ".repeat(pad + 3));
+        }
+    }
+    line.push('\n');
+    line
+}
+
+/// `items` as an aligned table under a header row, one resource per line.
+/// With `ansi`, statuses are colored by health.
+pub fn render_table(items: &[K8sItem], ansi: bool) -> String {
+    let with_context = items.iter().any(|item| !item.context().is_empty());
+    let mut headers: Vec<&str> = HEADERS.to_vec();
+    let mut rows: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| table_cells(item).to_vec())
+        .collect();
+    let mut status_col = STATUS;
+    if with_context {
+        headers.insert(0, "CONTEXT");
+        for (row, item) in rows.iter_mut().zip(items) {
+            row.insert(0, item.context().to_string());
+        }
+        status_col += 1;
+    }
+
+    let widths = column_widths(&headers, &rows);
+    let titles: Vec<String> = headers.iter().map(|h| (*h).to_string()).collect();
+    let mut out = format_row(&titles, &widths, None, false);
+    for row in &rows {
+        out.push_str(&format_row(row, &widths, Some(status_col), ansi));
+    }
+    out
+}
@@ -0,0 +1,123 @@
+//! `--rollout` headless mode — follow one deployment's rollout: its replica
+//! progression and its pods' statuses, until the rollout completes or fails.
+
+use anyhow::Result;
+use k8s_openapi::api::{apps::v1::Deployment, core::v1::Pod};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::{Api, ListParams};
+use kube::{Client, ResourceExt};
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::k8s::resources::{deploy_status, pod_status};
+
+/// How often the deployment and its pods are re-read.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Where a deployment's rollout stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RolloutState {
+    InProgress,
+    Complete,
+    /// The `Progressing` condition's reason, e.g. `ProgressDeadlineExceeded`.
+    Failed(String),
+}
+
+/// Judge a rollout the way `kubectl rollout status` does: nothing counts
+/// until the controller has observed the latest spec; then it has failed
+/// once the progress deadline is exceeded, and is complete once every
+/// replica is updated and available and no old replicas remain.
+pub fn rollout_state(d: &Deployment) -> RolloutState {
+    let Some(status) = &d.status else {
+        return RolloutState::InProgress;
+    };
+    let generation = d.metadata.generation.unwrap_or(0);
+    if status.observed_generation.unwrap_or(0) < generation {
+        return RolloutState::InProgress;
+    }
+    let deadline_exceeded = status.conditions.iter().flatten().find(|c| {
+        c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+    if let Some(condition) = deadline_exceeded {
+        return RolloutState::Failed(condition.reason.clone().unwrap_or_default());
+    }
+    let desired = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let total = status.replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    if updated < desired || total > updated || available < updated {
+        RolloutState::InProgress
+    } else {
+        RolloutState::Complete
+    }
+}
+
+/// `matchLabels` of `selector` as a label selector string (`app=web,tier=fe`);
+/// `None` when there are none to list pods by.
+pub fn match_labels_selector(selector: &LabelSelector) -> Option<String> {
+    let labels = selector.match_labels.as_ref().filter(|l| !l.is_empty())?;
+    Some(
+        labels
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// One snapshot of the rollout: the deployment's status line, then one line
+/// per pod (name and status), sorted by name.
+pub fn render_rollout(name: &str, status: &str, pods: &[(String, String)]) -> String {
+    let mut pods = pods.to_vec();
+    pods.sort();
+    let width = pods.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+    let mut out = format!("deploy/{name}  {status}\n");
+    for (pod, pod_status) in &pods {
+        let _ = writeln!(out, "  {pod:<width$}  {pod_status}");
+    }
+    out
+}
+
+/// Follow the rollout of deployment `name` in `namespace`, printing a fresh
+/// snapshot whenever it changes. Returns once the rollout is complete; fails
+/// when it has failed or the deployment can't be read.
+pub async fn run_rollout(client: Client, namespace: &str, name: &str) -> Result<()> {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let pods: Api<Pod> = Api::namespaced(client, namespace);
+    let mut last = String::new();
+    loop {
+        let d = deployments
+            .get(name)
+            .await
+            .map_err(|e| anyhow::anyhow!("deployment {namespace}/{name}: {e}"))?;
+        let selector = d
+            .spec
+            .as_ref()
+            .and_then(|s| match_labels_selector(&s.selector));
+        let pod_rows: Vec<(String, String)> = match selector {
+            Some(sel) => pods
+                .list(&ListParams::default().labels(&sel))
+                .await?
+                .items
+                .iter()
+                .map(|p| (p.name_any(), pod_status(p)))
+                .collect(),
+            None => Vec::new(),
+        };
+        let snapshot = render_rollout(name, &deploy_status(&d), &pod_rows);
+        if snapshot != last {
+            println!("{snapshot}");
+            last = snapshot;
+        }
+        match rollout_state(&d) {
+            RolloutState::Complete => {
+                println!("✓ deploy/{name} rolled out");
+                return Ok(());
+            }
+            RolloutState::Failed(reason) => {
+                anyhow::bail!("deploy/{name} rollout failed: {reason}")
+            }
+            RolloutState::InProgress => tokio::time::sleep(POLL_INTERVAL).await,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! `--top` headless mode — a quick "what's hot" report built on `kubectl top`.
+
+use anyhow::Result;
+use std::fmt::Write;
+use std::process::Command;
+
+use crate::k8s::discovery::parse_quantity;
+use crate::k8s::resources::parse_namespaces;
+
+/// Rows printed when `--top-n` is not given.
+pub const DEFAULT_TOP_N: usize = 10;
+
+/// What `--top` reports on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopTarget {
+    Pods,
+    Nodes,
+}
+
+impl TopTarget {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pods => "pods",
+            Self::Nodes => "nodes",
+        }
+    }
+}
+
+/// Column `--top` sorts by (descending).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TopSort {
+    #[default]
+    Cpu,
+    Memory,
+}
+
+/// One row of `kubectl top` output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopRow {
+    /// Empty for nodes and for pods listed in a single namespace.
+    pub namespace: String,
+    pub name: String,
+    /// CPU as printed by kubectl (e.g. `250m`).
+    pub cpu: String,
+    /// Memory as printed by kubectl (e.g. `512Mi`).
+    pub memory: String,
+    /// `cpu` in cores, for sorting.
+    pub cpu_cores: f64,
+    /// `memory` in bytes, for sorting.
+    pub memory_bytes: f64,
+}
+
+/// `kubectl top` arguments. Pods are listed across all namespaces unless
+/// `namespace` is given; nodes ignore it.
+pub fn top_args(target: TopTarget, namespace: Option<&str>, context: &str) -> Vec<String> {
+    let mut args = vec!["top".to_string(), target.as_str().to_string()];
+    if target == TopTarget::Pods {
+        match namespace {
+            Some(ns) => args.extend(["-n".to_string(), ns.to_string()]),
+            None => args.push("-A".to_string()),
+        }
+    }
+    if !context.is_empty() {
+        args.extend(["--context".to_string(), context.to_string()]);
+    }
+    args
+}
+
+/// Parse `kubectl top pods|nodes` output. Columns are located by header name
+/// (`NAMESPACE`, `NAME`, `CPU(cores)`, `MEMORY(bytes)`), so the pod, node
+/// and all-namespaces layouts all work; the `%` columns are ignored.
+pub fn parse_top(output: &str) -> Vec<TopRow> {
+    let mut lines = output.lines().filter(|l| !l.trim().is_empty());
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.split_whitespace().collect();
+    let find = |name: &str| columns.iter().position(|c| *c == name);
+    let (Some(name_col), Some(cpu_col), Some(mem_col)) =
+        (find("NAME"), find("CPU(cores)"), find("MEMORY(bytes)"))
+    else {
+        return Vec::new();
+    };
+    let ns_col = find("NAMESPACE");
+
+    lines
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let cpu = *fields.get(cpu_col)?;
+            let memory = *fields.get(mem_col)?;
+            Some(TopRow {
+                namespace: ns_col
+                    .and_then(|i| fields.get(i))
+                    .map_or_else(String::new, |ns| (*ns).to_string()),
+                name: (*fields.get(name_col)?).to_string(),
+                cpu: cpu.to_string(),
+                memory: memory.to_string(),
+                cpu_cores: parse_quantity(cpu).unwrap_or(0.0),
+                memory_bytes: parse_quantity(memory).unwrap_or(0.0),
+            })
+        })
+        .collect()
+}
+
+/// Sort rows by `sort`, highest first.
+pub fn sort_top(rows: &mut [TopRow], sort: TopSort) {
+    let key = |row: &TopRow| match sort {
+        TopSort::Cpu => row.cpu_cores,
+        TopSort::Memory => row.memory_bytes,
+    };
+    rows.sort_by(|a, b| key(b).total_cmp(&key(a)));
+}
+
+/// Aligned table of the first `n` rows.
+pub fn render_top(rows: &[TopRow], n: usize) -> String {
+    let rows = &rows[..n.min(rows.len())];
+    let with_ns = rows.iter().any(|r| !r.namespace.is_empty());
+    let label = |r: &TopRow| {
+        if r.namespace.is_empty() {
+            r.name.clone()
+        } else {
+            format!("{}/{}", r.namespace, r.name)
+        }
+    };
+    let width = rows
+        .iter()
+        .map(|r| label(r).len())
+        .chain(std::iter::once(4))
+        .max()
+        .unwrap_or(4);
+    let title = if with_ns { "NAMESPACE/NAME" } else { "NAME" };
+    let width = width.max(title.len());
+
+    let mut out = format!("{title:<width$}  {:>8}  {:>10}\n", "CPU", "MEMORY");
+    for row in rows {
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>8}  {:>10}",
+            label(row),
+            row.cpu,
+            row.memory
+        );
+    }
+    out
+}
+
+/// Run `kubectl top`, then print the top `n` rows sorted by `sort`.
+pub fn run_top(
+    target: TopTarget,
+    namespace: Option<&str>,
+    context: &str,
+    sort: TopSort,
+    n: usize,
+) -> Result<()> {
+    // kubectl top takes a single namespace; several are listed cluster-wide
+    // and filtered below.
+    let namespaces = namespace.map(parse_namespaces).unwrap_or_default();
+    let scope = match namespaces.as_slice() {
+        [ns] => Some(ns.as_str()),
+        _ => None,
+    };
+    let out = Command::new("kubectl")
+        .args(top_args(target, scope, context))
+        .output()?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        if stderr.contains("Metrics API not available") || stderr.contains("metrics.k8s.io") {
+            anyhow::bail!(
+                "kubectl top needs metrics-server, which this cluster doesn't seem to run.\n\
+                 See https://github.com/kubernetes-sigs/metrics-server"
+            );
+        }
+        anyhow::bail!("kubectl top failed: {}", stderr.trim());
+    }
+    let mut rows = parse_top(&String::from_utf8_lossy(&out.stdout));
+    if target == TopTarget::Pods && namespaces.len() > 1 {
+        rows.retain(|row| namespaces.contains(&row.namespace));
+    }
+    if rows.is_empty() {
+        println!("no {} reported by metrics-server", target.as_str());
+        return Ok(());
+    }
+    sort_top(&mut rows, sort);
+    print!("{}", render_top(&rows, n));
+    Ok(())
+}
@@ -0,0 +1,273 @@
+//! skim option builders for the main resource list.
+
+use anyhow::Result;
+use skim::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::actions::preview_toggle_path;
+use crate::cli::namespace_badge;
+use crate::items::{K8sItem, Layout, ResourceKind, StatusHealth};
+
+/// Share of the terminal width given to the preview pane (`right:50%`).
+const PREVIEW_PERCENT: usize = 50;
+
+/// Share of the terminal height skim takes when `--height` is not given.
+pub const DEFAULT_HEIGHT_PERCENT: u8 = 60;
+
+/// Columns skim draws before each row (cursor and selection markers) plus
+/// the preview border.
+const LIST_GUTTER: usize = 3;
+
+/// Columns a list row can use in a terminal `terminal_cols` wide.
+pub fn list_columns(terminal_cols: usize) -> usize {
+    (terminal_cols * (100 - PREVIEW_PERCENT) / 100).saturating_sub(LIST_GUTTER)
+}
+
+/// Everything the main list's header and key bindings depend on.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Default)]
+pub struct ListView<'a> {
+    pub ctx_label: &'a str,
+    pub kind_label: &'a str,
+    /// Offer ctrl-x context switching, alt-n namespace toggling and alt-k
+    /// kind visibility (single-cluster mode only).
+    pub show_ctx_switch: bool,
+    pub read_only: bool,
+    pub namespace: Option<&'a str>,
+    /// Active-filters status line shown under the key hints.
+    pub footer: &'a str,
+    /// Enable skim's mouse mode (click to select, wheel to scroll).
+    pub mouse: bool,
+    /// Share of the terminal height (`--height`); `None` for the default.
+    pub height: Option<u8>,
+    /// List layout (`--layout`).
+    pub layout: Layout,
+    /// Match the query as exact substrings instead of fuzzily (`--exact`,
+    /// toggled with alt-m).
+    pub exact: bool,
+}
+
+impl ListView<'_> {
+    /// skim's `height` value, e.g. `60%`.
+    fn height(&self) -> String {
+        format!("{}%", self.height.unwrap_or(DEFAULT_HEIGHT_PERCENT))
+    }
+}
+
+// RST-006: return Result instead of panicking with .expect()
+pub fn build_skim_options(view: &ListView) -> Result<SkimOptions> {
+    let ListView {
+        ctx_label,
+        kind_label,
+        show_ctx_switch,
+        read_only,
+        namespace,
+        footer,
+        mouse,
+        ..
+    } = *view;
+    let ro_hint = if read_only { "  [READ-ONLY]" } else { "" };
+    let exact_hint = if view.exact { "  [EXACT]" } else { "" };
+    let ns_badge = namespace_badge(namespace);
+
+    Ok(SkimOptionsBuilder::default()
+        .multi(true)
+        .exact(view.exact)
+        .no_mouse(!mouse)
+        .preview(String::new())
+        .preview_window("right:50%")
+        .height(view.height())
+        .layout(view.layout.as_str())
+        .header(format!(
+            "KubeRift  ctx:{ctx_label}  {ns_badge}  res:{kind_label}{ro_hint}{exact_hint}\n\
+             <tab> select  <enter> describe  ctrl-l logs  ctrl-e exec  ctrl-d delete  \
+             ctrl-y yaml  ctrl-p preview  f1 all-keys\n\
+             {footer}",
+        ))
+        .prompt("❯ ")
+        .bind(list_binds(show_ctx_switch))
+        .build()?)
+}
+
+/// skim bindings of the main list: every action key accepts so `dispatch`
+/// can act on it; the context, namespace, kind and label pickers and the
+/// exact/fuzzy toggle only when `show_ctx_switch`.
+pub fn list_binds(show_ctx_switch: bool) -> Vec<String> {
+    let mut binds = vec![
+        "ctrl-l:accept".to_string(),
+        "alt-l:accept".to_string(),
+        "ctrl-e:accept".to_string(),
+        "ctrl-d:accept".to_string(),
+        "alt-x:accept".to_string(),
+        "alt-z:accept".to_string(),
+        "alt-a:accept".to_string(),
+        "ctrl-f:accept".to_string(),
+        "ctrl-r:accept".to_string(),
+        "alt-p:accept".to_string(),
+        "alt-r:accept".to_string(),
+        "ctrl-s:accept".to_string(),
+        "alt-o:accept".to_string(),
+        "alt-u:accept".to_string(),
+        "alt-e:accept".to_string(),
+        "ctrl-w:accept".to_string(),
+        "ctrl-y:accept".to_string(),
+        "alt-c:accept".to_string(),
+        "alt-w:accept".to_string(),
+        "alt-y:accept".to_string(),
+        "alt-v:accept".to_string(),
+        "alt-i:accept".to_string(),
+        "ctrl-o:accept".to_string(),
+        "f1:accept".to_string(),
+        format!(
+            "ctrl-p:execute({})+refresh-preview",
+            preview_toggle_path().display()
+        ),
+    ];
+    if show_ctx_switch {
+        binds.push("ctrl-x:accept".to_string());
+        binds.push("alt-n:accept".to_string());
+        binds.push("alt-k:accept".to_string());
+        binds.push("alt-t:accept".to_string());
+        binds.push("alt-m:accept".to_string());
+    }
+    binds
+}
+
+/// Keys of the main list and what they do, listed by f1; the header only
+/// has room for the everyday ones.
+const KEY_HELP: &[(&str, &str)] = &[
+    ("tab", "select"),
+    ("enter", "describe"),
+    ("ctrl-l", "logs"),
+    ("alt-l", "crash logs"),
+    ("ctrl-e", "exec"),
+    ("ctrl-d", "delete"),
+    ("alt-a", "delete everything matching the label filter"),
+    ("alt-x", "force-delete"),
+    ("alt-z", "restart a pod's containers"),
+    ("ctrl-f", "port-forward"),
+    ("ctrl-r", "rollout restart"),
+    ("alt-p / alt-r", "rollout pause / resume"),
+    ("ctrl-s", "scale"),
+    ("alt-o / alt-u", "cordon / uncordon"),
+    ("alt-e", "drain"),
+    ("ctrl-w", "edit"),
+    ("ctrl-y", "yaml"),
+    ("alt-y", "copy yaml"),
+    ("alt-w", "watch"),
+    ("alt-v", "drift from last-applied"),
+    ("alt-i", "apply a manifest file"),
+    ("alt-c", "show the kubectl commands"),
+    ("ctrl-o", "sort"),
+    ("ctrl-p", "cycle preview (incl. previous logs)"),
+];
+
+/// Keys of the context, namespace, kind and label pickers and the
+/// exact/fuzzy toggle, bound only in single-cluster mode.
+const CTX_KEY_HELP: &[(&str, &str)] = &[
+    ("ctrl-x", "switch context"),
+    ("alt-n", "toggle namespace / all namespaces"),
+    ("alt-k", "show/hide kinds"),
+    ("alt-t", "narrow the label filter"),
+    ("alt-m", "exact / fuzzy matching"),
+];
+
+/// The f1 help view: every key `list_binds` binds, one per line.
+pub fn key_help(show_ctx_switch: bool) -> String {
+    let ctx: &[(&str, &str)] = if show_ctx_switch { CTX_KEY_HELP } else { &[] };
+    KEY_HELP
+        .iter()
+        .chain(ctx)
+        .map(|(key, action)| format!("  {key:<15}{action}\n"))
+        .collect()
+}
+
+/// Minimal options for `--pick`: the list and preview only, no action
+/// bindings; Enter accepts the selection so it can be printed.
+pub fn build_pick_options(view: &ListView) -> Result<SkimOptions> {
+    let ns_badge = namespace_badge(view.namespace);
+    Ok(SkimOptionsBuilder::default()
+        .multi(true)
+        .exact(view.exact)
+        .no_mouse(!view.mouse)
+        .preview(String::new())
+        .preview_window("right:50%")
+        .height(view.height())
+        .layout(view.layout.as_str())
+        .header(format!(
+            "KubeRift  ctx:{}  {ns_badge}  res:{}\n\
+             <tab> select  <enter> print selection",
+            view.ctx_label, view.kind_label
+        ))
+        .prompt("❯ ")
+        .build()?)
+}
+
+/// Text printed by `--pick`: one `output_str` per selected item.
+pub fn pick_output(items: &[&K8sItem]) -> String {
+    items
+        .iter()
+        .map(|item| format!("{}\n", item.output_str()))
+        .collect()
+}
+
+/// Text printed for `--output json`: the selected items as a JSON array.
+pub fn json_output(items: &[&K8sItem]) -> Result<String> {
+    Ok(format!("{}\n", serde_json::to_string_pretty(items)?))
+}
+
+/// Text printed for `--output grouped-json`: the selected items as a JSON
+/// object keyed by context, each value an array of that cluster's items in
+/// selection order. Items of a single-cluster session have no context and
+/// are listed under `""`.
+pub fn grouped_json_output(items: &[&K8sItem]) -> Result<String> {
+    let mut groups: BTreeMap<&str, Vec<&K8sItem>> = BTreeMap::new();
+    for item in items {
+        groups.entry(item.context()).or_default().push(*item);
+    }
+    Ok(format!("{}\n", serde_json::to_string_pretty(&groups)?))
+}
+
+/// Drop repeated selections, keeping the first of each `output_str`. A late
+/// watcher reconnect can re-send a resource skim already holds, so the same
+/// item may be selected twice; it should still be acted on and printed once.
+pub fn dedup_selection(items: Vec<&K8sItem>) -> Vec<&K8sItem> {
+    let mut seen = HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| seen.insert(item.output_str()))
+        .collect()
+}
+
+// ─── Two-stage pick (--two-stage) ────────────────────────────────────────────
+
+/// Kinds offered in the first stage: each of `kinds` with at least one live
+/// resource in `items`, with its count, in `kinds` order. Deleted and
+/// unavailable rows don't count.
+pub fn kind_choices(items: &[K8sItem], kinds: &[ResourceKind]) -> Vec<(ResourceKind, usize)> {
+    kinds
+        .iter()
+        .map(|kind| {
+            let count = items
+                .iter()
+                .filter(|item| item.kind() == kind)
+                .filter(|item| StatusHealth::classify(&item.status()) != StatusHealth::Unknown)
+                .count();
+            (kind.clone(), count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+/// First-stage row, e.g. `pod      12`.
+pub fn kind_choice_label(kind: &ResourceKind, count: usize) -> String {
+    format!("{:<8} {count}", kind.as_str())
+}
+
+/// Kinds and header label of the second stage for the kind picked in the
+/// first; `None` when the first stage was cancelled.
+pub fn instance_stage(picked: Option<ResourceKind>) -> Option<(Vec<ResourceKind>, String)> {
+    let kind = picked?;
+    let label = kind.as_str().to_string();
+    Some((vec![kind], label))
+}
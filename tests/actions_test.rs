@@ -7,14 +7,30 @@
 //! fact that stdin is closed / EOF in automated test runs, which causes the functions to
 //! cancel cleanly and return Ok(()).
 
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use kuberift::actions::{
-    action_delete, action_describe, action_edit, action_exec, action_logs, action_portforward,
-    action_rollout_restart, action_scale, action_yaml, current_preview_mode,
-    install_preview_toggle, preview_toggle_path, runtime_dir,
+    action_apply, action_apply_prompt, action_copy_yaml, action_cordon, action_delete,
+    action_delete_matching, action_describe, action_drain, action_drift, action_edit, action_exec,
+    action_force_delete, action_jsonpath, action_logs, action_logs_smart, action_portforward,
+    action_raw_watch, action_report, action_restart_container, action_rollout_pause,
+    action_rollout_restart, action_rollout_resume, action_scale, action_uncordon, action_yaml,
+    build_apply_args, build_container_logs_args, build_delete_args, build_describe_args,
+    build_drain_args, build_exec_args, build_force_delete_args, build_logs_args,
+    build_portforward_args, build_raw_watch_args, build_scale_args, build_selector_delete_args,
+    build_yaml_args, command_line, container_flags, copy_to_clipboard, cronjob_jobs_args,
+    current_preview_mode, default_action_for, drain_emptydir_flag, drift_report, dry_run_commands,
+    find_drift, force_deletable, install_preview_toggle, install_preview_toggle_preserving,
+    job_logs_args, job_pod_selector, job_pods_args, jsonpath_args, kubectl_version, last_applied,
+    latest_cronjob_job, map_ordered, needs_strict_confirm, node_args, parse_container_names,
+    parse_dry_run_count, parse_kubectl_version, parse_pod_names, pod_containers_args,
+    pod_restart_plan, preview_toggle_path, rollout_args, runtime_dir, should_pause,
+    smart_logs_args, with_spinner, ActionOutcome, KubectlVersion, PodRestart, SelectorDelete,
+    DEFAULT_BULK_DELETE_THRESHOLD, MIN_KUBECTL_VERSION, PREVIEW_MODES,
 };
-use kuberift::items::{K8sItem, ResourceKind};
+use kuberift::items::{ItemState, K8sItem, ResourceKind};
 
 // ── Test item helpers ─────────────────────────────────────────────────────────
 
@@ -109,6 +125,52 @@ where
     result
 }
 
+/// Like [`with_fake_kubectl`], but the fake kubectl prints `stdout` and
+/// exits 0 on every call.
+fn with_fake_kubectl_printing<F, T>(stdout: &str, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _guard = PATH_MUTEX.lock().unwrap();
+    let tmp = tempfile::tempdir().unwrap();
+    let kubectl = tmp.path().join("kubectl");
+    std::fs::write(&kubectl, format!("#!/bin/sh\nprintf '%s' '{stdout}'\n")).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&kubectl, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let old_path = std::env::var("PATH").unwrap_or_default();
+    // SAFETY: serialised by PATH_MUTEX — no concurrent PATH reads during f().
+    unsafe { std::env::set_var("PATH", format!("{}:{old_path}", tmp.path().display())) };
+    let result = f();
+    unsafe { std::env::set_var("PATH", old_path) };
+    result
+}
+
+/// Like [`with_fake_kubectl`], but PATH holds *only* the fake kubectl, so no
+/// other tool (e.g. a clipboard helper) can be found.
+fn with_only_fake_kubectl<F, T>(exit_code: i32, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _guard = PATH_MUTEX.lock().unwrap();
+    let tmp = tempfile::tempdir().unwrap();
+    let kubectl = tmp.path().join("kubectl");
+    std::fs::write(&kubectl, format!("#!/bin/sh\nexit {exit_code}\n")).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&kubectl, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let old_path = std::env::var("PATH").unwrap_or_default();
+    // SAFETY: serialised by PATH_MUTEX — no concurrent PATH reads during f().
+    unsafe { std::env::set_var("PATH", tmp.path()) };
+    let result = f();
+    unsafe { std::env::set_var("PATH", old_path) };
+    result
+}
+
 // ── runtime_dir ───────────────────────────────────────────────────────────────
 
 #[test]
@@ -153,6 +215,29 @@ fn install_preview_toggle_creates_mode_file_set_to_zero() {
     );
 }
 
+#[test]
+fn install_preview_toggle_preserving_keeps_the_mode() {
+    let _guard = PREVIEW_MUTEX.lock().unwrap();
+    install_preview_toggle();
+    std::fs::write(runtime_dir().join("preview-mode"), "2").unwrap();
+    install_preview_toggle_preserving();
+    assert_eq!(
+        current_preview_mode(),
+        2,
+        "logs mode must survive a relaunch"
+    );
+    assert!(preview_toggle_path().exists());
+}
+
+#[test]
+fn install_preview_toggle_still_resets_after_preserving() {
+    let _guard = PREVIEW_MUTEX.lock().unwrap();
+    std::fs::write(runtime_dir().join("preview-mode"), "4").unwrap();
+    install_preview_toggle_preserving();
+    install_preview_toggle();
+    assert_eq!(current_preview_mode(), 0);
+}
+
 #[test]
 fn install_preview_toggle_creates_executable_script() {
     let _guard = PREVIEW_MUTEX.lock().unwrap();
@@ -178,6 +263,64 @@ fn current_preview_mode_returns_zero_to_two() {
     assert!(mode <= 2, "mode must be 0, 1, or 2; got {mode}");
 }
 
+#[test]
+fn current_preview_mode_wraps_after_previous_logs() {
+    let _guard = PREVIEW_MUTEX.lock().unwrap();
+    install_preview_toggle();
+    let mode_file = runtime_dir().join("preview-mode");
+    std::fs::write(&mode_file, "5").unwrap();
+    assert_eq!(current_preview_mode(), 5, "5 = previous logs");
+    std::fs::write(&mode_file, PREVIEW_MODES.to_string()).unwrap();
+    assert_eq!(current_preview_mode(), 0);
+    install_preview_toggle();
+}
+
+#[cfg(unix)]
+#[test]
+fn preview_toggle_script_cycles_through_every_mode() {
+    let _guard = PREVIEW_MUTEX.lock().unwrap();
+    install_preview_toggle();
+    let mut seen = Vec::new();
+    for _ in 0..PREVIEW_MODES {
+        std::process::Command::new(preview_toggle_path())
+            .status()
+            .unwrap();
+        seen.push(current_preview_mode());
+    }
+    assert_eq!(seen, [1, 2, 3, 4, 5, 0]);
+}
+
+// ── ActionOutcome ─────────────────────────────────────────────────────────────
+
+#[test]
+fn action_outcome_counts_successes_and_failures() {
+    let mut outcome = ActionOutcome::default();
+    for ok in [true, true, false, true, false] {
+        outcome.record(ok);
+    }
+    assert_eq!(outcome.succeeded, 3);
+    assert_eq!(outcome.failed, 2);
+    assert_eq!(outcome.total(), 5);
+}
+
+#[test]
+fn action_outcome_summary_reports_failures() {
+    let outcome = ActionOutcome {
+        succeeded: 18,
+        failed: 2,
+    };
+    assert_eq!(outcome.summary("Deleted"), "Deleted 18/20 (2 failed)");
+}
+
+#[test]
+fn action_outcome_summary_omits_zero_failures() {
+    let outcome = ActionOutcome {
+        succeeded: 3,
+        failed: 0,
+    };
+    assert_eq!(outcome.summary("Restarted"), "Restarted 3/3");
+}
+
 // ── action_logs — kind guard ───────────────────────────────────────────────────
 
 #[test]
@@ -227,6 +370,274 @@ fn action_logs_pod_with_kubectl_failure_still_ok() {
     );
 }
 
+// ── action_logs — container selection ─────────────────────────────────────────
+
+#[test]
+fn pod_containers_args_query_container_names() {
+    assert_eq!(
+        pod_containers_args(&pod_item()),
+        [
+            "get",
+            "pod",
+            "-o",
+            "jsonpath={.spec.containers[*].name}",
+            "-n",
+            "default",
+            "--",
+            "test-pod"
+        ]
+    );
+    assert_eq!(parse_container_names("app sidecar"), ["app", "sidecar"]);
+    assert!(parse_container_names("").is_empty());
+}
+
+#[test]
+fn container_flags_by_number_name_or_all() {
+    let containers = vec!["app".to_string(), "istio-proxy".to_string()];
+    assert_eq!(
+        container_flags("2\n", &containers).unwrap(),
+        ["-c", "istio-proxy"]
+    );
+    assert_eq!(container_flags("app", &containers).unwrap(), ["-c", "app"]);
+    assert_eq!(
+        container_flags("", &containers).unwrap(),
+        ["--all-containers"]
+    );
+    for bad in ["0", "3", "db"] {
+        assert!(container_flags(bad, &containers).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn build_container_logs_args_places_flags_before_the_name() {
+    let args = build_container_logs_args(&pod_item(), &["-c".to_string(), "app".to_string()]);
+    assert_eq!(
+        args,
+        [
+            "logs",
+            "--tail=200",
+            "-c",
+            "app",
+            "-n",
+            "default",
+            "--",
+            "test-pod"
+        ]
+    );
+    assert_eq!(
+        build_logs_args(&pod_item()),
+        build_container_logs_args(&pod_item(), &[])
+    );
+}
+
+#[test]
+fn action_logs_single_container_pod_streams_without_prompt() {
+    let item = pod_item();
+    let result = with_fake_kubectl_printing("app", || action_logs(&[&item]));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn action_logs_multi_container_pod_with_empty_stdin_is_ok() {
+    // No answer on stdin → all containers.
+    let item = pod_item();
+    let result = with_fake_kubectl_printing("app sidecar", || action_logs(&[&item]));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn action_logs_ok_when_container_query_fails() {
+    let item = pod_item();
+    let result = with_fake_kubectl(1, || action_logs(&[&item]));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+// ── action_logs — Job / CronJob ───────────────────────────────────────────────
+
+fn job_item() -> K8sItem {
+    K8sItem::new(
+        ResourceKind::Job,
+        "batch",
+        "backup-28431",
+        "Complete",
+        "5m",
+        "",
+    )
+}
+
+fn cronjob_item() -> K8sItem {
+    K8sItem::new(
+        ResourceKind::CronJob,
+        "batch",
+        "backup",
+        "Active",
+        "30d",
+        "",
+    )
+}
+
+#[test]
+fn job_pod_selector_uses_the_job_name_label() {
+    assert_eq!(job_pod_selector("backup-28431"), "job-name=backup-28431");
+}
+
+#[test]
+fn job_pods_args_list_pods_by_label_in_the_namespace() {
+    assert_eq!(
+        job_pods_args(&job_item(), "backup-28431"),
+        [
+            "get",
+            "pods",
+            "-l",
+            "job-name=backup-28431",
+            "-o",
+            "name",
+            "-n",
+            "batch"
+        ]
+    );
+    assert_eq!(
+        cronjob_jobs_args(&cronjob_item()),
+        ["get", "jobs", "-o", "json", "-n", "batch"]
+    );
+}
+
+#[test]
+fn job_logs_args_combine_and_prefix_every_pod() {
+    let args = job_logs_args(&job_item(), "backup-28431");
+    assert_eq!(args[..3], ["logs", "-l", "job-name=backup-28431"]);
+    for flag in ["--prefix", "-f", "--all-containers"] {
+        assert!(args.iter().any(|a| a == flag), "{flag} missing: {args:?}");
+    }
+    assert!(args.ends_with(&["-n".to_string(), "batch".to_string()]));
+}
+
+#[test]
+fn parse_pod_names_strips_the_resource_prefix() {
+    assert_eq!(
+        parse_pod_names("pod/backup-28431-x7k2p\npod/backup-28431-q9zzt\n\n"),
+        ["backup-28431-x7k2p", "backup-28431-q9zzt"]
+    );
+    assert!(parse_pod_names("").is_empty());
+}
+
+#[test]
+fn latest_cronjob_job_picks_the_newest_owned_job() {
+    let job = |name: &str, created: &str, owner: &str| {
+        serde_json::json!({
+            "metadata": {
+                "name": name,
+                "creationTimestamp": created,
+                "ownerReferences": [{ "kind": "CronJob", "name": owner }],
+            }
+        })
+    };
+    let list = serde_json::json!({
+        "items": [
+            job("backup-28430", "2026-03-01T02:00:00Z", "backup"),
+            job("backup-28432", "2026-03-03T02:00:00Z", "backup"),
+            job("report-28433", "2026-03-04T02:00:00Z", "report"),
+            job("backup-28431", "2026-03-02T02:00:00Z", "backup"),
+            { "metadata": { "name": "manual", "creationTimestamp": "2026-03-05T00:00:00Z" } },
+        ]
+    })
+    .to_string();
+    assert_eq!(
+        latest_cronjob_job(&list, "backup").unwrap().as_deref(),
+        Some("backup-28432")
+    );
+    assert_eq!(latest_cronjob_job(&list, "cleanup").unwrap(), None);
+    assert!(latest_cronjob_job("not json", "backup").is_err());
+}
+
+#[test]
+fn action_logs_job_without_pods_returns_ok() {
+    // The fake kubectl prints nothing: no pods, and no jobs for the cronjob.
+    let job = job_item();
+    let cronjob = cronjob_item();
+    let result = with_fake_kubectl(0, || action_logs(&[&job, &cronjob]));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+// ── action_logs_smart — crash-aware logs ─────────────────────────────────────
+
+#[test]
+fn smart_logs_crashing_pod_fetches_previous_then_current() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "api",
+        "CrashLoopBackOff (x4)",
+        "1h",
+        "",
+    );
+    item.state().write().unwrap().crashed_at = Some(1_700_000_000);
+    let calls = smart_logs_args(&item);
+    assert_eq!(calls.len(), 2);
+    assert_eq!(
+        calls[0],
+        vec![
+            "logs",
+            "--previous",
+            "--tail=200",
+            "-n",
+            "default",
+            "--",
+            "api"
+        ]
+    );
+    assert_eq!(
+        calls[1],
+        vec!["logs", "--tail=200", "-n", "default", "--", "api"]
+    );
+    let result = with_fake_kubectl(0, || action_logs_smart(&item));
+    assert!(result.is_ok(), "action_logs_smart should be Ok: {result:?}");
+}
+
+#[test]
+fn smart_logs_healthy_pod_fetches_current_only() {
+    let item = pod_item();
+    let calls = smart_logs_args(&item);
+    assert_eq!(calls.len(), 1);
+    assert!(!calls[0].contains(&"--previous".to_string()));
+}
+
+#[test]
+fn smart_logs_pod_that_never_restarted_fetches_current_only() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "api",
+        "ImagePullBackOff",
+        "1h",
+        "",
+    );
+    let calls = smart_logs_args(&item);
+    assert_eq!(calls.len(), 1);
+    assert!(!calls[0].contains(&"--previous".to_string()));
+}
+
+#[test]
+fn smart_logs_running_pod_that_just_crashed_fetches_previous() {
+    let item = K8sItem::new(ResourceKind::Pod, "default", "api", "Running", "1h", "");
+    let crashed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        - 60;
+    item.state().write().unwrap().crashed_at = Some(i64::try_from(crashed).unwrap());
+    assert!(item.status().starts_with("Running (restarted"));
+    let calls = smart_logs_args(&item);
+    assert_eq!(calls.len(), 2);
+    assert!(calls[0].contains(&"--previous".to_string()));
+}
+
+#[test]
+fn smart_logs_skips_non_pod() {
+    let item = deploy_item();
+    assert!(action_logs_smart(&item).is_ok());
+}
+
 // ── action_exec — kind guard ───────────────────────────────────────────────────
 
 #[test]
@@ -268,7 +679,7 @@ fn action_exec_pod_with_kubectl_failure_on_both_shells() {
 fn action_delete_single_item_cancelled_on_empty_stdin() {
     // In automated runs stdin is closed → read_line returns "" → not "y" → cancel
     let item = pod_item();
-    let result = action_delete(&[&item]);
+    let result = action_delete(&[&item], DEFAULT_BULK_DELETE_THRESHOLD);
     assert!(
         result.is_ok(),
         "action_delete with empty stdin must return Ok: {result:?}"
@@ -278,7 +689,7 @@ fn action_delete_single_item_cancelled_on_empty_stdin() {
 #[test]
 fn action_delete_cluster_scoped_item_cancelled_on_empty_stdin() {
     let item = node_item();
-    let result = action_delete(&[&item]);
+    let result = action_delete(&[&item], DEFAULT_BULK_DELETE_THRESHOLD);
     assert!(result.is_ok());
 }
 
@@ -298,80 +709,443 @@ fn action_delete_bulk_more_than_ten_cancelled_on_empty_stdin() {
         })
         .collect();
     let refs: Vec<&K8sItem> = items.iter().collect();
-    let result = action_delete(&refs);
+    let result = action_delete(&refs, DEFAULT_BULK_DELETE_THRESHOLD);
     assert!(
         result.is_ok(),
         "bulk delete with empty stdin must return Ok: {result:?}"
     );
 }
 
-// ── action_portforward — kind guard ───────────────────────────────────────────
-
 #[test]
-fn action_portforward_returns_ok_immediately_for_non_pod_non_service() {
-    let item = deploy_item();
-    let result = action_portforward(&item);
+fn action_delete_custom_threshold_takes_strict_path() {
+    // threshold 2, 3 items → "type 'yes'" prompt; empty stdin cancels
+    assert!(needs_strict_confirm(3, 2));
+    let items: Vec<K8sItem> = (0..3)
+        .map(|i| {
+            K8sItem::new(
+                ResourceKind::Pod,
+                "ns",
+                format!("pod-{i}"),
+                "Running",
+                "1d",
+                "",
+            )
+        })
+        .collect();
+    let refs: Vec<&K8sItem> = items.iter().collect();
+    let result = action_delete(&refs, 2);
     assert!(
         result.is_ok(),
-        "action_portforward on Deployment must return Ok: {result:?}"
+        "strict delete with empty stdin must return Ok: {result:?}"
     );
 }
 
 #[test]
-fn action_portforward_returns_ok_for_node_kind() {
-    let item = node_item();
-    let result = action_portforward(&item);
-    assert!(result.is_ok());
+fn needs_strict_confirm_boundaries() {
+    assert!(!needs_strict_confirm(10, DEFAULT_BULK_DELETE_THRESHOLD));
+    assert!(needs_strict_confirm(11, DEFAULT_BULK_DELETE_THRESHOLD));
+    assert!(
+        needs_strict_confirm(1, 0),
+        "threshold 0 makes every delete strict"
+    );
 }
 
-// ── action_portforward — stdin-empty cancel (Pod / Service) ──────────────────
+// ── action_force_delete (alt-x) ───────────────────────────────────────────────
+
+fn terminating_pod() -> K8sItem {
+    K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "stuck-0",
+        "Terminating",
+        "3d",
+        "",
+    )
+}
 
 #[test]
-fn action_portforward_pod_cancelled_on_empty_stdin() {
-    // Port prompt reads stdin; empty stdin → Ok(None) for local port → "Cancelled."
-    let item = pod_item();
-    let result = action_portforward(&item);
-    assert!(
-        result.is_ok(),
-        "action_portforward cancelled on empty stdin: {result:?}"
+fn build_force_delete_args_skip_the_grace_period() {
+    assert_eq!(
+        build_force_delete_args(&terminating_pod()),
+        [
+            "delete",
+            "pod",
+            "--grace-period=0",
+            "--force",
+            "-n",
+            "default",
+            "--",
+            "stuck-0"
+        ]
     );
 }
 
 #[test]
-fn action_portforward_service_cancelled_on_empty_stdin() {
-    let item = service_item();
-    let result = action_portforward(&item);
-    assert!(result.is_ok());
+fn force_delete_only_for_terminating_pods() {
+    assert!(force_deletable(&terminating_pod()));
+    assert!(!force_deletable(&pod_item()), "Running pod");
+    let terminating_deploy = K8sItem::new(
+        ResourceKind::Deployment,
+        "default",
+        "web",
+        "Terminating",
+        "1d",
+        "",
+    );
+    assert!(!force_deletable(&terminating_deploy));
 }
 
-// ── action_rollout_restart — kind guard ──────────────────────────────────────
-
 #[test]
-fn action_rollout_restart_skips_pod_and_returns_ok() {
+fn action_force_delete_rejects_non_terminating_pod() {
+    // A kubectl that fails proves it is never reached: the call must still be
+    // Ok and nothing deleted.
     let item = pod_item();
-    let result = action_rollout_restart(&[&item]);
-    assert!(
-        result.is_ok(),
-        "action_rollout_restart on Pod must skip and return Ok: {result:?}"
-    );
+    let result = with_fake_kubectl(1, || action_force_delete(&[&item]));
+    assert!(result.is_ok(), "{result:?}");
 }
 
 #[test]
-fn action_rollout_restart_skips_service_and_returns_ok() {
-    let item = service_item();
-    let result = action_rollout_restart(&[&item]);
-    assert!(result.is_ok());
+fn action_force_delete_empty_stdin_skips() {
+    let item = terminating_pod();
+    let result = action_force_delete(&[&item]);
+    assert!(result.is_ok(), "{result:?}");
 }
 
-// ── action_rollout_restart — kubectl paths ────────────────────────────────────
+#[test]
+fn dry_run_offers_force_delete_for_terminating_pods_only() {
+    let labels = |item: &K8sItem| -> Vec<&'static str> {
+        dry_run_commands(item).into_iter().map(|(l, _)| l).collect()
+    };
+    assert!(labels(&terminating_pod()).contains(&"force-delete"));
+    assert!(!labels(&pod_item()).contains(&"force-delete"));
+}
+
+// ── Restart a pod's containers (alt-z) ────────────────────────────────────────
+
+fn owned_pod(owner: &str) -> K8sItem {
+    let state = ItemState {
+        status: "CrashLoopBackOff".to_string(),
+        age: "1d".to_string(),
+        owner: owner.to_string(),
+        controlled: !owner.is_empty(),
+        ..ItemState::default()
+    };
+    K8sItem::new_live(
+        ResourceKind::Pod,
+        "default",
+        "web-7d9f8b6c5-x2k4q",
+        "",
+        Arc::new(RwLock::new(state)),
+    )
+}
 
 #[test]
-fn action_rollout_restart_deploy_kubectl_success() {
-    let item = deploy_item();
-    let result = with_fake_kubectl(0, || action_rollout_restart(&[&item]));
-    assert!(
-        result.is_ok(),
-        "rollout restart should be Ok when kubectl exits 0: {result:?}"
+fn managed_pod_is_restarted_by_recreating_it() {
+    assert_eq!(
+        pod_restart_plan(&owned_pod("ReplicaSet/web-7d9f8b6c5")),
+        PodRestart::Recreate {
+            owner: "ReplicaSet/web-7d9f8b6c5".to_string()
+        }
+    );
+}
+
+#[test]
+fn standalone_pod_and_other_kinds_are_not_restarted() {
+    assert_eq!(pod_restart_plan(&owned_pod("")), PodRestart::Standalone);
+    assert_eq!(pod_restart_plan(&deploy_item()), PodRestart::NotAPod);
+}
+
+#[test]
+fn pod_owned_but_not_controlled_is_not_restarted() {
+    let item = owned_pod("ConfigMap/web-config");
+    item.state().write().unwrap().controlled = false;
+    assert_eq!(pod_restart_plan(&item), PodRestart::Standalone);
+}
+
+#[test]
+fn action_restart_container_managed_pod_proceeds_to_delete() {
+    // Reaches the delete confirmation, which empty stdin cancels.
+    let item = owned_pod("ReplicaSet/web-7d9f8b6c5");
+    let result = with_fake_kubectl(0, || action_restart_container(&item));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn action_restart_container_standalone_pod_warns() {
+    // A kubectl that fails proves it is never reached.
+    let item = owned_pod("");
+    let result = with_fake_kubectl(1, || action_restart_container(&item));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+// ── Delete by selector (alt-a / --delete-selector) ───────────────────────────
+
+fn failed_pods() -> SelectorDelete {
+    SelectorDelete {
+        kind: "pod".to_string(),
+        namespace: "prod".to_string(),
+        context: String::new(),
+        label: None,
+        field: Some("status.phase=Failed".to_string()),
+        kubeconfig: None,
+    }
+}
+
+#[test]
+fn build_selector_delete_args_deletes_by_field_selector() {
+    assert_eq!(
+        build_selector_delete_args(&failed_pods(), false),
+        [
+            "delete",
+            "pod",
+            "--field-selector",
+            "status.phase=Failed",
+            "-n",
+            "prod"
+        ]
+    );
+}
+
+#[test]
+fn build_selector_delete_args_dry_run_lists_names() {
+    let target = SelectorDelete {
+        label: Some("app=web".to_string()),
+        context: "staging".to_string(),
+        ..failed_pods()
+    };
+    assert_eq!(
+        build_selector_delete_args(&target, true),
+        [
+            "delete",
+            "pod",
+            "-l",
+            "app=web",
+            "--field-selector",
+            "status.phase=Failed",
+            "-n",
+            "prod",
+            "--context",
+            "staging",
+            "--dry-run=client",
+            "-o",
+            "name"
+        ]
+    );
+}
+
+#[test]
+fn build_selector_delete_args_pass_the_kubeconfig_on() {
+    let target = SelectorDelete {
+        context: "ci".to_string(),
+        kubeconfig: Some("other.yaml".to_string()),
+        ..failed_pods()
+    };
+    let args = build_selector_delete_args(&target, false);
+    assert_eq!(
+        args[args.len() - 4..],
+        ["--context", "ci", "--kubeconfig", "other.yaml"]
+    );
+}
+
+#[test]
+fn selector_delete_like_takes_the_items_kind_namespace_and_context() {
+    let item = K8sItem::new(ResourceKind::Pod, "prod", "web-0", "Evicted", "1d", "east");
+    let target = SelectorDelete::like(&item, "app=web");
+    assert_eq!(
+        build_selector_delete_args(&target, false),
+        [
+            "delete",
+            "pod",
+            "-l",
+            "app=web",
+            "-n",
+            "prod",
+            "--context",
+            "east"
+        ]
+    );
+    assert_eq!(target.describe(), "pod -l app=web -n prod");
+}
+
+#[test]
+fn parse_dry_run_count_counts_names() {
+    assert_eq!(parse_dry_run_count("pod/web-0\npod/web-1\n\n"), 2);
+    assert_eq!(parse_dry_run_count(""), 0);
+}
+
+#[test]
+fn action_delete_matching_empty_stdin_cancels() {
+    let result = with_fake_kubectl_printing("pod/web-0\npod/web-1\n", || {
+        action_delete_matching(&failed_pods())
+    });
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn action_delete_matching_fails_when_dry_run_fails() {
+    let result = with_fake_kubectl(1, || action_delete_matching(&failed_pods()));
+    assert!(result.is_err());
+}
+
+// ── Apply a manifest (alt-i) ──────────────────────────────────────────────────
+
+#[test]
+fn apply_args_add_namespace_and_context_when_given() {
+    let path = std::path::Path::new("/tmp/hotfix.yaml");
+    assert_eq!(
+        build_apply_args(path, "", None),
+        ["apply", "-f", "/tmp/hotfix.yaml"]
+    );
+    assert_eq!(
+        build_apply_args(path, "prod", Some("web")),
+        [
+            "apply",
+            "-f",
+            "/tmp/hotfix.yaml",
+            "-n",
+            "web",
+            "--context",
+            "prod"
+        ]
+    );
+}
+
+#[test]
+fn apply_missing_file_errors_without_kubectl() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.yaml");
+    let err = action_apply(&missing, "", None).unwrap_err();
+    assert!(err.to_string().contains("missing.yaml"), "got {err}");
+    // A directory is not a manifest either.
+    assert!(action_apply(dir.path(), "", None).is_err());
+}
+
+#[test]
+fn apply_existing_file_with_fake_kubectl_succeeds() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest = dir.path().join("hotfix.yaml");
+    std::fs::write(&manifest, "apiVersion: v1\nkind: ConfigMap\n").unwrap();
+    let result = with_fake_kubectl(0, || action_apply(&manifest, "", Some("default")));
+    assert!(result.is_ok(), "got {result:?}");
+}
+
+#[test]
+fn apply_reports_kubectl_failure() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest = dir.path().join("hotfix.yaml");
+    std::fs::write(&manifest, "kind: ConfigMap\n").unwrap();
+    assert!(with_fake_kubectl(1, || action_apply(&manifest, "", None)).is_err());
+}
+
+#[test]
+fn apply_prompt_stdin_empty_cancels() {
+    let result = with_fake_kubectl(0, || action_apply_prompt("", None));
+    assert!(result.is_ok());
+}
+
+// ── action_portforward — kind guard ───────────────────────────────────────────
+
+#[test]
+fn action_portforward_returns_ok_immediately_for_non_pod_non_service() {
+    let item = deploy_item();
+    let result = action_portforward(&item);
+    assert!(
+        result.is_ok(),
+        "action_portforward on Deployment must return Ok: {result:?}"
+    );
+}
+
+#[test]
+fn action_portforward_returns_ok_for_node_kind() {
+    let item = node_item();
+    let result = action_portforward(&item);
+    assert!(result.is_ok());
+}
+
+// ── action_portforward — stdin-empty cancel (Pod / Service) ──────────────────
+
+#[test]
+fn action_portforward_pod_cancelled_on_empty_stdin() {
+    // Port prompt reads stdin; empty stdin → Ok(None) for local port → "Cancelled."
+    let item = pod_item();
+    let result = action_portforward(&item);
+    assert!(
+        result.is_ok(),
+        "action_portforward cancelled on empty stdin: {result:?}"
+    );
+}
+
+#[test]
+fn action_portforward_service_cancelled_on_empty_stdin() {
+    let item = service_item();
+    let result = action_portforward(&item);
+    assert!(result.is_ok());
+}
+
+// ── action_rollout_pause / action_rollout_resume ─────────────────────────────
+
+#[test]
+fn rollout_args_pause_deployment() {
+    let item = deploy_item();
+    assert_eq!(
+        rollout_args(&item, "pause"),
+        vec!["rollout", "pause", "deploy/test-deploy", "-n", "default"]
+    );
+    assert_eq!(rollout_args(&item, "resume")[1], "resume");
+}
+
+#[test]
+fn action_rollout_pause_skips_non_deployment() {
+    let pod = pod_item();
+    let result = action_rollout_pause(&[&pod]);
+    assert!(result.is_ok(), "pause on a pod must skip: {result:?}");
+}
+
+#[test]
+fn action_rollout_resume_deploy_kubectl_success() {
+    let item = deploy_item();
+    let result = with_fake_kubectl(0, || action_rollout_resume(&[&item]));
+    assert!(result.is_ok(), "resume should be Ok: {result:?}");
+}
+
+#[test]
+fn action_rollout_pause_kubectl_failure_still_ok() {
+    let item = deploy_item();
+    let result = with_fake_kubectl(1, || action_rollout_pause(&[&item]));
+    assert!(
+        result.is_ok(),
+        "pause failure is reported, not returned: {result:?}"
+    );
+}
+
+// ── action_rollout_restart — kind guard ──────────────────────────────────────
+
+#[test]
+fn action_rollout_restart_skips_pod_and_returns_ok() {
+    let item = pod_item();
+    let result = action_rollout_restart(&[&item]);
+    assert!(
+        result.is_ok(),
+        "action_rollout_restart on Pod must skip and return Ok: {result:?}"
+    );
+}
+
+#[test]
+fn action_rollout_restart_skips_service_and_returns_ok() {
+    let item = service_item();
+    let result = action_rollout_restart(&[&item]);
+    assert!(result.is_ok());
+}
+
+// ── action_rollout_restart — kubectl paths ────────────────────────────────────
+
+#[test]
+fn action_rollout_restart_deploy_kubectl_success() {
+    let item = deploy_item();
+    let result = with_fake_kubectl(0, || action_rollout_restart(&[&item]));
+    assert!(
+        result.is_ok(),
+        "rollout restart should be Ok when kubectl exits 0: {result:?}"
     );
 }
 
@@ -468,6 +1242,206 @@ fn action_describe_works_for_cluster_scoped_resources() {
     assert!(result.is_ok());
 }
 
+// ── default_action_for — per-kind Enter commands ─────────────────────────────
+
+#[test]
+fn default_action_for_pod_is_plain_describe() {
+    let item = pod_item();
+    assert_eq!(
+        default_action_for(item.kind(), &item),
+        vec![build_describe_args(&item)]
+    );
+}
+
+#[test]
+fn default_action_for_service_adds_endpoints_query() {
+    let item = service_item();
+    let commands = default_action_for(item.kind(), &item);
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[0], build_describe_args(&item));
+    assert_eq!(
+        strs(&commands[1]),
+        [
+            "get",
+            "endpoints",
+            "-o",
+            "wide",
+            "-n",
+            "default",
+            "--",
+            "test-svc"
+        ]
+    );
+}
+
+#[test]
+fn default_action_for_node_includes_describe_and_capacity() {
+    let item = node_item();
+    let commands = default_action_for(item.kind(), &item);
+    assert_eq!(strs(&commands[0]), ["describe", "node", "--", "node-1"]);
+    let columns = commands[1].join(" ");
+    assert!(columns.contains("custom-columns="));
+    assert!(columns.contains(".status.allocatable.cpu"));
+    assert!(columns.contains(".status.capacity.memory"));
+}
+
+#[test]
+fn default_action_for_ingress_lists_backend_services() {
+    let item = K8sItem::new(ResourceKind::Ingress, "default", "web", "ok", "1d", "");
+    let commands = default_action_for(item.kind(), &item);
+    assert_eq!(commands.len(), 2);
+    assert!(commands[1]
+        .iter()
+        .any(|a| a.contains(".backend.service.name")));
+    assert_eq!(commands[1].last().map(String::as_str), Some("web"));
+}
+
+#[test]
+fn action_describe_service_with_enrichment_succeeds() {
+    let item = service_item();
+    assert!(with_fake_kubectl(0, || action_describe(&[&item])).is_ok());
+}
+
+#[test]
+fn action_describe_many_items_in_parallel() {
+    let items: Vec<K8sItem> = (0..20)
+        .map(|i| {
+            K8sItem::new(
+                ResourceKind::Pod,
+                "default",
+                format!("pod-{i}"),
+                "Running",
+                "1m",
+                "",
+            )
+        })
+        .collect();
+    let refs: Vec<&K8sItem> = items.iter().collect();
+    let result = with_fake_kubectl(0, || action_describe(&refs));
+    assert!(result.is_ok(), "parallel describe should be Ok: {result:?}");
+}
+
+// ── map_ordered ───────────────────────────────────────────────────────────────
+
+#[test]
+fn map_ordered_preserves_input_order_despite_completion_order() {
+    // Earlier inputs sleep longer, so they finish last.
+    let inputs: Vec<u64> = (0..8).collect();
+    let out = map_ordered(&inputs, 8, |&i| {
+        std::thread::sleep(std::time::Duration::from_millis((8 - i) * 10));
+        i * 2
+    });
+    assert_eq!(out, vec![0, 2, 4, 6, 8, 10, 12, 14]);
+}
+
+#[test]
+fn map_ordered_caps_concurrency() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    let running = AtomicUsize::new(0);
+    let peak = AtomicUsize::new(0);
+    let inputs: Vec<usize> = (0..16).collect();
+    let out = map_ordered(&inputs, 3, |&i| {
+        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+        peak.fetch_max(now, Ordering::SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        running.fetch_sub(1, Ordering::SeqCst);
+        i
+    });
+    assert_eq!(out, inputs);
+    assert!(peak.load(Ordering::SeqCst) <= 3);
+}
+
+#[test]
+fn map_ordered_empty_input() {
+    let out: Vec<u8> = map_ordered(&[] as &[u8], 8, |&b| b);
+    assert!(out.is_empty());
+}
+
+// ── action_jsonpath ───────────────────────────────────────────────────────────
+
+#[test]
+fn jsonpath_args_include_expression_namespace_and_name() {
+    let item = pod_item();
+    let args = jsonpath_args(&item, ".spec.nodeName");
+    assert_eq!(
+        args,
+        vec![
+            "get",
+            "pod",
+            "-o",
+            "jsonpath={.spec.nodeName}",
+            "-n",
+            "default",
+            "--",
+            "test-pod"
+        ]
+    );
+}
+
+#[test]
+fn jsonpath_args_keep_braced_template_and_skip_namespace_for_cluster_scoped() {
+    let item = node_item();
+    let args = jsonpath_args(&item, "{.status.nodeInfo.kubeletVersion}");
+    assert!(args.contains(&"jsonpath={.status.nodeInfo.kubeletVersion}".to_string()));
+    assert!(!args.contains(&"-n".to_string()));
+}
+
+#[test]
+fn action_jsonpath_kubectl_success() {
+    let pod = pod_item();
+    let deploy = deploy_item();
+    let result = with_fake_kubectl(0, || action_jsonpath(&[&pod, &deploy], ".metadata.uid"));
+    assert!(result.is_ok(), "action_jsonpath should be Ok: {result:?}");
+}
+
+#[test]
+fn action_jsonpath_empty_expression_is_ok_without_kubectl() {
+    let item = pod_item();
+    assert!(action_jsonpath(&[&item], "  ").is_ok());
+}
+
+// ── action_report ─────────────────────────────────────────────────────────────
+
+#[test]
+fn action_report_writes_summary_and_section_per_resource() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("report.md");
+    let crashing = K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "crashing-pod",
+        "CrashLoopBackOff",
+        "1h",
+        "",
+    );
+    let deploy = deploy_item();
+    let result = with_fake_kubectl(0, || action_report(&[&crashing, &deploy], &path));
+    assert!(result.is_ok(), "action_report should be Ok: {result:?}");
+
+    let md = std::fs::read_to_string(&path).unwrap();
+    assert!(md.starts_with("# KubeRift report"));
+    assert!(md.contains("## Summary"));
+    assert!(md.contains("| critical | 1 | `pod/default/crashing-pod` |"));
+    assert!(md.contains("| healthy | 1 | `deploy/default/test-deploy` |"));
+    assert!(md.contains("## pod/default/crashing-pod — CrashLoopBackOff"));
+    assert!(md.contains("## deploy/default/test-deploy — 3/3"));
+    assert!(md.contains("```text"));
+}
+
+#[test]
+fn action_report_notes_kubectl_failure_per_item() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("report.md");
+    let item = pod_item();
+    let result = with_fake_kubectl(1, || action_report(&[&item], &path));
+    assert!(result.is_ok());
+
+    let md = std::fs::read_to_string(&path).unwrap();
+    assert!(md.contains("## pod/default/test-pod — Running"));
+    assert!(md.contains("kubectl describe failed"));
+    assert!(!md.contains("```text"));
+}
+
 // ── Multi-cluster context forwarding ──────────────────────────────────────────
 
 #[test]
@@ -485,6 +1459,139 @@ fn action_logs_passes_context_flag_for_multi_cluster_item() {
     assert!(result.is_ok());
 }
 
+// ── kubectl version ───────────────────────────────────────────────────────────
+
+#[test]
+fn parse_kubectl_version_reads_major_and_minor() {
+    let json = r#"{"clientVersion":{"major":"1","minor":"29","gitVersion":"v1.29.2"},"kustomizeVersion":"v5.0.4"}"#;
+    assert_eq!(
+        parse_kubectl_version(json),
+        Some(KubectlVersion {
+            major: 1,
+            minor: 29
+        })
+    );
+}
+
+#[test]
+fn parse_kubectl_version_tolerates_plus_suffix() {
+    // EKS/GKE builds report e.g. "minor": "27+".
+    let json =
+        r#"{"clientVersion":{"major":"1","minor":"27+","gitVersion":"v1.27.8-eks-a5df82a"}}"#;
+    assert_eq!(
+        parse_kubectl_version(json),
+        Some(KubectlVersion {
+            major: 1,
+            minor: 27
+        })
+    );
+}
+
+#[test]
+fn parse_kubectl_version_falls_back_to_git_version() {
+    let json = r#"{"clientVersion":{"major":"","minor":"","gitVersion":"v1.17.4"}}"#;
+    assert_eq!(
+        parse_kubectl_version(json),
+        Some(KubectlVersion {
+            major: 1,
+            minor: 17
+        })
+    );
+}
+
+#[test]
+fn parse_kubectl_version_rejects_garbage() {
+    assert_eq!(parse_kubectl_version(""), None);
+    assert_eq!(parse_kubectl_version("Client Version: v1.29.2"), None);
+    assert_eq!(parse_kubectl_version(r#"{"serverVersion":{}}"#), None);
+}
+
+#[test]
+fn kubectl_versions_order_and_display() {
+    let old = KubectlVersion { major: 1, minor: 9 };
+    assert!(old < MIN_KUBECTL_VERSION);
+    assert!(
+        KubectlVersion {
+            major: 1,
+            minor: 30
+        } > MIN_KUBECTL_VERSION
+    );
+    assert_eq!(MIN_KUBECTL_VERSION.to_string(), "1.18");
+}
+
+#[test]
+fn drain_emptydir_flag_follows_kubectl_version() {
+    let v = |minor| Some(KubectlVersion { major: 1, minor });
+    assert_eq!(drain_emptydir_flag(v(19)), "--delete-local-data");
+    assert_eq!(drain_emptydir_flag(v(20)), "--delete-emptydir-data");
+    assert_eq!(drain_emptydir_flag(v(29)), "--delete-emptydir-data");
+    assert_eq!(
+        drain_emptydir_flag(None),
+        "--delete-emptydir-data",
+        "unknown version assumes a modern kubectl"
+    );
+}
+
+// ── Node maintenance — cordon / uncordon / drain ──────────────────────────────
+
+#[test]
+fn node_maintenance_args() {
+    let node = node_item();
+    assert_eq!(node_args(&node, "cordon"), ["cordon", "--", node.name()]);
+    assert_eq!(
+        node_args(&node, "uncordon"),
+        ["uncordon", "--", node.name()]
+    );
+    assert_eq!(
+        build_drain_args(&node),
+        [
+            "drain",
+            "--ignore-daemonsets",
+            drain_emptydir_flag(kubectl_version()),
+            "--",
+            node.name()
+        ]
+    );
+}
+
+#[test]
+fn node_actions_skip_pods() {
+    // A failing kubectl would be counted; the guard means it's never run.
+    let pod = pod_item();
+    for action in [action_cordon, action_uncordon, action_drain] {
+        let result = with_fake_kubectl(1, || action(&[&pod]));
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
+
+#[test]
+fn action_cordon_node_with_kubectl_success() {
+    let node = node_item();
+    let result = with_fake_kubectl(0, || action_cordon(&[&node]));
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn action_drain_empty_stdin_cancels() {
+    let node = node_item();
+    let result = with_fake_kubectl(1, || action_drain(&[&node]));
+    assert!(
+        result.is_ok(),
+        "drain with empty stdin must cancel and return Ok: {result:?}"
+    );
+}
+
+#[test]
+fn dry_run_lists_node_maintenance_for_nodes_only() {
+    let labels = |item: &K8sItem| -> Vec<&'static str> {
+        dry_run_commands(item).into_iter().map(|(l, _)| l).collect()
+    };
+    for verb in ["cordon", "uncordon", "drain"] {
+        assert!(labels(&node_item()).contains(&verb), "{verb}");
+        assert!(!labels(&pod_item()).contains(&verb), "{verb}");
+    }
+}
+
 // ── action_scale — kind guard ────────────────────────────────────────────────
 
 #[test]
@@ -556,3 +1663,335 @@ fn action_edit_works_for_any_kind() {
     let result = with_fake_kubectl(0, || action_edit(&[&item], "true"));
     assert!(result.is_ok());
 }
+
+// ── kubectl argument builders ─────────────────────────────────────────────────
+
+fn strs(args: &[String]) -> Vec<&str> {
+    args.iter().map(String::as_str).collect()
+}
+
+#[test]
+fn build_describe_args_namespaced_and_cluster_scoped() {
+    assert_eq!(
+        strs(&build_describe_args(&pod_item())),
+        ["describe", "pod", "-n", "default", "--", "test-pod"]
+    );
+    assert_eq!(
+        strs(&build_describe_args(&node_item())),
+        ["describe", "node", "--", "node-1"]
+    );
+}
+
+#[test]
+fn build_yaml_args_includes_namespace() {
+    assert_eq!(
+        strs(&build_yaml_args(&pod_item())),
+        ["get", "pod", "-o", "yaml", "-n", "default", "--", "test-pod"]
+    );
+}
+
+#[test]
+fn build_logs_args_includes_namespace() {
+    assert_eq!(
+        strs(&build_logs_args(&pod_item())),
+        ["logs", "--tail=200", "-n", "default", "--", "test-pod"]
+    );
+}
+
+#[test]
+fn build_exec_args_puts_shell_after_separator() {
+    assert_eq!(
+        strs(&build_exec_args(&pod_item(), "/bin/sh")),
+        ["exec", "-it", "test-pod", "-n", "default", "--", "/bin/sh"]
+    );
+}
+
+#[test]
+fn build_delete_args_cluster_scoped_has_no_namespace() {
+    let args = build_delete_args(&node_item());
+    assert_eq!(strs(&args), ["delete", "node", "--", "node-1"]);
+}
+
+#[test]
+fn build_portforward_args_includes_namespace() {
+    assert_eq!(
+        strs(&build_portforward_args(&pod_item(), "8080:80")),
+        ["port-forward", "pod/test-pod", "8080:80", "-n", "default"]
+    );
+}
+
+#[test]
+fn build_scale_args_includes_namespace() {
+    assert_eq!(
+        strs(&build_scale_args(&deploy_item(), "3")),
+        [
+            "scale",
+            "deploy/test-deploy",
+            "--replicas=3",
+            "-n",
+            "default"
+        ]
+    );
+}
+
+#[test]
+fn command_line_adds_context_only_for_other_clusters() {
+    let args = build_describe_args(&pod_item());
+    assert_eq!(
+        command_line(&pod_item(), &args),
+        "kubectl describe pod -n default -- test-pod"
+    );
+    let remote = K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "test-pod",
+        "Running",
+        "1d",
+        "prod",
+    );
+    assert_eq!(
+        command_line(&remote, &args),
+        "kubectl --context prod describe pod -n default -- test-pod"
+    );
+}
+
+#[test]
+fn command_line_quotes_shell_metacharacters() {
+    let item = pod_item();
+    let line = command_line(&item, &jsonpath_args(&item, "{.spec.nodeName}"));
+    assert!(line.contains("'jsonpath={.spec.nodeName}'"), "{line}");
+}
+
+#[test]
+fn dry_run_commands_match_kind() {
+    let labels = |item: &K8sItem| -> Vec<&'static str> {
+        dry_run_commands(item).into_iter().map(|(l, _)| l).collect()
+    };
+    assert!(labels(&pod_item()).contains(&"logs"));
+    assert!(!labels(&pod_item()).contains(&"scale"));
+    assert!(labels(&deploy_item()).contains(&"scale"));
+    assert!(!labels(&node_item()).contains(&"exec"));
+    assert!(labels(&job_item()).contains(&"logs"));
+}
+
+// ── with_spinner ──────────────────────────────────────────────────────────────
+
+#[test]
+fn with_spinner_returns_closure_value() {
+    let mut ran = false;
+    let value = with_spinner("working", || {
+        ran = true;
+        std::thread::sleep(std::time::Duration::from_millis(150));
+        42
+    });
+    assert!(ran);
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn with_spinner_passes_errors_through() {
+    let result: Result<(), String> = with_spinner("failing", || Err("boom".to_string()));
+    assert_eq!(result.unwrap_err(), "boom");
+}
+
+// ── action_raw_watch ──────────────────────────────────────────────────────────
+
+#[test]
+fn build_raw_watch_args_namespaced_pod() {
+    assert_eq!(
+        strs(&build_raw_watch_args(&pod_item())),
+        ["get", "pod", "-w", "-n", "default", "--", "test-pod"]
+    );
+}
+
+#[test]
+fn build_raw_watch_args_cluster_scoped_node() {
+    assert_eq!(
+        strs(&build_raw_watch_args(&node_item())),
+        ["get", "node", "-w", "--", "node-1"]
+    );
+}
+
+#[test]
+fn raw_watch_with_fake_kubectl_returns_ok() {
+    with_fake_kubectl(0, || {
+        assert!(action_raw_watch(&deploy_item()).is_ok());
+    });
+}
+
+#[test]
+fn raw_watch_kubectl_failure_still_returns_ok() {
+    with_fake_kubectl(1, || {
+        assert!(action_raw_watch(&pod_item()).is_ok());
+    });
+}
+
+// ── action_copy_yaml ──────────────────────────────────────────────────────────
+
+#[test]
+fn copy_to_clipboard_without_tools_reports_false() {
+    with_only_fake_kubectl(0, || {
+        assert!(!copy_to_clipboard("apiVersion: v1").unwrap());
+    });
+}
+
+#[test]
+fn copy_yaml_without_clipboard_tool_returns_ok() {
+    with_only_fake_kubectl(0, || {
+        assert!(action_copy_yaml(&pod_item()).is_ok());
+    });
+}
+
+#[test]
+fn copy_yaml_kubectl_failure_returns_ok() {
+    with_fake_kubectl(1, || {
+        assert!(action_copy_yaml(&deploy_item()).is_ok());
+    });
+}
+
+// ── should_pause ──────────────────────────────────────────────────────────────
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+#[test]
+fn should_pause_after_output_actions_on_a_tty() {
+    assert!(should_pause(
+        key(KeyCode::Enter, KeyModifiers::NONE),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::F(1), KeyModifiers::NONE),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::Char('y'), KeyModifiers::CONTROL),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::Char('l'), KeyModifiers::CONTROL),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::Char('l'), KeyModifiers::ALT),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::Char('c'), KeyModifiers::ALT),
+        true,
+        false
+    ));
+    assert!(should_pause(
+        key(KeyCode::Char('v'), KeyModifiers::ALT),
+        true,
+        false
+    ));
+    assert!(
+        should_pause(key(KeyCode::Char('e'), KeyModifiers::ALT), true, false),
+        "drain output stays readable"
+    );
+    assert!(
+        should_pause(key(KeyCode::Char('a'), KeyModifiers::ALT), true, false),
+        "delete-all-matching output stays readable"
+    );
+}
+
+#[test]
+fn should_not_pause_after_interactive_actions() {
+    for k in [
+        key(KeyCode::Char('e'), KeyModifiers::CONTROL),
+        key(KeyCode::Char('w'), KeyModifiers::CONTROL),
+        key(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        key(KeyCode::Char('w'), KeyModifiers::ALT),
+    ] {
+        assert!(!should_pause(k, true, false), "{k:?}");
+    }
+}
+
+#[test]
+fn should_not_pause_without_tty_or_with_no_pause() {
+    let enter = key(KeyCode::Enter, KeyModifiers::NONE);
+    assert!(!should_pause(enter, false, false));
+    assert!(!should_pause(enter, true, true));
+}
+
+// ── Drift from last-applied (alt-v) ───────────────────────────────────────────
+
+/// A Deployment applied with 2 replicas of nginx:1.25, since scaled to 5 and
+/// with its `tier` label removed. `strategy` and `status` are cluster-filled.
+const DRIFTED_DEPLOYMENT: &str = r#"{
+  "apiVersion": "apps/v1",
+  "kind": "Deployment",
+  "metadata": {
+    "name": "web",
+    "namespace": "default",
+    "labels": {"app": "web"},
+    "annotations": {
+      "kubectl.kubernetes.io/last-applied-configuration": "{\"apiVersion\":\"apps/v1\",\"kind\":\"Deployment\",\"metadata\":{\"labels\":{\"app\":\"web\",\"tier\":\"frontend\"},\"name\":\"web\",\"namespace\":\"default\"},\"spec\":{\"replicas\":2,\"template\":{\"spec\":{\"containers\":[{\"image\":\"nginx:1.25\",\"name\":\"web\"}]}}}}"
+    }
+  },
+  "spec": {
+    "replicas": 5,
+    "strategy": {"type": "RollingUpdate"},
+    "template": {"spec": {"containers": [{"image": "nginx:1.25", "name": "web"}]}}
+  },
+  "status": {"replicas": 5}
+}"#;
+
+#[test]
+fn last_applied_extracts_annotation() {
+    let live: serde_json::Value = serde_json::from_str(DRIFTED_DEPLOYMENT).unwrap();
+    let applied = last_applied(&live).unwrap().expect("annotation present");
+    assert_eq!(applied["spec"]["replicas"], 2);
+    assert_eq!(applied["metadata"]["labels"]["tier"], "frontend");
+}
+
+#[test]
+fn last_applied_none_without_annotation() {
+    let live = serde_json::json!({"metadata": {"name": "web", "annotations": {}}});
+    assert!(last_applied(&live).unwrap().is_none());
+    let live = serde_json::json!({"metadata": {"name": "web"}});
+    assert!(last_applied(&live).unwrap().is_none());
+}
+
+#[test]
+fn find_drift_reports_changed_and_removed_fields_only() {
+    let live: serde_json::Value = serde_json::from_str(DRIFTED_DEPLOYMENT).unwrap();
+    let applied = last_applied(&live).unwrap().unwrap();
+    let drift = find_drift(&applied, &live);
+    let paths: Vec<&str> = drift.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(paths, ["metadata.labels.tier", "spec.replicas"]);
+    assert_eq!(drift[0].live, None);
+    assert_eq!(drift[1].live, Some(serde_json::json!(5)));
+}
+
+#[test]
+fn drift_report_renders_diff_lines() {
+    let report = drift_report(DRIFTED_DEPLOYMENT, false).unwrap();
+    assert_eq!(
+        report,
+        "- metadata.labels.tier: \"frontend\"\n+ metadata.labels.tier: (removed)\n\
+         - spec.replicas: 2\n+ spec.replicas: 5\n"
+    );
+}
+
+#[test]
+fn drift_report_handles_unapplied_objects() {
+    let report = drift_report(r#"{"metadata": {"name": "web"}}"#, false).unwrap();
+    assert!(
+        report.contains("not managed with kubectl apply"),
+        "{report}"
+    );
+}
+
+#[test]
+fn action_drift_survives_kubectl_failure_and_empty_output() {
+    assert!(with_fake_kubectl(1, || action_drift(&[&pod_item()])).is_ok());
+    assert!(with_fake_kubectl(0, || action_drift(&[&deploy_item()])).is_ok());
+}
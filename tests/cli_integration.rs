@@ -67,6 +67,20 @@ fn completions_fish() {
         .stdout(predicate::str::is_empty().not());
 }
 
+// ── --help-colors ─────────────────────────────────────────────────────────────
+
+#[test]
+fn help_colors_prints_legend() {
+    Command::cargo_bin("kf")
+        .unwrap()
+        .arg("--help-colors")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Resource kinds"))
+        .stdout(predicate::str::contains("Status health"))
+        .stdout(predicate::str::contains("CrashLoopBackOff"));
+}
+
 // ── --mangen ──────────────────────────────────────────────────────────────────
 
 #[test]
@@ -78,3 +92,59 @@ fn mangen_flag() {
         .success()
         .stdout(predicate::str::contains("kf"));
 }
+
+// ── --delete-selector ─────────────────────────────────────────────────────────
+
+#[test]
+fn delete_selector_respects_read_only_from_config() {
+    let tmp = tempfile::tempdir().unwrap();
+    let config_dir = tmp.path().join("config").join("kuberift");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        "[general]\nread_only = true\n",
+    )
+    .unwrap();
+
+    // A fake kubectl that records every call.
+    let bin = tmp.path().join("bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    let calls = tmp.path().join("calls.log");
+    std::fs::write(
+        bin.join("kubectl"),
+        format!("#!/bin/sh\necho \"$@\" >> {}\n", calls.display()),
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(bin.join("kubectl"), std::fs::Permissions::from_mode(0o755))
+            .unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        bin.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+
+    Command::cargo_bin("kf")
+        .unwrap()
+        .args([
+            "pods",
+            "--delete-selector",
+            "status.phase=Failed",
+            "-n",
+            "prod",
+            "--context",
+            "test",
+        ])
+        .env("XDG_CONFIG_HOME", tmp.path().join("config"))
+        .env("HOME", tmp.path())
+        .env("PATH", path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("read-only mode"));
+
+    let calls = std::fs::read_to_string(&calls).unwrap_or_default();
+    assert!(!calls.contains("delete"), "kubectl calls:\n{calls}");
+}
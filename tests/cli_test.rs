@@ -1,38 +1,143 @@
 //! Tests for kuberift::cli — Args::resource_filter alias resolution and config merge.
 
-use kuberift::cli::Args;
+use kuberift::cli::{
+    add_label_requirement, apply_exclusions, ignored_namespace_notice, kind_label, namespace_badge,
+    parse_age_format, parse_columns, parse_height, parse_layout, parse_min_severity, parse_output,
+    parse_resource_kinds, parse_sort_field, parse_theme, status_footer, validate_label_selector,
+    Args,
+};
 use kuberift::config::Config;
-use kuberift::items::ResourceKind;
+use kuberift::items::{
+    AgeFormat, Column, Layout, OutputFormat, ResourceKind, SortField, StatusHealth, Theme,
+};
 
 // ── Helper ────────────────────────────────────────────────────────────────────
 
 fn args_with(resource: &str) -> Args {
     Args {
         resource: Some(resource.to_string()),
+        exclude: None,
         all_contexts: false,
-        context: None,
+        group_by_context: false,
+        changes_only: false,
+        exclude_namespaces: vec![],
+        no_system: false,
+        sort: None,
+        reverse: false,
+        no_pause: false,
+        api_resources: false,
+        age_format: AgeFormat::Relative,
+        show_labels: false,
+        columns: None,
+        metrics: false,
+        compact_status: false,
+        hide_terminating: false,
+        height: None,
+        layout: Layout::Default,
+        output: OutputFormat::Text,
+        watch_file: None,
+        init_timeout: 8,
+        context_timeout: 8,
+        stale_after: 0,
+        context: Vec::new(),
+        use_kube_current: false,
+        forget_context: false,
+        print_config: false,
         namespace: None,
         read_only: false,
+        bulk_delete_threshold: 10,
+        mouse: false,
+        exact: false,
+        pick: false,
+        two_stage: false,
         label: None,
         kubeconfig: None,
         completions: None,
         mangen: false,
+        help_colors: false,
+        theme_preview: false,
         no_crds: false,
+        name_width: 31,
+        describe_width: None,
+        theme: None,
+        jsonpath: None,
+        report: None,
+        report_all: false,
+        max_retries: 10,
+        page_size: None,
+        count: false,
+        min_severity: None,
+        fail_if_nonzero: false,
+        top: None,
+        sort_by: None,
+        top_n: 10,
+        rollout: None,
+        delete_selector: None,
+        summary: false,
     }
 }
 
 fn no_resource_args() -> Args {
     Args {
         resource: None,
+        exclude: None,
         all_contexts: false,
-        context: None,
+        group_by_context: false,
+        changes_only: false,
+        exclude_namespaces: vec![],
+        no_system: false,
+        sort: None,
+        reverse: false,
+        no_pause: false,
+        api_resources: false,
+        age_format: AgeFormat::Relative,
+        show_labels: false,
+        columns: None,
+        metrics: false,
+        compact_status: false,
+        hide_terminating: false,
+        height: None,
+        layout: Layout::Default,
+        output: OutputFormat::Text,
+        watch_file: None,
+        init_timeout: 8,
+        context_timeout: 8,
+        stale_after: 0,
+        context: Vec::new(),
+        use_kube_current: false,
+        forget_context: false,
+        print_config: false,
         namespace: None,
         read_only: false,
+        bulk_delete_threshold: 10,
+        mouse: false,
+        exact: false,
+        pick: false,
+        two_stage: false,
         label: None,
         kubeconfig: None,
         completions: None,
         mangen: false,
+        help_colors: false,
+        theme_preview: false,
         no_crds: false,
+        name_width: 31,
+        describe_width: None,
+        theme: None,
+        jsonpath: None,
+        report: None,
+        report_all: false,
+        max_retries: 10,
+        page_size: None,
+        count: false,
+        min_severity: None,
+        fail_if_nonzero: false,
+        top: None,
+        sort_by: None,
+        top_n: 10,
+        rollout: None,
+        delete_selector: None,
+        summary: false,
     }
 }
 
@@ -239,6 +344,38 @@ fn filter_job_aliases() {
     }
 }
 
+// ── Endpoints aliases ─────────────────────────────────────────────────────────
+
+#[test]
+fn filter_endpoints_aliases() {
+    for alias in &["ep", "endpoint", "endpoints"] {
+        let kinds = args_with(alias)
+            .resource_filter()
+            .unwrap_or_else(|| panic!("alias '{alias}' should resolve"));
+        assert_eq!(
+            kinds,
+            vec![ResourceKind::Endpoints],
+            "alias '{alias}' → Endpoints"
+        );
+    }
+}
+
+// ── HPA aliases ───────────────────────────────────────────────────────────────
+
+#[test]
+fn filter_hpa_aliases() {
+    for alias in &["hpa", "horizontalpodautoscaler", "HorizontalPodAutoscalers"] {
+        let kinds = args_with(alias)
+            .resource_filter()
+            .unwrap_or_else(|| panic!("alias '{alias}' should resolve"));
+        assert_eq!(
+            kinds,
+            vec![ResourceKind::HorizontalPodAutoscaler],
+            "alias '{alias}' → HorizontalPodAutoscaler"
+        );
+    }
+}
+
 // ── CronJob aliases ───────────────────────────────────────────────────────────
 
 #[test]
@@ -267,11 +404,30 @@ fn filter_event_aliases() {
     }
 }
 
+#[test]
+fn filter_rbac_aliases() {
+    let cases: &[(&str, ResourceKind)] = &[
+        ("sa", ResourceKind::ServiceAccount),
+        ("serviceaccount", ResourceKind::ServiceAccount),
+        ("serviceaccounts", ResourceKind::ServiceAccount),
+        ("role", ResourceKind::Role),
+        ("roles", ResourceKind::Role),
+        ("rolebinding", ResourceKind::RoleBinding),
+        ("rolebindings", ResourceKind::RoleBinding),
+    ];
+    for (alias, expected) in cases {
+        let kinds = args_with(alias)
+            .resource_filter()
+            .unwrap_or_else(|| panic!("alias '{alias}' should resolve"));
+        assert_eq!(kinds, vec![expected.clone()], "alias '{alias}'");
+    }
+}
+
 // ── Unknown alias falls back to None ─────────────────────────────────────────
 
 #[test]
 fn filter_unknown_alias_returns_custom() {
-    for alias in &["unknowntype", "replicaset", "hpa", "certificates"] {
+    for alias in &["unknowntype", "replicaset", "pdb", "certificates"] {
         let kinds = args_with(alias)
             .resource_filter()
             .unwrap_or_else(|| panic!("alias '{alias}' should resolve to Custom"));
@@ -283,6 +439,99 @@ fn filter_unknown_alias_returns_custom() {
     }
 }
 
+// ── Several kinds (pod,svc,deploy) ───────────────────────────────────────────
+
+#[test]
+fn filter_comma_separated_kinds() {
+    assert_eq!(
+        args_with("pod,svc,deploy").resource_filter(),
+        Some(vec![
+            ResourceKind::Pod,
+            ResourceKind::Service,
+            ResourceKind::Deployment
+        ])
+    );
+}
+
+#[test]
+fn filter_comma_separated_kinds_are_deduplicated() {
+    assert_eq!(
+        args_with("pods, po,POD,svc,,services").resource_filter(),
+        Some(vec![ResourceKind::Pod, ResourceKind::Service])
+    );
+}
+
+#[test]
+fn filter_skips_invalid_tokens_among_valid_ones() {
+    let (kinds, rejected) = parse_resource_kinds("pod,sv c,widgets,deploy!");
+    assert_eq!(
+        kinds,
+        [
+            ResourceKind::Pod,
+            ResourceKind::Custom("widgets".to_string())
+        ]
+    );
+    assert_eq!(rejected, ["sv c", "deploy!"]);
+    assert_eq!(
+        args_with("pod,sv c").resource_filter(),
+        Some(vec![ResourceKind::Pod])
+    );
+}
+
+#[test]
+fn filter_all_when_every_token_is_invalid() {
+    assert!(args_with("sv c,deploy!").resource_filter().is_none());
+    assert!(args_with(",,").resource_filter().is_none());
+}
+
+// ── --exclude ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn apply_exclusions_removes_excluded_kinds_in_order() {
+    let kinds = [
+        ResourceKind::Pod,
+        ResourceKind::Secret,
+        ResourceKind::Service,
+        ResourceKind::ConfigMap,
+    ];
+    assert_eq!(
+        apply_exclusions(&kinds, &[ResourceKind::ConfigMap, ResourceKind::Secret]),
+        [ResourceKind::Pod, ResourceKind::Service]
+    );
+    assert_eq!(apply_exclusions(&kinds, &[]), kinds);
+    assert!(apply_exclusions(&kinds, &kinds).is_empty());
+}
+
+#[test]
+fn excluded_kinds_resolve_aliases() {
+    let args = Args {
+        exclude: Some("secrets, cm,sv c".to_string()),
+        ..no_resource_args()
+    };
+    assert_eq!(
+        args.excluded_kinds(),
+        [ResourceKind::Secret, ResourceKind::ConfigMap]
+    );
+    assert!(no_resource_args().excluded_kinds().is_empty());
+}
+
+#[test]
+fn watch_kinds_exclude_is_an_alias_of_exclude() {
+    use clap::Parser;
+    let args = Args::try_parse_from(["kf", "--watch-kinds-exclude", "secret"]).unwrap();
+    assert_eq!(args.exclude.as_deref(), Some("secret"));
+}
+
+#[test]
+fn kind_label_joins_short_names() {
+    assert_eq!(
+        kind_label(&[ResourceKind::Pod, ResourceKind::Service]),
+        "pod,svc"
+    );
+    assert_eq!(kind_label(&[ResourceKind::Pod]), "pod");
+    assert_eq!(kind_label(kuberift::k8s::resources::ALL_KINDS), "all");
+}
+
 // ── Case-insensitive matching ─────────────────────────────────────────────────
 
 #[test]
@@ -338,7 +587,7 @@ fn merge_config_fills_empty_args() {
     let mut args = no_resource_args();
     args.merge_with_config(&config);
     assert_eq!(args.namespace.as_deref(), Some("production"));
-    assert_eq!(args.context.as_deref(), Some("staging"));
+    assert_eq!(args.context, ["staging"]);
     assert_eq!(args.resource.as_deref(), Some("pods"));
     assert!(args.read_only);
 }
@@ -355,13 +604,13 @@ fn merge_config_cli_overrides_config() {
     );
     let mut args = Args {
         namespace: Some("kube-system".to_string()),
-        context: Some("my-cluster".to_string()),
+        context: vec!["my-cluster".to_string()],
         ..no_resource_args()
     };
     args.merge_with_config(&config);
     // CLI values should win
     assert_eq!(args.namespace.as_deref(), Some("kube-system"));
-    assert_eq!(args.context.as_deref(), Some("my-cluster"));
+    assert_eq!(args.context, ["my-cluster"]);
 }
 
 #[test]
@@ -370,7 +619,424 @@ fn merge_empty_config_changes_nothing() {
     let mut args = no_resource_args();
     args.merge_with_config(&config);
     assert!(args.namespace.is_none());
-    assert!(args.context.is_none());
+    assert!(args.context.is_empty());
     assert!(args.resource.is_none());
     assert!(!args.read_only);
 }
+
+// ── --print-config ────────────────────────────────────────────────────────────
+
+#[test]
+fn print_config_shows_resolved_context_and_kinds() {
+    let args = Args {
+        namespace: Some("payments".to_string()),
+        sort: Some(SortField::Age),
+        ..args_with("deploy")
+    };
+    let out = EffectiveConfig::new(&args, &Config::default(), "prod-eu").render();
+    assert!(out.contains("context       prod-eu\n"), "{out}");
+    assert!(out.contains("kinds         deploy\n"), "{out}");
+    assert!(out.contains("namespace     payments\n"), "{out}");
+    assert!(out.contains("sort          age\n"), "{out}");
+    assert!(out.contains("theme         dark (default)\n"), "{out}");
+    assert!(out.contains("ctrl-l:accept"), "key bindings listed: {out}");
+}
+
+#[test]
+fn print_config_lists_every_kind_without_a_filter() {
+    let out = EffectiveConfig::new(&no_resource_args(), &Config::default(), "dev").render();
+    let kinds = out.lines().find(|l| l.starts_with("kinds")).unwrap();
+    assert!(kinds.contains("pod, deploy, sts"), "{kinds}");
+    assert!(out.contains("namespace     all\n"), "{out}");
+    assert!(out.contains("config values none\n"), "{out}");
+}
+
+#[test]
+fn config_overrides_name_the_values_that_apply() {
+    let config = kuberift::config::parse_config(
+        r#"
+        [general]
+        default_namespace = "production"
+        default_context = "staging"
+        editor = "hx"
+        [ui]
+        default_sort = "name"
+        "#,
+        std::path::Path::new("test.toml"),
+    );
+    let args = Args {
+        context: vec!["my-cluster".to_string()],
+        ..no_resource_args()
+    };
+    assert_eq!(
+        args.config_overrides(&config),
+        [
+            "general.default_namespace = \"production\"",
+            "general.editor = \"hx\"",
+            "ui.default_sort = \"name\"",
+        ]
+    );
+    let mut args = args;
+    args.merge_with_config(&config);
+    let out = EffectiveConfig::new(&args, &config, "my-cluster").render();
+    assert!(out.contains("sort          name\n"), "{out}");
+}
+
+// ── namespace_badge ───────────────────────────────────────────────────────────
+
+#[test]
+fn namespace_badge_single_namespace() {
+    assert_eq!(namespace_badge(Some("payments")), "[ns:payments]");
+}
+
+#[test]
+fn namespace_badge_joins_several_namespaces() {
+    assert_eq!(
+        namespace_badge(Some("app, app-system")),
+        "[ns:app,app-system]"
+    );
+}
+
+#[test]
+fn namespace_badge_cluster_wide() {
+    assert_eq!(namespace_badge(None), "[all-ns]");
+}
+
+// ── ignored_namespace_notice ──────────────────────────────────────────────────
+
+fn notice_for(resource: &str, namespace: Option<&str>) -> Option<String> {
+    let args = Args {
+        namespace: namespace.map(str::to_string),
+        ..args_with(resource)
+    };
+    ignored_namespace_notice(&args.resource_filter().unwrap(), args.namespace.as_deref())
+}
+
+#[test]
+fn namespace_notice_for_cluster_scoped_kind() {
+    let notice = notice_for("node", Some("foo")).expect("notice for node -n foo");
+    assert!(notice.contains("--namespace foo is ignored"), "{notice}");
+    assert!(notice.contains("node"), "{notice}");
+}
+
+#[test]
+fn no_namespace_notice_for_namespaced_kind_or_without_namespace() {
+    assert_eq!(notice_for("pod", Some("foo")), None);
+    assert_eq!(notice_for("node", None), None);
+}
+
+// ── parse_min_severity ────────────────────────────────────────────────────────
+
+#[test]
+fn parse_min_severity_accepts_tiers() {
+    assert_eq!(parse_min_severity("critical"), Ok(StatusHealth::Critical));
+    assert_eq!(parse_min_severity("Warning"), Ok(StatusHealth::Warning));
+}
+
+#[test]
+fn parse_min_severity_rejects_unknown() {
+    assert!(parse_min_severity("unknown").is_err());
+    assert!(parse_min_severity("severe").is_err());
+}
+
+// ── status_footer ─────────────────────────────────────────────────────────────
+
+#[test]
+fn status_footer_lists_filters_and_count() {
+    assert_eq!(
+        status_footer(Some("foo"), Some("app=nginx"), SortField::Age, Some(42)),
+        "filters: ns=foo l=app=nginx sort=age | 42 items"
+    );
+}
+
+#[test]
+fn status_footer_without_filters_or_count() {
+    assert_eq!(
+        status_footer(None, None, SortField::Health, None),
+        "filters: none"
+    );
+}
+
+// ── VPA alias ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn filter_vpa_aliases_resolve_to_crd_plural() {
+    for alias in ["vpa", "verticalpodautoscaler", "VerticalPodAutoscalers"] {
+        assert_eq!(
+            args_with(alias).resource_filter(),
+            Some(vec![ResourceKind::Custom(
+                "verticalpodautoscalers".to_string()
+            )]),
+            "alias {alias}"
+        );
+    }
+}
+
+// ── --theme ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_theme_accepts_presets() {
+    assert_eq!(parse_theme("light").unwrap(), Theme::LIGHT);
+    let err = parse_theme("neon").unwrap_err();
+    assert!(err.contains("colorblind"), "{err}");
+}
+
+// ── --age-format ──────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_age_format_accepts_each_mode() {
+    assert_eq!(parse_age_format("relative").unwrap(), AgeFormat::Relative);
+    assert_eq!(parse_age_format("absolute").unwrap(), AgeFormat::Absolute);
+    assert_eq!(parse_age_format("iso").unwrap(), AgeFormat::Iso);
+    assert!(parse_age_format("epoch").is_err());
+}
+
+// ── --sort ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_sort_field_accepts_known_fields() {
+    assert_eq!(parse_sort_field("age").unwrap(), SortField::Age);
+    assert_eq!(parse_sort_field("Name").unwrap(), SortField::Name);
+    let err = parse_sort_field("size").unwrap_err();
+    assert!(err.contains("health") && err.contains("age"), "{err}");
+}
+
+// ── --exclude-namespaces / --no-system ─────────────────────────────────────────
+
+#[test]
+fn excluded_namespaces_merges_no_system() {
+    let mut args = no_resource_args();
+    args.exclude_namespaces = vec!["monitoring".to_string(), "kube-system".to_string()];
+    args.no_system = true;
+    assert_eq!(
+        args.excluded_namespaces(),
+        [
+            "kube-node-lease",
+            "kube-public",
+            "kube-system",
+            "monitoring"
+        ]
+    );
+}
+
+#[test]
+fn excluded_namespaces_empty_by_default() {
+    assert!(no_resource_args().excluded_namespaces().is_empty());
+}
+
+// ── validate_label_selector ───────────────────────────────────────────────────
+
+#[test]
+fn label_selector_valid_forms() {
+    for expr in [
+        "app=nginx",
+        "app==nginx",
+        "app!=nginx",
+        "env in (prod,staging)",
+        "env notin (dev)",
+        "app",
+        "!canary",
+        "app.kubernetes.io/name=web",
+        "tier=frontend,env in (prod, staging),!canary",
+        "replicas>1",
+    ] {
+        assert!(
+            validate_label_selector(expr).is_ok(),
+            "{expr} should be valid: {:?}",
+            validate_label_selector(expr)
+        );
+    }
+}
+
+#[test]
+fn label_selector_missing_value_rejected() {
+    let err = validate_label_selector("app=").unwrap_err().to_string();
+    assert!(err.contains("missing value"), "{err}");
+}
+
+#[test]
+fn label_selector_malformed_forms_rejected() {
+    for expr in [
+        "",
+        "app=nginx,",
+        "env in (prod",
+        "env within (prod)",
+        "=nginx",
+        "app=-bad",
+        "my app",
+        "replicas>many",
+    ] {
+        assert!(
+            validate_label_selector(expr).is_err(),
+            "{expr} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn add_label_requirement_appends_to_selector() {
+    assert_eq!(add_label_requirement(None, "app=nginx"), "app=nginx");
+    assert_eq!(add_label_requirement(Some("  "), "app=nginx"), "app=nginx");
+    assert_eq!(
+        add_label_requirement(Some("env in (prod,staging)"), "app=nginx"),
+        "env in (prod,staging),app=nginx"
+    );
+    assert_eq!(
+        add_label_requirement(Some("app=nginx,tier=web"), "app=nginx"),
+        "app=nginx,tier=web"
+    );
+}
+
+// ── --height / --layout ───────────────────────────────────────────────────────
+
+#[test]
+fn parse_layout_accepts_known_layouts() {
+    assert_eq!(parse_layout("default"), Ok(Layout::Default));
+    assert_eq!(parse_layout("reverse"), Ok(Layout::Reverse));
+    assert!(parse_layout("reverse-list").is_err());
+}
+
+#[test]
+fn parse_height_accepts_percentages() {
+    assert_eq!(parse_height("40"), Ok(40));
+    assert_eq!(parse_height("100%"), Ok(100));
+    for bad in ["0", "101", "tall", ""] {
+        assert!(parse_height(bad).is_err(), "{bad} should be rejected");
+    }
+}
+
+#[test]
+fn parse_output_accepts_text_and_json() {
+    assert_eq!(parse_output("text"), Ok(OutputFormat::Text));
+    assert_eq!(parse_output("json"), Ok(OutputFormat::Json));
+    assert_eq!(parse_output("grouped-json"), Ok(OutputFormat::GroupedJson));
+    assert!(parse_output("yaml").is_err());
+}
+
+#[test]
+fn output_flag_parses_short_form() {
+    use clap::Parser;
+    let args = Args::try_parse_from(["kf", "pods", "-o", "json"]).unwrap();
+    assert_eq!(args.output, OutputFormat::Json);
+    let args = Args::try_parse_from(["kf", "pods"]).unwrap();
+    assert_eq!(args.output, OutputFormat::Text);
+    assert!(Args::try_parse_from(["kf", "-o", "json", "--jsonpath", ".spec"]).is_err());
+}
+
+#[test]
+fn report_all_needs_a_report_path() {
+    use clap::Parser;
+    let args = Args::try_parse_from(["kf", "pods", "--report", "out.md", "--report-all"]).unwrap();
+    assert!(args.report_all);
+    assert!(Args::try_parse_from(["kf", "pods", "--report-all"]).is_err());
+    assert!(Args::try_parse_from(["kf", "--report", "out.md", "--report-all", "--pick"]).is_err());
+}
+
+// ── --exact ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn no_fuzzy_is_an_alias_of_exact() {
+    use clap::Parser;
+    assert!(Args::try_parse_from(["kf", "--exact"]).unwrap().exact);
+    assert!(Args::try_parse_from(["kf", "--no-fuzzy"]).unwrap().exact);
+    assert!(!Args::try_parse_from(["kf"]).unwrap().exact);
+}
+
+// ── --columns ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_columns_keeps_the_given_order() {
+    let columns = parse_columns("name, Status,age").unwrap();
+    assert_eq!(columns.order(), [Column::Name, Column::Status, Column::Age]);
+}
+
+#[test]
+fn parse_columns_rejects_unknown_repeated_and_empty() {
+    assert!(parse_columns("name,size").unwrap_err().contains("'size'"));
+    assert!(parse_columns("name,name").unwrap_err().contains("twice"));
+    assert!(parse_columns(" , ").is_err());
+}
+
+// ── repeated --context ────────────────────────────────────────────────────────
+
+#[test]
+fn context_is_repeatable() {
+    use clap::Parser;
+    let args = Args::try_parse_from(["kf", "--context", "prod", "--context", "staging"]).unwrap();
+    assert_eq!(args.context, ["prod", "staging"]);
+    assert!(args.multi_context());
+}
+
+#[test]
+fn one_context_is_a_single_cluster_session() {
+    use clap::Parser;
+    let args = Args::try_parse_from(["kf", "--context", "prod"]).unwrap();
+    assert!(!args.multi_context());
+    assert!(Args::try_parse_from(["kf", "--all-contexts"])
+        .unwrap()
+        .multi_context());
+}
+
+#[test]
+fn single_cluster_flags_refuse_several_contexts() {
+    let several = || vec!["prod".to_string(), "staging".to_string()];
+    let args = Args {
+        context: several(),
+        rollout: Some("web".to_string()),
+        ..no_resource_args()
+    };
+    assert_eq!(args.single_context_flag(), Some("--rollout"));
+    let args = Args {
+        context: several(),
+        ..no_resource_args()
+    };
+    assert_eq!(args.single_context_flag(), None);
+    let args = Args {
+        context: vec!["prod".to_string()],
+        api_resources: true,
+        ..no_resource_args()
+    };
+    assert_eq!(args.single_context_flag(), None);
+}
+
+// ── --init-timeout ────────────────────────────────────────────────────────────
+
+#[test]
+fn init_timeout_defaults_to_eight_seconds() {
+    use clap::Parser;
+    use std::time::Duration;
+    let args = Args::try_parse_from(["kf"]).unwrap();
+    assert_eq!(args.init_timeout, 8);
+    assert_eq!(args.init_window(), Some(Duration::from_secs(8)));
+}
+
+#[test]
+fn init_timeout_parses_seconds_and_zero_waits_for_all() {
+    use clap::Parser;
+    use std::time::Duration;
+    let args = Args::try_parse_from(["kf", "--init-timeout", "30"]).unwrap();
+    assert_eq!(args.init_window(), Some(Duration::from_secs(30)));
+    let args = Args::try_parse_from(["kf", "--init-timeout", "0"]).unwrap();
+    assert_eq!(args.init_window(), None);
+    assert!(Args::try_parse_from(["kf", "--init-timeout", "soon"]).is_err());
+}
+
+// ── --stale-after ─────────────────────────────────────────────────────────────
+
+#[test]
+fn stale_window_in_minutes_and_zero_disables() {
+    use std::time::Duration;
+    let args = Args {
+        stale_after: 10,
+        ..args_with("pod")
+    };
+    assert_eq!(args.stale_window(), Some(Duration::from_secs(600)));
+    assert_eq!(args_with("pod").stale_window(), None);
+}
+
+#[test]
+fn stale_check_is_off_by_default() {
+    let args = Args::try_parse_from(["kf"]).unwrap();
+    assert_eq!(args.stale_after, 0);
+    assert_eq!(args.stale_window(), None);
+}
@@ -2,7 +2,11 @@
 
 use std::sync::Mutex;
 
-use kuberift::k8s::client::{current_context, list_contexts, load_last_context, save_last_context};
+use kuberift::k8s::client::{
+    clear_last_context, collision_warning, context_namespace, current_context, current_context_in,
+    find_context_collisions, list_contexts, load_kubeconfigs, load_last_context, save_last_context,
+    select_contexts,
+};
 
 /// Serialises tests that modify the last_context file so they don't race.
 static CTX_MUTEX: Mutex<()> = Mutex::new(());
@@ -20,6 +24,34 @@ fn current_context_returns_non_empty_string() {
     );
 }
 
+// ── --kubeconfig ──────────────────────────────────────────────────────────────
+
+#[test]
+fn kubeconfig_file_gives_the_current_context_and_its_namespace() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("other.yaml");
+    std::fs::write(
+        &path,
+        "apiVersion: v1\n\
+         kind: Config\n\
+         current-context: ci\n\
+         contexts:\n\
+         - name: ci\n  \
+           context:\n    \
+             cluster: ci\n    \
+             user: ci\n    \
+             namespace: builds\n",
+    )
+    .unwrap();
+    let path = path.to_str().unwrap();
+    assert_eq!(current_context_in(Some(path)), "ci");
+    assert_eq!(
+        context_namespace("ci", Some(path)).as_deref(),
+        Some("builds")
+    );
+    assert_eq!(context_namespace("prod", Some(path)), None);
+}
+
 // ── list_contexts ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -41,6 +73,36 @@ fn list_contexts_does_not_panic_without_kubeconfig() {
     let _ = list_contexts();
 }
 
+// ── select_contexts ───────────────────────────────────────────────────────────
+
+fn names(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| (*s).to_string()).collect()
+}
+
+#[test]
+fn select_contexts_keeps_known_names_in_the_order_given() {
+    let available = names(&["dev", "prod", "staging"]);
+    let (known, unknown) = select_contexts(&names(&["staging", "prod"]), &available);
+    assert_eq!(known, ["staging", "prod"]);
+    assert!(unknown.is_empty());
+}
+
+#[test]
+fn select_contexts_reports_unknown_names() {
+    let available = names(&["dev", "prod"]);
+    let (known, unknown) = select_contexts(&names(&["prod", "prdo", "qa"]), &available);
+    assert_eq!(known, ["prod"]);
+    assert_eq!(unknown, ["prdo", "qa"]);
+}
+
+#[test]
+fn select_contexts_lists_a_repeated_name_once() {
+    let available = names(&["prod"]);
+    let (known, unknown) = select_contexts(&names(&["prod", "prod", "x", "x"]), &available);
+    assert_eq!(known, ["prod"]);
+    assert_eq!(unknown, ["x"]);
+}
+
 // ── save_last_context / load_last_context ─────────────────────────────────────
 
 #[test]
@@ -92,6 +154,27 @@ fn save_last_context_trims_whitespace_on_reload() {
     }
 }
 
+#[test]
+fn clear_last_context_removes_saved_file() {
+    let _guard = CTX_MUTEX.lock().unwrap();
+    let original = load_last_context();
+
+    save_last_context("kuberift-test-forget");
+    assert!(
+        clear_last_context(),
+        "clearing a saved context must report removal"
+    );
+    assert_eq!(load_last_context(), None);
+    assert!(
+        !clear_last_context(),
+        "clearing again must report that nothing was saved"
+    );
+
+    if let Some(ref ctx) = original {
+        save_last_context(ctx);
+    }
+}
+
 #[test]
 fn load_last_context_does_not_panic_when_file_missing() {
     // Just verifies the function completes without panic in any filesystem state.
@@ -120,3 +203,62 @@ async fn build_client_nonexistent_context_returns_error() {
         "build_client_for_context with unknown context must return Err"
     );
 }
+
+// ── context collisions across merged kubeconfigs ──────────────────────────────
+
+fn kubeconfig_file(
+    dir: &std::path::Path,
+    file: &str,
+    context: &str,
+    cluster: &str,
+) -> std::path::PathBuf {
+    let path = dir.join(file);
+    let yaml = format!(
+        "apiVersion: v1\n\
+         kind: Config\n\
+         clusters:\n\
+         - name: {cluster}\n  \
+           cluster:\n    \
+             server: https://{cluster}.example:6443\n\
+         contexts:\n\
+         - name: {context}\n  \
+           context:\n    \
+             cluster: {cluster}\n    \
+             user: admin\n\
+         users:\n\
+         - name: admin\n  \
+           user: {{}}\n"
+    );
+    std::fs::write(&path, yaml).unwrap();
+    path
+}
+
+#[test]
+fn colliding_context_names_are_detected() {
+    let tmp = tempfile::tempdir().unwrap();
+    let a = kubeconfig_file(tmp.path(), "a.yaml", "prod", "east");
+    let b = kubeconfig_file(tmp.path(), "b.yaml", "prod", "west");
+    let c = kubeconfig_file(tmp.path(), "c.yaml", "staging", "east");
+
+    let configs = load_kubeconfigs(&[a.clone(), b.clone(), c]);
+    assert_eq!(configs.len(), 3);
+    let collisions = find_context_collisions(&configs);
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].name, "prod");
+    assert_eq!(collisions[0].files, vec![a.clone(), b]);
+
+    let warning = collision_warning(&collisions[0]);
+    assert!(warning.contains("'prod'"), "{warning}");
+    assert!(
+        warning.ends_with(&format!("using the one from {}", a.display())),
+        "{warning}"
+    );
+}
+
+#[test]
+fn identical_context_in_two_files_is_not_a_collision() {
+    let tmp = tempfile::tempdir().unwrap();
+    let a = kubeconfig_file(tmp.path(), "a.yaml", "prod", "east");
+    let b = kubeconfig_file(tmp.path(), "b.yaml", "prod", "east");
+    assert!(find_context_collisions(&load_kubeconfigs(&[a, b])).is_empty());
+}
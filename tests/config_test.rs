@@ -1,6 +1,7 @@
 use std::path::Path;
 
-use kuberift::config::{parse_config, Config};
+use kuberift::config::{parse_config, parse_status_overrides, Config};
+use kuberift::items::{StatusHealth, StatusOverride};
 
 #[test]
 fn empty_file_returns_defaults() {
@@ -111,3 +112,46 @@ fn default_trait_matches_empty_parse() {
     );
     assert_eq!(from_default.ui.show_namespace, from_empty.ui.show_namespace);
 }
+
+// ── status_overrides.toml ─────────────────────────────────────────────────────
+
+#[test]
+fn status_overrides_parse_exact_and_prefix_entries() {
+    let raw = r#"
+Degraded = "critical"
+"Sync*" = "Warning"
+"#;
+    let overrides = parse_status_overrides(raw, Path::new("status_overrides.toml"));
+    assert_eq!(
+        overrides,
+        [
+            StatusOverride {
+                pattern: "Degraded".to_string(),
+                prefix: false,
+                health: StatusHealth::Critical,
+            },
+            StatusOverride {
+                pattern: "Sync".to_string(),
+                prefix: true,
+                health: StatusHealth::Warning,
+            },
+        ]
+    );
+}
+
+#[test]
+fn status_overrides_skip_unknown_categories() {
+    let raw = r#"
+Degraded = "on-fire"
+Drained = "healthy"
+"#;
+    let overrides = parse_status_overrides(raw, Path::new("status_overrides.toml"));
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].pattern, "Drained");
+}
+
+#[test]
+fn malformed_status_overrides_are_empty() {
+    assert!(parse_status_overrides("Degraded = ", Path::new("bad.toml")).is_empty());
+    assert!(parse_status_overrides("", Path::new("empty.toml")).is_empty());
+}
@@ -0,0 +1,126 @@
+//! Tests for kuberift::k8s::discovery — curated CRD status extractors.
+
+use kube::api::DynamicObject;
+use kuberift::items::StatusHealth;
+use kuberift::k8s::discovery::{
+    builtin_api, cross_reference, parse_quantity, render_api_resources, vpa_status, ApiSupport,
+    ServedResource, VPA_GROUP, VPA_KIND, VPA_VERSION,
+};
+use kuberift::k8s::resources::ALL_KINDS;
+use serde_json::json;
+
+fn vpa(status: serde_json::Value) -> DynamicObject {
+    serde_json::from_value(json!({
+        "apiVersion": format!("{VPA_GROUP}/{VPA_VERSION}"),
+        "kind": VPA_KIND,
+        "metadata": { "name": "api", "namespace": "prod" },
+        "status": status,
+    }))
+    .unwrap()
+}
+
+// ── vpa_status ────────────────────────────────────────────────────────────────
+
+#[test]
+fn vpa_status_summarises_recommendation() {
+    let obj = vpa(json!({
+        "recommendation": { "containerRecommendations": [{
+            "containerName": "api",
+            "target": { "cpu": "250m", "memory": "256Mi" },
+            "uncappedTarget": { "cpu": "250m", "memory": "256Mi" },
+        }]}
+    }));
+    let status = vpa_status(&obj);
+    assert_eq!(status, "rec cpu=250m mem=256Mi");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn vpa_status_flags_undersized_as_warning() {
+    let obj = vpa(json!({
+        "recommendation": { "containerRecommendations": [{
+            "containerName": "api",
+            "target": { "cpu": "500m", "memory": "256Mi" },
+            "uncappedTarget": { "cpu": "2", "memory": "256Mi" },
+        }]}
+    }));
+    let status = vpa_status(&obj);
+    assert_eq!(status, "Undersized(cpu 2>500m)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+#[test]
+fn vpa_status_pending_without_recommendation() {
+    assert_eq!(vpa_status(&vpa(json!({}))), "Pending");
+}
+
+// ── parse_quantity ────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_quantity_units() {
+    assert_eq!(parse_quantity("250m"), Some(0.25));
+    assert_eq!(parse_quantity("2"), Some(2.0));
+    assert_eq!(parse_quantity("1Ki"), Some(1024.0));
+    assert_eq!(parse_quantity("1G"), Some(1e9));
+    assert_eq!(parse_quantity("lots"), None);
+}
+
+// ── --api-resources ───────────────────────────────────────────────────────────
+
+fn served(group: &str, version: &str, kind: &str, plural: &str) -> ServedResource {
+    ServedResource {
+        group: group.to_string(),
+        version: version.to_string(),
+        kind: kind.to_string(),
+        plural: plural.to_string(),
+    }
+}
+
+#[test]
+fn builtin_api_covers_every_watched_kind() {
+    for kind in ALL_KINDS {
+        assert!(builtin_api(kind).is_some(), "{kind:?}");
+    }
+}
+
+#[test]
+fn cross_reference_marks_served_and_missing_kinds() {
+    let rows = cross_reference(&[
+        served("", "v1", "Pod", "pods"),
+        served("apps", "v1", "Deployment", "deployments"),
+        served("cert-manager.io", "v1", "Certificate", "certificates"),
+    ]);
+    let row = |name: &str| rows.iter().find(|r| r.name == name).unwrap();
+    assert_eq!(row("pod").api_version.as_deref(), Some("v1"));
+    assert_eq!(row("deploy").api_version.as_deref(), Some("apps/v1"));
+    assert_eq!(row("ing").api_version, None);
+    assert_eq!(
+        rows.last().unwrap(),
+        &ApiSupport {
+            name: "certificates".to_string(),
+            api_version: Some("cert-manager.io/v1".to_string()),
+            custom: true,
+        }
+    );
+    assert_eq!(rows.len(), ALL_KINDS.len() + 1);
+}
+
+#[test]
+fn render_api_resources_aligns_columns() {
+    let table = render_api_resources(&[
+        ApiSupport {
+            name: "pod".to_string(),
+            api_version: Some("v1".to_string()),
+            custom: false,
+        },
+        ApiSupport {
+            name: "ing".to_string(),
+            api_version: None,
+            custom: false,
+        },
+    ]);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines[0], "KIND  API VERSION  STATUS");
+    assert_eq!(lines[1], "pod   v1           served");
+    assert_eq!(lines[2], "ing   -            not served");
+}
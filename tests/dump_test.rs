@@ -0,0 +1,159 @@
+//! Tests for kuberift::k8s::dump — `--watch-file` parsing and reloads.
+
+use kuberift::items::{K8sItem, ResourceKind};
+use kuberift::k8s::dump::{parse_dump, DumpState};
+use kuberift::k8s::resources::ALL_KINDS;
+use serde_json::json;
+
+fn pod(name: &str, phase: &str) -> serde_json::Value {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "name": name, "namespace": "default", "labels": { "app": "web" } },
+        "status": { "phase": phase },
+    })
+}
+
+fn list(items: &[serde_json::Value]) -> String {
+    json!({ "apiVersion": "v1", "kind": "List", "items": items }).to_string()
+}
+
+// ── parse_dump ────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_dump_reads_a_list() {
+    let node = json!({ "apiVersion": "v1", "kind": "Node", "metadata": { "name": "worker-1" } });
+    let entries = parse_dump(&list(&[pod("web-0", "Running"), node]), ALL_KINDS).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].kind, ResourceKind::Pod);
+    assert_eq!(entries[0].namespace, "default");
+    assert_eq!(entries[0].name, "web-0");
+    assert_eq!(entries[0].state.status, "Running");
+    assert_eq!(
+        entries[0].state.labels,
+        [("app".to_string(), "web".to_string())]
+    );
+    assert_eq!(entries[1].kind, ResourceKind::Node);
+    assert!(entries[1].namespace.is_empty());
+}
+
+#[test]
+fn parse_dump_reads_a_single_object() {
+    let entries = parse_dump(&pod("web-0", "Pending").to_string(), ALL_KINDS).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].state.status, "Pending");
+}
+
+#[test]
+fn parse_dump_keeps_requested_kinds_only() {
+    let custom =
+        json!({ "apiVersion": "example.com/v1", "kind": "Widget", "metadata": { "name": "w" } });
+    let svc = json!({ "apiVersion": "v1", "kind": "Service", "metadata": { "name": "web", "namespace": "default" } });
+    let entries = parse_dump(
+        &list(&[pod("web-0", "Running"), svc, custom]),
+        &[ResourceKind::Service],
+    )
+    .unwrap();
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["web"]);
+}
+
+#[test]
+fn unknown_kind_renders_with_its_own_kind_name() {
+    let widget = json!({
+        "apiVersion": "example.com/v1",
+        "kind": "Widget",
+        "metadata": { "name": "w", "namespace": "default" },
+        "status": { "phase": "Spinning" },
+    });
+    let entries = parse_dump(&list(&[widget]), ALL_KINDS).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].kind, ResourceKind::Custom("Widget".to_string()));
+    assert_eq!(entries[0].state.status, "Spinning");
+
+    let items = DumpState::new().apply(entries, "");
+    assert_eq!(items[0].kind().to_string(), "Widget");
+    assert_eq!(items[0].output_str(), "Widget/default/w");
+}
+
+#[test]
+fn unknown_kind_without_phase_shows_a_dash() {
+    let rs = json!({
+        "apiVersion": "apps/v1",
+        "kind": "ReplicaSet",
+        "metadata": { "name": "web-7d4f", "namespace": "ci" },
+    });
+    let entries = parse_dump(&rs.to_string(), ALL_KINDS).unwrap();
+    assert_eq!(
+        entries[0].kind,
+        ResourceKind::Custom("ReplicaSet".to_string())
+    );
+    assert_eq!(entries[0].state.status, "-");
+}
+
+#[test]
+fn unknown_kind_kept_when_asked_for_by_plural() {
+    let widget =
+        json!({ "apiVersion": "example.com/v1", "kind": "Widget", "metadata": { "name": "w" } });
+    let text = list(&[pod("web-0", "Running"), widget]);
+    let entries = parse_dump(&text, &[ResourceKind::Custom("widgets".to_string())]).unwrap();
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["w"]);
+}
+
+#[test]
+fn parse_dump_rejects_truncated_json() {
+    let full = list(&[pod("web-0", "Running")]);
+    assert!(parse_dump(&full[..full.len() / 2], ALL_KINDS).is_err());
+    assert!(parse_dump("[]", ALL_KINDS).is_err());
+}
+
+// ── DumpState (reloads) ───────────────────────────────────────────────────────
+
+#[test]
+fn reparsing_a_changed_file_updates_items() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dump.json");
+    let mut state = DumpState::new();
+
+    std::fs::write(
+        &path,
+        list(&[pod("web-0", "Pending"), pod("web-1", "Running")]),
+    )
+    .unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    let first: Vec<K8sItem> = state.apply(parse_dump(&text, ALL_KINDS).unwrap(), "");
+    assert_eq!(first.len(), 2);
+
+    // web-0 starts running, web-1 is gone, web-2 is new.
+    std::fs::write(
+        &path,
+        list(&[pod("web-0", "Running"), pod("web-2", "Failed")]),
+    )
+    .unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    let added = state.apply(parse_dump(&text, ALL_KINDS).unwrap(), "");
+
+    assert_eq!(
+        first[0].status(),
+        "Running",
+        "existing row updated in place"
+    );
+    assert_eq!(first[1].status(), "[DELETED]");
+    assert_eq!(added.len(), 1);
+    assert_eq!(added[0].name(), "web-2");
+    assert_eq!(added[0].status(), "Failed");
+}
+
+#[test]
+fn reload_with_same_content_adds_nothing() {
+    let text = list(&[pod("web-0", "Running")]);
+    let mut state = DumpState::new();
+    assert_eq!(
+        state.apply(parse_dump(&text, ALL_KINDS).unwrap(), "").len(),
+        1
+    );
+    assert!(state
+        .apply(parse_dump(&text, ALL_KINDS).unwrap(), "")
+        .is_empty());
+}
@@ -1,9 +1,17 @@
 //! Tests for kuberift::items — StatusHealth, ResourceKind, K8sItem, and helpers.
 
 use kuberift::items::{
-    context_color, truncate_name, K8sItem, ResourceKind, SortField, StatusHealth,
+    abbreviate_status, color_legend, context_color, count_matching, demo_items,
+    describe_events_args, detail_line, events_args, format_age, invalidate_list_width,
+    kind_visible, label_chips, line_to_ansi, list_width, owned_by, related_summary, restart_count,
+    set_list_width_source, summarize, theme_preview, toggle_hidden, truncate_name,
+    without_crash_note, wrap_preview, AgeFormat, Column, Columns, K8sItem, OutputFormat,
+    ResourceKind, SortField, StatusHealth, Theme, RESTART_WARNING_THRESHOLD,
 };
+use kuberift::k8s::resources::ALL_KINDS;
 use ratatui::style::Color;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // ── Helper ────────────────────────────────────────────────────────────────────
 
@@ -29,6 +37,11 @@ fn kind_as_str_all_variants() {
     assert_eq!(ResourceKind::Job.as_str(), "job");
     assert_eq!(ResourceKind::CronJob.as_str(), "cronjob");
     assert_eq!(ResourceKind::Event.as_str(), "event");
+    assert_eq!(ResourceKind::HorizontalPodAutoscaler.as_str(), "hpa");
+    assert_eq!(ResourceKind::Endpoints.as_str(), "ep");
+    assert_eq!(ResourceKind::ServiceAccount.as_str(), "sa");
+    assert_eq!(ResourceKind::Role.as_str(), "role");
+    assert_eq!(ResourceKind::RoleBinding.as_str(), "rolebinding");
 }
 
 // ── ResourceKind::color ───────────────────────────────────────────────────────
@@ -83,6 +96,92 @@ fn kind_display_matches_as_str_for_all_variants() {
     }
 }
 
+// ── ResourceKind::is_cluster_scoped ───────────────────────────────────────────
+
+#[test]
+fn cluster_scoped_kinds() {
+    for kind in ALL_KINDS {
+        let expected = matches!(
+            kind,
+            ResourceKind::Node | ResourceKind::Namespace | ResourceKind::PersistentVolume
+        );
+        assert_eq!(kind.is_cluster_scoped(), expected, "{kind:?}");
+    }
+    assert!(!ResourceKind::Custom("widgets".to_string()).is_cluster_scoped());
+}
+
+// ── color_legend (--help-colors) ──────────────────────────────────────────────
+
+#[test]
+fn color_legend_mentions_every_kind_and_health_tier() {
+    let legend = color_legend(false);
+    for kind in ALL_KINDS {
+        assert!(
+            legend.contains(kind.as_str()),
+            "legend must mention '{}'",
+            kind.as_str()
+        );
+    }
+    for health in StatusHealth::ALL {
+        assert!(legend.contains(health.as_str()));
+    }
+    assert!(legend.contains("CrashLoopBackOff"));
+    assert!(
+        !legend.contains('\x1b'),
+        "plain legend must not contain ANSI codes"
+    );
+}
+
+#[test]
+fn color_legend_ansi_wraps_labels_in_escape_codes() {
+    let legend = color_legend(true);
+    assert!(legend.contains("\x1b[32mpod"), "pod label should be green");
+    assert!(
+        legend.contains("\x1b[31mcritical"),
+        "critical label should be red"
+    );
+}
+
+// ── theme preview (--theme-preview) ───────────────────────────────────────────
+
+#[test]
+fn theme_preview_has_a_row_for_every_kind() {
+    let preview = theme_preview(false);
+    for kind in ALL_KINDS {
+        assert!(
+            preview
+                .lines()
+                .any(|line| line.split_whitespace().next() == Some(kind.as_str())),
+            "preview must show a '{}' row",
+            kind.as_str()
+        );
+    }
+    assert!(preview.contains("Certificate"), "and a custom resource");
+    assert!(!preview.contains('\x1b'));
+}
+
+#[test]
+fn demo_items_cover_every_health_tier() {
+    let tiers: HashSet<&str> = demo_items()
+        .iter()
+        .map(|item| StatusHealth::classify(&item.status()).as_str())
+        .collect();
+    for health in StatusHealth::ALL {
+        assert!(tiers.contains(health.as_str()), "{health:?}");
+    }
+}
+
+#[test]
+fn line_to_ansi_colors_each_span() {
+    use ratatui::text::{Line, Span};
+    let line = Line::from(vec![
+        Span::styled("pod ", ratatui::style::Style::default().fg(Color::Green)),
+        Span::raw("web"),
+    ]);
+    assert_eq!(line_to_ansi(&line, true), "\x1b[32mpod \x1b[0mweb");
+    assert_eq!(line_to_ansi(&line, false), "pod web");
+}
+
 // ── StatusHealth::classify — critical exact ───────────────────────────────────
 
 #[test]
@@ -152,6 +251,18 @@ fn classify_warning_statuses() {
     }
 }
 
+#[test]
+fn classify_node_status_with_role_and_version() {
+    let cases = [
+        ("Ready (control-plane, v1.29.2)", StatusHealth::Healthy),
+        ("NotReady (worker, v1.29.2)", StatusHealth::Critical),
+        ("Unknown (v1.29.2)", StatusHealth::Warning),
+    ];
+    for (status, health) in cases {
+        assert_eq!(StatusHealth::classify(status), health, "{status}");
+    }
+}
+
 // ── StatusHealth::classify — deleted ─────────────────────────────────────────
 
 #[test]
@@ -200,6 +311,18 @@ fn classify_ratio_unequal_is_warning() {
     assert_eq!(StatusHealth::classify("2/3"), StatusHealth::Warning);
 }
 
+#[test]
+fn classify_ratio_ignores_detail_after_counts() {
+    assert_eq!(
+        StatusHealth::classify("5/5 (cpu 45%)"),
+        StatusHealth::Healthy
+    );
+    assert_eq!(
+        StatusHealth::classify("3/5 (cpu 80%)"),
+        StatusHealth::Warning
+    );
+}
+
 // ── StatusHealth::classify — Active prefix ────────────────────────────────────
 
 #[test]
@@ -219,6 +342,133 @@ fn classify_unknown_status_defaults_to_healthy() {
     assert_eq!(StatusHealth::classify(""), StatusHealth::Healthy);
 }
 
+// ── StatusHealth::classify — Running with restarts ───────────────────────────
+
+#[test]
+fn running_with_few_restarts_stays_healthy() {
+    for n in [1, RESTART_WARNING_THRESHOLD] {
+        let status = format!("Running (x{n})");
+        assert_eq!(
+            StatusHealth::classify(&status),
+            StatusHealth::Healthy,
+            "{status}"
+        );
+    }
+}
+
+#[test]
+fn running_above_restart_threshold_is_warning() {
+    for n in [RESTART_WARNING_THRESHOLD + 1, 12] {
+        let status = format!("Running (x{n})");
+        assert_eq!(
+            StatusHealth::classify(&status),
+            StatusHealth::Warning,
+            "{status}"
+        );
+    }
+}
+
+#[test]
+fn restart_count_parses_the_suffix() {
+    assert_eq!(restart_count("Running (x12)"), Some(12));
+    assert_eq!(restart_count("CrashLoopBackOff (x7)"), Some(7));
+    assert_eq!(restart_count("Running"), None);
+    assert_eq!(restart_count("Running (debug)"), None);
+    assert_eq!(restart_count("Running (x)"), None);
+}
+
+#[test]
+fn crash_note_is_read_apart_from_the_status_it_follows() {
+    assert_eq!(
+        without_crash_note("Running (x12) (restarted 2m ago)"),
+        Some("Running (x12)")
+    );
+    assert_eq!(
+        without_crash_note("Running (debug) (restarted 30s ago)"),
+        Some("Running (debug)")
+    );
+    assert_eq!(without_crash_note("Running (x12)"), None);
+    for status in [
+        "Running (restarted 2m ago)",
+        "Running (debug) (restarted 2m ago)",
+        "Running (x12) (restarted 2m ago)",
+    ] {
+        assert_eq!(
+            StatusHealth::classify(status),
+            StatusHealth::Warning,
+            "{status}"
+        );
+    }
+}
+
+// ── StatusHealth::classify — status_overrides.toml ───────────────────────────
+
+fn overrides() -> Vec<StatusOverride> {
+    vec![
+        StatusOverride::new("Degraded", "critical").unwrap(),
+        StatusOverride::new("Sync*", "warning").unwrap(),
+        StatusOverride::new("SyncOK", "healthy").unwrap(),
+        StatusOverride::new("Pending", "healthy").unwrap(),
+    ]
+}
+
+#[test]
+fn override_reclassifies_custom_status_as_critical() {
+    assert_eq!(StatusHealth::classify("Degraded"), StatusHealth::Healthy);
+    assert_eq!(
+        StatusHealth::classify_with("Degraded", &overrides()),
+        StatusHealth::Critical
+    );
+    assert_eq!(
+        StatusHealth::classify_with("Degraded (x3)", &overrides()),
+        StatusHealth::Critical,
+        "restart count suffix is ignored"
+    );
+}
+
+#[test]
+fn override_prefix_and_exact_precedence() {
+    assert_eq!(
+        StatusHealth::classify_with("SyncFailed", &overrides()),
+        StatusHealth::Warning
+    );
+    assert_eq!(
+        StatusHealth::classify_with("SyncOK", &overrides()),
+        StatusHealth::Healthy,
+        "an exact entry beats a prefix"
+    );
+}
+
+#[test]
+fn override_can_change_a_built_in_status() {
+    assert_eq!(
+        StatusHealth::classify_with("Pending", &overrides()),
+        StatusHealth::Healthy
+    );
+}
+
+#[test]
+fn built_in_rules_apply_when_no_override_matches() {
+    for status in [
+        "CrashLoopBackOff",
+        "Running",
+        "1/3",
+        "[DELETED]",
+        "Terminating",
+    ] {
+        assert_eq!(
+            StatusHealth::classify_with(status, &overrides()),
+            StatusHealth::classify(status),
+            "{status}"
+        );
+    }
+}
+
+#[test]
+fn override_new_rejects_unknown_category() {
+    assert_eq!(StatusOverride::new("Degraded", "bad"), None);
+}
+
 // ── StatusHealth::color ───────────────────────────────────────────────────────
 
 #[test]
@@ -274,6 +524,31 @@ fn k8s_item_empty_namespace_and_context() {
     assert_eq!(item.context(), "");
 }
 
+// ── K8sItem identity equality / hash ──────────────────────────────────────────
+
+#[test]
+fn k8s_item_equal_when_only_status_and_age_differ() {
+    let a = K8sItem::new(ResourceKind::Pod, "default", "web", "Pending", "1m", "");
+    let b = K8sItem::new(ResourceKind::Pod, "default", "web", "Running", "5m", "");
+    assert_eq!(a, b, "status/age must not affect identity");
+
+    let set: HashSet<K8sItem> = [a, b].into_iter().collect();
+    assert_eq!(set.len(), 1, "equal items must hash identically");
+}
+
+#[test]
+fn k8s_item_not_equal_when_identity_differs() {
+    let base = K8sItem::new(ResourceKind::Pod, "default", "web", "Running", "1d", "");
+    let other_name = K8sItem::new(ResourceKind::Pod, "default", "api", "Running", "1d", "");
+    let other_ns = K8sItem::new(ResourceKind::Pod, "prod", "web", "Running", "1d", "");
+    let other_kind = K8sItem::new(ResourceKind::Service, "default", "web", "Running", "1d", "");
+    let other_ctx = K8sItem::new(ResourceKind::Pod, "default", "web", "Running", "1d", "prod");
+    assert_ne!(base, other_name);
+    assert_ne!(base, other_ns);
+    assert_ne!(base, other_kind);
+    assert_ne!(base, other_ctx);
+}
+
 // ── K8sItem::status_color ─────────────────────────────────────────────────────
 
 #[test]
@@ -432,3 +707,606 @@ fn sort_field_display() {
     assert_eq!(format!("{}", SortField::Health), "health");
     assert_eq!(format!("{}", SortField::Name), "name");
 }
+
+// ── count_matching (--count) ─────────────────────────────────────────────────
+
+fn count_buffer() -> Vec<K8sItem> {
+    [
+        "CrashLoopBackOff",
+        "Error",
+        "Pending",
+        "Running",
+        "Running",
+        "[DELETED]",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, status)| {
+        K8sItem::new(
+            ResourceKind::Pod,
+            "default",
+            format!("pod-{i}"),
+            *status,
+            "1m",
+            "",
+        )
+    })
+    .collect()
+}
+
+#[test]
+fn count_matching_skips_deleted() {
+    assert_eq!(count_matching(&count_buffer(), None), 5);
+}
+
+#[test]
+fn count_matching_respects_min_severity() {
+    let items = count_buffer();
+    assert_eq!(count_matching(&items, Some(StatusHealth::Critical)), 2);
+    assert_eq!(count_matching(&items, Some(StatusHealth::Warning)), 3);
+    assert_eq!(count_matching(&items, Some(StatusHealth::Healthy)), 5);
+}
+
+#[test]
+fn count_matching_empty_buffer_is_zero() {
+    assert_eq!(count_matching(&[], Some(StatusHealth::Critical)), 0);
+}
+
+// ── summarize (loaded-list summary) ──────────────────────────────────────────
+
+#[test]
+fn summarize_counts_rows_critical_and_warning() {
+    // Deleted rows are still rows in the list.
+    assert_eq!(summarize(&count_buffer()), (6, 2, 1));
+}
+
+#[test]
+fn summarize_empty_buffer_is_all_zero() {
+    assert_eq!(summarize(&[]), (0, 0, 0));
+}
+
+// ── related_summary (related-resources preview) ──────────────────────────────
+
+#[test]
+fn related_summary_pod_with_owner_queries_owner_and_siblings() {
+    let pod = K8sItem::new(
+        ResourceKind::Pod,
+        "prod",
+        "api-7d9f8b6c5-xk2lp",
+        "Running",
+        "1h",
+        "",
+    );
+    {
+        let mut state = pod.state().write().unwrap();
+        state.owner = "ReplicaSet/api-7d9f8b6c5".to_string();
+        state.controlled = true;
+    }
+
+    let queries = related_summary(&pod);
+    let labels: Vec<&str> = queries.iter().map(|(label, ..)| *label).collect();
+    assert_eq!(labels, ["owner", "siblings"]);
+
+    let owner_args = &queries[0].1;
+    assert_eq!(
+        &owner_args[..5],
+        ["get", "replicaset", "api-7d9f8b6c5", "-n", "prod"]
+    );
+
+    assert!(
+        owner_args[6].contains("@.controller==true"),
+        "{}",
+        owner_args[6]
+    );
+
+    let (_, sibling_args, sibling_owner) = &queries[1];
+    assert_eq!(&sibling_args[..4], ["get", "pods", "-n", "prod"]);
+    assert!(
+        sibling_args[5].contains("@.controller==true"),
+        "siblings must list the controlling owner: {}",
+        sibling_args[5]
+    );
+    assert_eq!(sibling_owner.as_deref(), Some("ReplicaSet/api-7d9f8b6c5"));
+}
+
+#[test]
+fn related_summary_pod_without_a_controller_has_no_siblings() {
+    let pod = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    pod.state().write().unwrap().owner = "ConfigMap/api".to_string();
+    let labels: Vec<&str> = related_summary(&pod)
+        .iter()
+        .map(|(label, ..)| *label)
+        .collect();
+    assert_eq!(labels, ["owner"]);
+}
+
+#[test]
+fn related_summary_bare_pod_has_no_queries() {
+    let pod = K8sItem::new(ResourceKind::Pod, "prod", "debug", "Running", "1m", "");
+    assert!(related_summary(&pod).is_empty());
+}
+
+#[test]
+fn related_summary_deployment_lists_replicasets() {
+    let deploy = K8sItem::new(ResourceKind::Deployment, "prod", "api", "3/3", "1d", "");
+    let queries = related_summary(&deploy);
+    assert_eq!(queries.len(), 1);
+    assert_eq!(queries[0].0, "replicasets");
+    assert_eq!(&queries[0].1[..2], ["get", "replicasets"]);
+    assert_eq!(queries[0].2.as_deref(), Some("Deployment/api"));
+}
+
+#[test]
+fn owned_by_matches_the_controllers_kind_and_name() {
+    let listing = "api-0\tStatefulSet/api\n\
+                   api-job-x\tJob/api\n\
+                   web-0\tStatefulSet/web\n\
+                   debug\t";
+    assert_eq!(owned_by(listing, "StatefulSet/api"), "api-0");
+    assert_eq!(owned_by(listing, "Job/api"), "api-job-x");
+    assert_eq!(owned_by(listing, "Deployment/api"), "");
+}
+
+// ── kind visibility (alt-k) ──────────────────────────────────────────────────
+
+#[test]
+fn kind_visible_when_not_hidden() {
+    let hidden: HashSet<ResourceKind> = [ResourceKind::Secret].into_iter().collect();
+    assert!(kind_visible(&ResourceKind::Pod, &hidden));
+    assert!(!kind_visible(&ResourceKind::Secret, &hidden));
+    assert!(kind_visible(&ResourceKind::Secret, &HashSet::new()));
+}
+
+#[test]
+fn toggle_hidden_flips_each_kind() {
+    let mut hidden: HashSet<ResourceKind> = [ResourceKind::Secret].into_iter().collect();
+    toggle_hidden(&mut hidden, &[ResourceKind::Secret, ResourceKind::Event]);
+    assert!(kind_visible(&ResourceKind::Secret, &hidden));
+    assert!(!kind_visible(&ResourceKind::Event, &hidden));
+}
+
+// ── detail_line (preview header) ─────────────────────────────────────────────
+
+#[test]
+fn detail_line_only_when_there_is_a_detail() {
+    assert_eq!(
+        detail_line("Back-off pulling image"),
+        "⚠ Back-off pulling image\n"
+    );
+    assert_eq!(detail_line(""), "");
+}
+
+// ── wrap_preview ──────────────────────────────────────────────────────────────
+
+#[test]
+fn wrap_preview_breaks_long_line_at_word_boundaries() {
+    let text = "Labels: app=nginx tier=frontend env=production team=payments";
+    let wrapped = wrap_preview(text, 24);
+    for line in wrapped.lines() {
+        assert!(line.chars().count() <= 24, "line too long: {line:?}");
+    }
+    assert_eq!(
+        wrapped,
+        "Labels: app=nginx\ntier=frontend\nenv=production\nteam=payments\n"
+    );
+}
+
+#[test]
+fn wrap_preview_keeps_indent_on_continuation_lines() {
+    let wrapped = wrap_preview("    Image: registry.example.com/team/api:1.2.3 pulled", 30);
+    let lines: Vec<&str> = wrapped.lines().collect();
+    assert!(lines.len() > 1, "{wrapped:?}");
+    assert!(lines.iter().all(|l| l.starts_with("    ")), "{wrapped:?}");
+    assert!(lines.iter().all(|l| l.chars().count() <= 30), "{wrapped:?}");
+}
+
+#[test]
+fn wrap_preview_expands_tabs_and_leaves_short_lines() {
+    assert_eq!(wrap_preview("Name:\tapi", 80), "Name:   api\n");
+    assert_eq!(wrap_preview("short", 80), "short\n");
+}
+
+#[test]
+fn wrap_preview_zero_width_does_not_wrap() {
+    let long = "x ".repeat(100);
+    assert_eq!(wrap_preview(&long, 0).lines().count(), 1);
+}
+
+// ── Theme presets ─────────────────────────────────────────────────────────────
+
+#[test]
+fn colorblind_theme_avoids_red_and_green() {
+    let theme = Theme::COLORBLIND;
+    let critical = theme.health_color(StatusHealth::Critical);
+    let healthy = theme.health_color(StatusHealth::Healthy);
+    assert!(!matches!(critical, Color::Red | Color::LightRed));
+    assert!(!matches!(healthy, Color::Green | Color::LightGreen));
+    assert_ne!(critical, healthy);
+}
+
+#[test]
+fn light_theme_avoids_yellow_warning() {
+    let warning = Theme::LIGHT.health_color(StatusHealth::Warning);
+    assert!(!matches!(warning, Color::Yellow | Color::LightYellow));
+    assert_ne!(
+        Theme::LIGHT.kind_color(&ResourceKind::Deployment),
+        Color::Yellow
+    );
+}
+
+#[test]
+fn default_theme_matches_builtin_colors() {
+    assert_eq!(Theme::default(), Theme::DARK);
+    assert_eq!(Theme::DARK.kind_color(&ResourceKind::Pod), Color::Green);
+    assert_eq!(Theme::DARK.health_color(StatusHealth::Critical), Color::Red);
+}
+
+#[test]
+fn theme_preset_lookup() {
+    assert_eq!(Theme::preset("colorblind"), Some(Theme::COLORBLIND));
+    assert_eq!(Theme::preset("High-Contrast"), Some(Theme::HIGH_CONTRAST));
+    assert_eq!(Theme::preset("solarized"), None);
+}
+
+// ── events_args (preview mode 3) ──────────────────────────────────────────────
+
+#[test]
+fn events_args_for_namespaced_item() {
+    let item = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Pending", "1m", "east");
+    assert_eq!(
+        events_args(&item),
+        [
+            "get",
+            "events",
+            "--field-selector",
+            "involvedObject.name=api-0,involvedObject.kind=Pod",
+            "-n",
+            "prod",
+            "--context",
+            "east",
+        ]
+    );
+}
+
+#[test]
+fn events_args_for_cluster_scoped_item() {
+    let item = K8sItem::new(ResourceKind::Node, "", "node-1", "NotReady", "9d", "");
+    assert_eq!(
+        events_args(&item),
+        [
+            "get",
+            "events",
+            "--field-selector",
+            "involvedObject.name=node-1,involvedObject.kind=Node",
+        ]
+    );
+}
+
+// ── describe_events_args ──────────────────────────────────────────────────────
+
+#[test]
+fn describe_events_args_for_crashing_pod() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "prod",
+        "api-0",
+        "CrashLoopBackOff",
+        "1h",
+        "east",
+    );
+    let args = describe_events_args(&item).expect("critical pod gets events");
+    assert_eq!(
+        args,
+        [
+            "get",
+            "events",
+            "--field-selector",
+            "involvedObject.name=api-0,involvedObject.kind=Pod",
+            "-n",
+            "prod",
+            "--context",
+            "east",
+            "--sort-by=.lastTimestamp",
+        ]
+    );
+}
+
+#[test]
+fn describe_events_args_skips_healthy_and_other_kinds() {
+    let healthy = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    assert!(describe_events_args(&healthy).is_none());
+    let degraded = K8sItem::new(ResourceKind::Deployment, "prod", "api", "1/3", "1h", "");
+    assert!(describe_events_args(&degraded).is_some());
+    let svc = K8sItem::new(ResourceKind::Service, "prod", "api", "Pending", "1h", "");
+    assert!(describe_events_args(&svc).is_none());
+}
+
+// ── list width (terminal resize) ──────────────────────────────────────────────
+
+/// Width the fake terminal reports, and how often it was asked.
+static FAKE_TERMINAL_WIDTH: AtomicUsize = AtomicUsize::new(80);
+static WIDTH_READS: AtomicUsize = AtomicUsize::new(0);
+
+fn fake_terminal_width() -> Option<usize> {
+    WIDTH_READS.fetch_add(1, Ordering::SeqCst);
+    Some(FAKE_TERMINAL_WIDTH.load(Ordering::SeqCst))
+}
+
+#[test]
+fn list_width_is_reread_only_after_invalidation() {
+    set_list_width_source(fake_terminal_width);
+    assert_eq!(list_width(), Some(80));
+    let reads = WIDTH_READS.load(Ordering::SeqCst);
+
+    // Resized, but nobody said so yet: the cached width stands.
+    FAKE_TERMINAL_WIDTH.store(120, Ordering::SeqCst);
+    assert_eq!(list_width(), Some(80));
+    assert_eq!(WIDTH_READS.load(Ordering::SeqCst), reads);
+
+    invalidate_list_width();
+    assert_eq!(list_width(), Some(120));
+    assert_eq!(list_width(), Some(120));
+    assert_eq!(WIDTH_READS.load(Ordering::SeqCst), reads + 1);
+}
+
+// ── row_line (width fitting) ──────────────────────────────────────────────────
+
+fn row_text(line: &ratatui::text::Line<'_>) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+#[test]
+fn row_line_unfitted_keeps_every_column() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "prod",
+        "api-0",
+        "CrashLoopBackOff",
+        "2d",
+        "",
+    );
+    let text = row_text(&item.row_line(None));
+    assert!(text.starts_with("pod      prod/api-0"));
+    assert!(text.contains("CrashLoopBackOff"));
+    assert!(text.ends_with("2d"));
+}
+
+#[test]
+fn row_line_narrow_width_fits_and_keeps_kind_and_name() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "production",
+        "api-server-7d9f8b6c5-x2x9z",
+        "CrashLoopBackOff",
+        "2d",
+        "",
+    );
+    for width in [60, 40, 30, 20] {
+        let line = item.row_line(Some(width));
+        assert!(line.width() <= width, "{width}: {:?}", row_text(&line));
+        let text = row_text(&line);
+        assert!(text.starts_with("pod"), "{width}: {text}");
+        assert!(text.contains("api-"), "{width}: {text}");
+    }
+}
+
+#[test]
+fn row_line_narrow_width_measures_wide_characters() {
+    let item = K8sItem::new(
+        ResourceKind::Pod,
+        "生产",
+        "接口服务-数据库-0",
+        "CrashLoopBackOff",
+        "2d",
+        "",
+    );
+    for width in [60, 40, 30, 20] {
+        let line = item.row_line(Some(width));
+        assert!(line.width() <= width, "{width}: {:?}", row_text(&line));
+        let text = row_text(&line);
+        assert!(text.starts_with("pod"), "{width}: {text}");
+        assert!(text.contains("接口"), "{width}: {text}");
+    }
+}
+
+#[test]
+fn row_line_drops_age_before_status() {
+    let item = K8sItem::new(ResourceKind::Pod, "ns", "api", "CrashLoopBackOff", "2d", "");
+    let full = item.row_line(None).width();
+    let text = row_text(&item.row_line(Some(full - 1)));
+    assert!(!text.contains("2d"), "{text}");
+    assert!(text.contains("CrashLoopBackOff"), "{text}");
+}
+
+#[test]
+fn row_line_wide_enough_is_unchanged() {
+    let item = K8sItem::new(ResourceKind::Service, "ns", "api", "ClusterIP", "5h", "");
+    assert_eq!(
+        row_text(&item.row_line(Some(200))),
+        row_text(&item.row_line(None))
+    );
+}
+
+// ── --columns ─────────────────────────────────────────────────────────────────
+
+fn crashing_pod() -> K8sItem {
+    K8sItem::new(ResourceKind::Pod, "ns", "api", "CrashLoopBackOff", "2d", "")
+}
+
+#[test]
+fn row_line_without_age_column_has_no_age_span() {
+    use Column::{Kind, Name, Namespace, Status};
+    let item = crashing_pod();
+    let columns = Columns::new(&[Kind, Namespace, Name, Status]).unwrap();
+    let line = item.row_line_with(&columns, None);
+    assert!(line.spans.iter().all(|span| span.content.trim() != "2d"));
+    assert!(row_text(&line).contains("CrashLoopBackOff"));
+    assert!(row_text(&item.row_line_with(&Columns::default(), None)).contains("2d"));
+}
+
+#[test]
+fn row_line_follows_column_order() {
+    use Column::{Age, Name, Status};
+    let item = crashing_pod();
+    let columns = Columns::new(&[Status, Name, Age]).unwrap();
+    let text = row_text(&item.row_line_with(&columns, None));
+    assert!(text.starts_with("CrashLoopBackOff"), "{text}");
+    let name_at = text.find("api").unwrap();
+    assert!(name_at < text.find("2d").unwrap(), "{text}");
+    assert!(!text.contains("pod"), "kind column hidden: {text}");
+    assert!(!text.contains("ns/"), "namespace column hidden: {text}");
+}
+
+#[test]
+fn text_with_default_columns_reads_like_the_row() {
+    let text = crashing_pod().text_with(&Columns::default());
+    assert_eq!(text, "pod      ns/api CrashLoopBackOff 2d");
+}
+
+#[test]
+fn text_with_searches_only_shown_columns_in_order() {
+    use Column::{Name, Namespace, Status};
+    let item = crashing_pod();
+    assert_eq!(
+        item.text_with(&Columns::new(&[Name, Namespace]).unwrap()),
+        "api ns"
+    );
+    assert_eq!(
+        item.text_with(&Columns::new(&[Status, Name]).unwrap()),
+        "CrashLoopBackOff api"
+    );
+}
+
+#[test]
+fn columns_must_be_non_empty_and_distinct() {
+    assert!(Columns::new(&[]).is_none());
+    assert!(Columns::new(&[Column::Name, Column::Name]).is_none());
+    assert_eq!(Columns::default().order(), Column::ALL);
+}
+
+// ── format_age (--age-format) ─────────────────────────────────────────────────
+
+/// 2024-05-01T12:03:00Z
+const CREATED: i64 = 1_714_564_980;
+
+#[test]
+fn format_age_iso_is_utc_rfc3339() {
+    assert_eq!(format_age(CREATED, AgeFormat::Iso), "2024-05-01T12:03:00Z");
+}
+
+#[test]
+fn format_age_absolute_is_local_date_and_minute() {
+    let text = format_age(CREATED, AgeFormat::Absolute);
+    assert_eq!(text.len(), "2024-05-01 12:03".len(), "{text}");
+    assert!(text.starts_with("2024-05-0"), "{text}");
+    assert_eq!(&text[10..11], " ");
+    assert_eq!(&text[13..14], ":");
+}
+
+#[test]
+fn format_age_relative_counts_years() {
+    // Created long ago: shown in years (and months).
+    let text = format_age(CREATED, AgeFormat::Relative);
+    let (years, rest) = text.split_once('y').unwrap_or_else(|| panic!("{text}"));
+    assert!(years.parse::<u64>().unwrap() >= 2, "{text}");
+    assert!(rest.is_empty() || rest.ends_with("mo"), "{text}");
+}
+
+#[test]
+fn format_age_out_of_range_is_unknown() {
+    assert_eq!(format_age(i64::MAX, AgeFormat::Iso), "?");
+}
+
+// ── label_chips (--show-labels) ───────────────────────────────────────────────
+
+fn labels(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+        .collect()
+}
+
+#[test]
+fn label_chips_render_each_label() {
+    let l = labels(&[("app", "nginx"), ("tier", "web")]);
+    assert_eq!(label_chips(&l, 3), "[app=nginx] [tier=web]");
+}
+
+#[test]
+fn label_chips_count_the_overflow() {
+    let l = labels(&[("a", "1"), ("b", "2"), ("c", "3"), ("d", "4"), ("e", "5")]);
+    assert_eq!(label_chips(&l, 3), "[a=1] [b=2] [c=3] +2");
+}
+
+#[test]
+fn label_chips_empty_without_labels() {
+    assert_eq!(label_chips(&[], 3), "");
+}
+
+// ── --output json ─────────────────────────────────────────────────────────────
+
+#[test]
+fn items_serialize_to_the_output_json_shape() {
+    let items = [
+        K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "2d", ""),
+        K8sItem::new(ResourceKind::Node, "", "worker-1", "Ready", "30d", "prod"),
+    ];
+    let json = serde_json::to_value(&items).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            {
+                "kind": "pod",
+                "namespace": "default",
+                "name": "web-0",
+                "status": "Running",
+                "age": "2d",
+                "context": "",
+            },
+            {
+                "kind": "node",
+                "namespace": "",
+                "name": "worker-1",
+                "status": "Ready",
+                "age": "30d",
+                "context": "prod",
+            },
+        ])
+    );
+}
+
+#[test]
+fn output_format_names() {
+    let names: Vec<&str> = OutputFormat::ALL.iter().map(|f| f.as_str()).collect();
+    assert_eq!(names, ["text", "json", "grouped-json"]);
+    assert_eq!(OutputFormat::default(), OutputFormat::Text);
+}
+
+// ── abbreviate_status (--compact-status) ──────────────────────────────────────
+
+#[test]
+fn abbreviate_status_shortens_known_statuses() {
+    for (long, short) in [
+        ("kubernetes.io/service-account-token", "sa-token"),
+        ("LoadBalancer", "LB"),
+        ("ContainerCreating", "Creating"),
+        ("kubernetes.io/tls", "tls"),
+    ] {
+        assert_eq!(abbreviate_status(long), short);
+    }
+}
+
+#[test]
+fn abbreviate_status_keeps_the_restart_count() {
+    assert_eq!(abbreviate_status("CrashLoopBackOff (x7)"), "CrashLoop (x7)");
+}
+
+#[test]
+fn abbreviate_status_passes_unknown_statuses_through() {
+    for status in ["Running", "3/3", "Opaque", "ClusterIP", "LoadBalancerX", ""] {
+        assert!(
+            matches!(abbreviate_status(status), std::borrow::Cow::Borrowed(s) if s == status),
+            "{status} should be unchanged"
+        );
+    }
+}
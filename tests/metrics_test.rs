@@ -0,0 +1,140 @@
+//! Tests for kuberift::k8s::metrics — `--metrics` usage from `kubectl top`.
+
+use std::collections::HashMap;
+
+use kuberift::items::{K8sItem, ResourceKind};
+use kuberift::k8s::metrics::{apply_usage, parse_top_line, usage_key, usage_text};
+
+fn usage(entries: &[(&str, &str, &str)]) -> HashMap<String, (String, String)> {
+    entries
+        .iter()
+        .map(|(key, cpu, mem)| ((*key).to_string(), ((*cpu).to_string(), (*mem).to_string())))
+        .collect()
+}
+
+fn row_text(item: &K8sItem, max_width: Option<usize>) -> String {
+    item.row_line(max_width)
+        .spans
+        .iter()
+        .map(|s| s.content.as_ref())
+        .collect()
+}
+
+// ── parse_top_line ────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_top_line_reads_a_pod_line() {
+    assert_eq!(
+        parse_top_line("web-7d4f   250m   512Mi"),
+        Some(("web-7d4f".into(), "250m".into(), "512Mi".into()))
+    );
+}
+
+#[test]
+fn parse_top_line_keys_all_namespaces_pods_by_namespace() {
+    assert_eq!(
+        parse_top_line("kube-system   coredns-5d78   3m   14Mi"),
+        Some((
+            "kube-system/coredns-5d78".into(),
+            "3m".into(),
+            "14Mi".into()
+        ))
+    );
+}
+
+#[test]
+fn parse_top_line_skips_node_percentages() {
+    assert_eq!(
+        parse_top_line("worker-1   412m   10%   2310Mi   30%"),
+        Some(("worker-1".into(), "412m".into(), "2310Mi".into()))
+    );
+}
+
+#[test]
+fn parse_top_line_rejects_other_shapes() {
+    assert_eq!(parse_top_line(""), None);
+    assert_eq!(parse_top_line("web-7d4f 250m"), None);
+    assert_eq!(parse_top_line("a b c d e f"), None);
+}
+
+// ── apply_usage ───────────────────────────────────────────────────────────────
+
+#[test]
+fn usage_key_is_namespace_and_name_or_just_the_name() {
+    let pod = K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", "");
+    assert_eq!(usage_key(&pod), "default/web-0");
+    let node = K8sItem::new(ResourceKind::Node, "", "worker-1", "Ready", "9d", "");
+    assert_eq!(usage_key(&node), "worker-1");
+}
+
+#[test]
+fn apply_usage_sets_pods_and_nodes_only() {
+    let items = [
+        K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", ""),
+        K8sItem::new(ResourceKind::Node, "", "worker-1", "Ready", "9d", ""),
+        K8sItem::new(
+            ResourceKind::Service,
+            "default",
+            "web-0",
+            "ClusterIP",
+            "3h",
+            "",
+        ),
+    ];
+    let pods = usage(&[("default/web-0", "250m", "512Mi")]);
+    let nodes = usage(&[("worker-1", "412m", "2310Mi")]);
+    apply_usage(&items, &pods, &nodes);
+    assert_eq!(items[0].state().read().unwrap().usage, "cpu 250m mem 512Mi");
+    assert_eq!(
+        items[1].state().read().unwrap().usage,
+        usage_text("412m", "2310Mi")
+    );
+    assert!(items[2].state().read().unwrap().usage.is_empty());
+}
+
+#[test]
+fn apply_usage_clears_usage_no_longer_reported() {
+    let items = [K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "web-0",
+        "Running",
+        "3h",
+        "",
+    )];
+    apply_usage(
+        &items,
+        &usage(&[("default/web-0", "250m", "512Mi")]),
+        &HashMap::new(),
+    );
+    apply_usage(&items, &HashMap::new(), &HashMap::new());
+    assert!(items[0].state().read().unwrap().usage.is_empty());
+}
+
+// ── usage column ──────────────────────────────────────────────────────────────
+
+#[test]
+fn row_shows_usage_only_when_known() {
+    let item = K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", "");
+    assert!(!row_text(&item, None).contains("cpu"));
+    apply_usage(
+        std::slice::from_ref(&item),
+        &usage(&[("default/web-0", "250m", "512Mi")]),
+        &HashMap::new(),
+    );
+    assert!(row_text(&item, None).ends_with("3h  cpu 250m mem 512Mi"));
+}
+
+#[test]
+fn usage_gives_way_before_age_in_a_narrow_row() {
+    let item = K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", "");
+    apply_usage(
+        std::slice::from_ref(&item),
+        &usage(&[("default/web-0", "250m", "512Mi")]),
+        &HashMap::new(),
+    );
+    let full = row_text(&item, None).chars().count();
+    let text = row_text(&item, Some(full - 1));
+    assert!(!text.contains("cpu"));
+    assert!(text.ends_with("3h"));
+}
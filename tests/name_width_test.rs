@@ -0,0 +1,52 @@
+//! Tests for the `--name-width` column setting.
+//!
+//! The width lives in a process-wide `OnceLock`, so these tests run in their own
+//! binary to avoid leaking a custom width into the other item tests.
+
+use kuberift::cli::parse_name_width;
+use kuberift::items::{name_column, name_width, set_name_width, K8sItem, ResourceKind};
+use skim::SkimItem;
+
+const WIDTH: usize = 12;
+
+fn long_pod() -> K8sItem {
+    K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "api-server-7d9f8b6c5-xk2lp",
+        "Running",
+        "1d",
+        "",
+    )
+}
+
+#[test]
+fn custom_width_applies_to_text_and_display_column() {
+    set_name_width(WIDTH);
+    assert_eq!(name_width(), WIDTH);
+
+    let item = long_pod();
+    let text = item.text();
+    assert!(
+        text.contains("default/api-server-7…"),
+        "text() must truncate to {WIDTH} bytes: {text}"
+    );
+
+    let col = name_column(item.name());
+    assert_eq!(col, "api-server-7… ");
+    // Short names are padded to the same column width.
+    assert_eq!(name_column("web").chars().count(), WIDTH + 1);
+}
+
+#[test]
+fn parse_name_width_accepts_valid_values() {
+    assert_eq!(parse_name_width("8"), Ok(8));
+    assert_eq!(parse_name_width("60"), Ok(60));
+}
+
+#[test]
+fn parse_name_width_rejects_too_small_or_garbage() {
+    assert!(parse_name_width("7").is_err());
+    assert!(parse_name_width("0").is_err());
+    assert!(parse_name_width("wide").is_err());
+}
@@ -0,0 +1,131 @@
+//! Tests for kuberift::output — the `--summary` table.
+
+use kuberift::items::{K8sItem, ResourceKind};
+use kuberift::output::{column_widths, format_row, render_table, table_cells, HEADERS};
+
+fn cells(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| (*v).to_string()).collect()
+}
+
+// ── column_widths ─────────────────────────────────────────────────────────────
+
+#[test]
+fn column_widths_take_the_widest_cell_or_title() {
+    let rows = vec![
+        cells(&["default", "web-7d4f"]),
+        cells(&["kube-system", "dns"]),
+    ];
+    assert_eq!(column_widths(&["NAMESPACE", "NAME"], &rows), [11, 8]);
+}
+
+#[test]
+fn column_widths_count_characters_not_bytes() {
+    let rows = vec![cells(&["café"])];
+    assert_eq!(column_widths(&["NS"], &rows), [4]);
+}
+
+// ── format_row ────────────────────────────────────────────────────────────────
+
+#[test]
+fn format_row_pads_all_but_the_last_column() {
+    let line = format_row(&cells(&["ns", "web", "1d"]), &[5, 4, 6], None, false);
+    assert_eq!(line, "ns      web    1d\n");
+}
+
+#[test]
+fn format_row_colors_only_the_status_cell() {
+    let line = format_row(
+        &cells(&["web", "CrashLoopBackOff", "1d"]),
+        &[3, 16, 2],
+        Some(1),
+        true,
+    );
+    assert!(line.starts_with("web   \x1b["), "got {line:?}");
+    assert!(
+        line.contains("mCrashLoopBackOff\x1b[0m   1d"),
+        "got {line:?}"
+    );
+    assert_eq!(line.matches("\x1b[").count(), 2);
+}
+
+#[test]
+fn format_row_without_ansi_is_plain() {
+    let line = format_row(&cells(&["web", "Running"]), &[3, 7], Some(1), false);
+    assert!(!line.contains('\x1b'));
+}
+
+// ── render_table ──────────────────────────────────────────────────────────────
+
+#[test]
+fn table_cells_follow_the_headers() {
+    let item = K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", "");
+    assert_eq!(
+        table_cells(&item),
+        ["default", "pod", "web-0", "Running", "3h"].map(String::from)
+    );
+}
+
+#[test]
+fn render_table_aligns_rows_under_the_header() {
+    let items = [
+        K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "3h", ""),
+        K8sItem::new(
+            ResourceKind::Deployment,
+            "kube-system",
+            "dns",
+            "1/1",
+            "12d",
+            "",
+        ),
+    ];
+    let table = render_table(&items, false);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with(HEADERS[0]));
+    let status_at = lines[0].find("STATUS").unwrap();
+    assert_eq!(lines[1].find("Running"), Some(status_at));
+    assert_eq!(lines[2].find("1/1"), Some(status_at));
+}
+
+#[test]
+fn render_table_adds_a_context_column_when_items_have_one() {
+    let items = [
+        K8sItem::new(
+            ResourceKind::Pod,
+            "default",
+            "web-0",
+            "Running",
+            "3h",
+            "prod",
+        ),
+        K8sItem::new(
+            ResourceKind::Pod,
+            "default",
+            "web-0",
+            "Pending",
+            "1m",
+            "staging",
+        ),
+    ];
+    let table = render_table(&items, false);
+    let lines: Vec<&str> = table.lines().collect();
+    assert!(lines[0].starts_with("CONTEXT   NAMESPACE"));
+    assert!(lines[1].starts_with("prod      default"));
+    assert!(lines[2].starts_with("staging   default"));
+}
+
+#[test]
+fn render_table_never_colors_the_header() {
+    let items = [K8sItem::new(
+        ResourceKind::Pod,
+        "default",
+        "web-0",
+        "Failed",
+        "3h",
+        "",
+    )];
+    let table = render_table(&items, true);
+    let (header, body) = table.split_once('\n').unwrap();
+    assert!(!header.contains('\x1b'));
+    assert!(body.contains("mFailed\x1b[0m"));
+}
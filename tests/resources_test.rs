@@ -6,31 +6,47 @@ use k8s_openapi::{
             DaemonSet, DaemonSetStatus, Deployment, DeploymentSpec, DeploymentStatus, StatefulSet,
             StatefulSetStatus,
         },
+        autoscaling::v2::{
+            HorizontalPodAutoscaler, HorizontalPodAutoscalerStatus, MetricStatus,
+            MetricValueStatus, ResourceMetricStatus,
+        },
         batch::v1::{CronJob, CronJobStatus, Job, JobStatus},
         core::v1::{
-            ContainerState, ContainerStateTerminated, ContainerStateWaiting, ContainerStatus,
-            Event, Namespace, NamespaceStatus, Node, NodeCondition, NodeStatus, ObjectReference,
+            ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting,
+            ContainerStatus, EndpointAddress, EndpointSubset, Endpoints, Event, Namespace,
+            NamespaceStatus, Node, NodeCondition, NodeStatus, NodeSystemInfo, ObjectReference,
             PersistentVolume, PersistentVolumeClaim, PersistentVolumeClaimStatus,
-            PersistentVolumeStatus, Pod, PodStatus, Secret, Service, ServiceSpec,
+            PersistentVolumeStatus, Pod, PodStatus, PodTemplateSpec, Secret, Service,
+            ServiceAccount, ServiceSpec,
         },
         networking::v1::{
             Ingress, IngressLoadBalancerIngress, IngressLoadBalancerStatus, IngressStatus,
         },
+        rbac::v1::{PolicyRule, Role, RoleBinding, RoleRef, Subject},
     },
     apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time},
+    jiff::{SignedDuration, Timestamp},
 };
-use kuberift::items::{ResourceKind, SortField};
+use kuberift::items::{Layout, ResourceKind, SortField, StatusHealth, RESTART_WARNING_THRESHOLD};
 use kuberift::k8s::resources::{
-    cronjob_status, daemonset_status, deploy_status, event_status, ingress_status, job_status,
-    namespace_status, node_status, pod_status, pv_status, pvc_status, resource_age, secret_status,
-    service_status, sort_items, statefulset_status, status_priority, ALL_KINDS,
+    connecting_text, context_separator, cronjob_status, daemonset_status, deploy_restarted,
+    deploy_status, endpoints_status, event_status, group_by_context, has_controller, hpa_status,
+    ingress_status, is_api_not_served, is_stale, job_status, loaded_text, mark_unused_pvcs,
+    namespace_excluded, namespace_status, node_status, parse_age, parse_namespaces,
+    pod_base_status, pod_claims, pod_crashed_at, pod_detail, pod_hooks, pod_status, pv_status,
+    pvc_status, resource_age, restart_marker, role_status, rolebinding_status, secret_status,
+    service_status, serviceaccount_status, sort_items, sort_items_with, stale_kinds, stale_text,
+    statefulset_status, status_priority, terminating_hidden, unavailable_text, unsupported_text,
+    wait_for_init, watch_event_stream, watch_resources, watcher_configs, watching_text,
+    with_recent_crash, InitBatch, InitWait, KindActivity, KindHooks, RetryCounter, Shutdown,
+    WatchOptions, ALL_KINDS, DEBUG_STATUS, RESTARTED_AT_ANNOTATION, UNUSED_PVC_STATUS,
 };
 
 // ── ALL_KINDS ─────────────────────────────────────────────────────────────────
 
 #[test]
-fn all_kinds_has_fifteen_entries() {
-    assert_eq!(ALL_KINDS.len(), 15);
+fn all_kinds_has_twenty_entries() {
+    assert_eq!(ALL_KINDS.len(), 20);
 }
 
 #[test]
@@ -50,6 +66,66 @@ fn all_kinds_contains_every_resource_variant() {
     assert!(ALL_KINDS.contains(&ResourceKind::Namespace));
     assert!(ALL_KINDS.contains(&ResourceKind::Node));
     assert!(ALL_KINDS.contains(&ResourceKind::Event));
+    assert!(ALL_KINDS.contains(&ResourceKind::HorizontalPodAutoscaler));
+    assert!(ALL_KINDS.contains(&ResourceKind::Endpoints));
+    assert!(ALL_KINDS.contains(&ResourceKind::ServiceAccount));
+    assert!(ALL_KINDS.contains(&ResourceKind::Role));
+    assert!(ALL_KINDS.contains(&ResourceKind::RoleBinding));
+}
+
+// ── RetryCounter ──────────────────────────────────────────────────────────────
+
+#[test]
+fn retry_counter_exhausts_after_max_consecutive_failures() {
+    let mut retries = RetryCounter::new(3);
+    assert!(!retries.fail());
+    assert!(!retries.fail());
+    assert!(!retries.fail());
+    assert!(
+        retries.fail(),
+        "4th consecutive failure must exceed max of 3"
+    );
+    assert_eq!(retries.failures(), 4);
+}
+
+#[test]
+fn retry_counter_resets_on_success() {
+    let mut retries = RetryCounter::new(2);
+    assert!(!retries.fail());
+    assert!(!retries.fail());
+    retries.reset();
+    assert_eq!(retries.failures(), 0);
+    assert!(
+        !retries.fail(),
+        "streak must restart after a successful InitDone"
+    );
+    assert!(!retries.fail());
+    assert!(retries.fail());
+}
+
+#[test]
+fn retry_counter_zero_max_never_gives_up() {
+    let mut retries = RetryCounter::new(0);
+    for _ in 0..100 {
+        assert!(!retries.fail());
+    }
+}
+
+// ── is_api_not_served ─────────────────────────────────────────────────────────
+
+#[test]
+fn api_not_served_for_not_found_and_method_not_allowed() {
+    assert!(is_api_not_served(Some(404)));
+    assert!(is_api_not_served(Some(405)));
+}
+
+#[test]
+fn transient_errors_are_not_api_not_served() {
+    for code in [401, 403, 410, 429, 500, 502, 503, 504] {
+        assert!(!is_api_not_served(Some(code)), "{code} should be retried");
+    }
+    // Connection/transport errors carry no HTTP status.
+    assert!(!is_api_not_served(None));
 }
 
 // ── status_priority ───────────────────────────────────────────────────────────
@@ -117,16 +193,44 @@ fn resource_age_no_timestamp_returns_question_mark() {
     assert_eq!(resource_age(&ObjectMeta::default()), "?");
 }
 
+fn created_ago(hours: i64) -> ObjectMeta {
+    let created = Timestamp::now() - SignedDuration::from_hours(hours);
+    ObjectMeta {
+        creation_timestamp: Some(Time(created)),
+        ..Default::default()
+    }
+}
+
 #[test]
-fn resource_age_old_timestamp_returns_days() {
-    // 2020-01-01 is always many days ago
+fn resource_age_old_timestamp_returns_years() {
+    // 2020-01-01 is always years ago
     let time: Time = serde_json::from_str(r#""2020-01-01T00:00:00Z""#).unwrap();
     let meta = ObjectMeta {
         creation_timestamp: Some(time),
         ..Default::default()
     };
     let age = resource_age(&meta);
-    assert!(age.ends_with('d'), "expected days suffix, got: {age}");
+    let years = age.split('y').next().unwrap();
+    assert!(
+        age.contains('y') && years.parse::<u32>().unwrap() >= 6,
+        "expected a years suffix, got: {age}"
+    );
+}
+
+#[test]
+fn resource_age_ten_days_stays_in_days() {
+    assert_eq!(resource_age(&created_ago(10 * 24 + 1)), "10d");
+}
+
+#[test]
+fn resource_age_several_weeks_returns_weeks() {
+    assert_eq!(resource_age(&created_ago(52 * 24 + 1)), "7w");
+}
+
+#[test]
+fn resource_age_young_keeps_hours_and_minutes() {
+    assert_eq!(resource_age(&created_ago(5)), "5h");
+    assert_eq!(resource_age(&created_ago(0)), "0m");
 }
 
 // ── pod_status ────────────────────────────────────────────────────────────────
@@ -198,6 +302,324 @@ fn pod_status_crashloop_from_container_waiting_reason() {
     assert_eq!(pod_status(&pod), "CrashLoopBackOff");
 }
 
+fn crashlooping_pod(restart_counts: &[i32]) -> Pod {
+    let statuses = restart_counts
+        .iter()
+        .map(|&restart_count| ContainerStatus {
+            restart_count,
+            state: Some(ContainerState {
+                waiting: Some(ContainerStateWaiting {
+                    reason: Some("CrashLoopBackOff".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .collect();
+    Pod {
+        status: Some(PodStatus {
+            container_statuses: Some(statuses),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pod_status_appends_summed_restart_count() {
+    let status = pod_status(&crashlooping_pod(&[4, 3]));
+    assert_eq!(status, "CrashLoopBackOff (x7)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Critical);
+}
+
+#[test]
+fn pod_status_no_restart_suffix_without_restarts() {
+    assert_eq!(pod_status(&crashlooping_pod(&[0, 0])), "CrashLoopBackOff");
+}
+
+#[test]
+fn restart_suffix_keeps_exact_critical_statuses_critical() {
+    for status in ["OOMKilled (x2)", "Error (x1)"] {
+        assert_eq!(
+            StatusHealth::classify(status),
+            StatusHealth::Critical,
+            "{status}"
+        );
+    }
+}
+
+fn running_pod_with_last_termination(restart_count: i32, finished: Timestamp) -> Pod {
+    Pod {
+        status: Some(PodStatus {
+            phase: Some("Running".to_string()),
+            container_statuses: Some(vec![ContainerStatus {
+                restart_count,
+                state: Some(ContainerState {
+                    running: Some(ContainerStateRunning::default()),
+                    ..Default::default()
+                }),
+                last_state: Some(ContainerState {
+                    terminated: Some(ContainerStateTerminated {
+                        exit_code: 1,
+                        reason: Some("Error".to_string()),
+                        finished_at: Some(Time(finished)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pod_status_running_with_recent_restart_is_warning() {
+    let two_min_ago = Timestamp::from_second(Timestamp::now().as_second() - 120).unwrap();
+    let status = pod_status(&running_pod_with_last_termination(3, two_min_ago));
+    assert_eq!(status, "Running (restarted 2m ago)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+#[test]
+fn pod_status_running_with_old_restart_stays_running() {
+    let hour_ago = Timestamp::from_second(Timestamp::now().as_second() - 3600).unwrap();
+    assert_eq!(
+        pod_status(&running_pod_with_last_termination(3, hour_ago)),
+        "Running"
+    );
+}
+
+#[test]
+fn pod_base_status_leaves_the_crash_to_be_worked_out_later() {
+    let two_min_ago = Timestamp::from_second(Timestamp::now().as_second() - 120).unwrap();
+    let pod = running_pod_with_last_termination(3, two_min_ago);
+    assert_eq!(pod_base_status(&pod), "Running");
+    assert_eq!(pod_crashed_at(&pod), Some(two_min_ago.as_second()));
+    assert_eq!(pod_crashed_at(&Pod::default()), None);
+}
+
+#[test]
+fn recent_crash_clears_once_the_window_passes_without_new_events() {
+    use kuberift::items::K8sItem;
+    let item = K8sItem::new(ResourceKind::Pod, "default", "web-0", "Running", "1h", "");
+    let now = Timestamp::now().as_second();
+    item.state().write().unwrap().crashed_at = Some(now - 120);
+    assert_eq!(item.status(), "Running (restarted 2m ago)");
+    assert_eq!(
+        StatusHealth::classify(&item.status()),
+        StatusHealth::Warning
+    );
+    // The same stored crash, read ten minutes on.
+    item.state().write().unwrap().crashed_at = Some(now - 600);
+    assert_eq!(item.status(), "Running");
+    assert_eq!(
+        StatusHealth::classify(&item.status()),
+        StatusHealth::Healthy
+    );
+}
+
+#[test]
+fn recent_crash_only_notes_running_pods() {
+    let now = Timestamp::now().as_second();
+    assert_eq!(
+        with_recent_crash("CrashLoopBackOff (x3)", Some(now - 60)),
+        "CrashLoopBackOff (x3)"
+    );
+    assert_eq!(with_recent_crash("Running", None), "Running");
+}
+
+#[test]
+fn recent_crash_keeps_the_running_suffix() {
+    let now = Timestamp::now().as_second();
+    let debug = with_recent_crash(DEBUG_STATUS, Some(now - 60));
+    assert_eq!(debug, "Running (debug) (restarted 1m ago)");
+    assert_eq!(StatusHealth::classify(&debug), StatusHealth::Warning);
+    let restarts = with_recent_crash("Running (x12)", Some(now - 60));
+    assert_eq!(restarts, "Running (x12) (restarted 1m ago)");
+    assert_eq!(StatusHealth::classify(&restarts), StatusHealth::Warning);
+}
+
+fn running_pod_with_restarts(restart_counts: &[i32]) -> Pod {
+    let statuses = restart_counts
+        .iter()
+        .map(|&restart_count| ContainerStatus {
+            restart_count,
+            state: Some(ContainerState {
+                running: Some(ContainerStateRunning::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .collect();
+    Pod {
+        status: Some(PodStatus {
+            phase: Some("Running".to_string()),
+            container_statuses: Some(statuses),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pod_status_running_at_restart_threshold_stays_running() {
+    let at = i32::try_from(RESTART_WARNING_THRESHOLD).unwrap();
+    let status = pod_status(&running_pod_with_restarts(&[at]));
+    assert_eq!(status, "Running");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn pod_status_running_above_restart_threshold_shows_count() {
+    let above = i32::try_from(RESTART_WARNING_THRESHOLD).unwrap() + 1;
+    let status = pod_status(&running_pod_with_restarts(&[above]));
+    assert_eq!(status, format!("Running (x{above})"));
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+#[test]
+fn pod_status_running_restart_threshold_uses_summed_count() {
+    let status = pod_status(&running_pod_with_restarts(&[4, 8]));
+    assert_eq!(status, "Running (x12)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+// ── pod_detail (searchable container message) ────────────────────────────────
+
+fn image_pull_pod() -> Pod {
+    Pod {
+        metadata: ObjectMeta {
+            name: Some("web-0".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        status: Some(PodStatus {
+            phase: Some("Pending".to_string()),
+            container_statuses: Some(vec![ContainerStatus {
+                state: Some(ContainerState {
+                    waiting: Some(ContainerStateWaiting {
+                        reason: Some("ImagePullBackOff".to_string()),
+                        message: Some("Back-off pulling image \"registry/foo:bar\"".to_string()),
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pod_detail_reads_the_waiting_message() {
+    assert_eq!(
+        pod_detail(&image_pull_pod()),
+        "Back-off pulling image \"registry/foo:bar\""
+    );
+}
+
+#[test]
+fn pod_detail_falls_back_to_the_pod_message() {
+    let pod = Pod {
+        status: Some(PodStatus {
+            phase: Some("Failed".to_string()),
+            reason: Some("Evicted".to_string()),
+            message: Some("The node was low on resource:\n  memory.".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(pod_detail(&pod), "The node was low on resource: memory.");
+}
+
+#[test]
+fn pod_detail_empty_for_healthy_pods() {
+    assert_eq!(pod_detail(&running_pod_with_restarts(&[0])), "");
+    assert_eq!(pod_detail(&Pod::default()), "");
+}
+
+#[test]
+fn only_pod_hooks_record_the_failure_detail() {
+    assert_eq!(
+        pod_hooks().state(&image_pull_pod()).detail,
+        "Back-off pulling image \"registry/foo:bar\""
+    );
+    let svc = KindHooks::new(service_status).state(&Service::default());
+    assert!(svc.detail.is_empty());
+}
+
+#[tokio::test]
+async fn image_pull_message_is_searchable_in_item_text() {
+    use kube::runtime::watcher::Event as W;
+    use skim::prelude::*;
+    use std::sync::Arc;
+
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let opts = WatchOptions::default();
+    let batch = Arc::new(InitBatch::new(tx, 1, &opts, &[ResourceKind::Pod]));
+    let events = vec![W::Init, W::InitApply(image_pull_pod()), W::InitDone];
+    let stream = futures::stream::iter(events.into_iter().map(Ok));
+    watch_event_stream(stream, batch.clone(), ResourceKind::Pod, opts, pod_hooks()).await;
+    batch.run(Some(std::time::Duration::from_secs(5))).await;
+
+    let texts: Vec<String> = rx
+        .try_iter()
+        .flatten()
+        .filter(|item| {
+            let inner: &dyn SkimItem = &**item;
+            inner
+                .as_any()
+                .downcast_ref::<kuberift::items::K8sItem>()
+                .is_some()
+        })
+        .map(|item| item.text().into_owned())
+        .collect();
+    assert_eq!(texts.len(), 1);
+    assert!(texts[0].contains("Back-off pulling image"), "{}", texts[0]);
+}
+
+fn running_pod_with_ephemeral(state: ContainerState) -> Pod {
+    Pod {
+        status: Some(PodStatus {
+            phase: Some("Running".to_string()),
+            ephemeral_container_statuses: Some(vec![ContainerStatus {
+                name: "debugger-x7k2p".to_string(),
+                state: Some(state),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pod_status_marks_active_debug_container() {
+    let status = pod_status(&running_pod_with_ephemeral(ContainerState {
+        running: Some(ContainerStateRunning::default()),
+        ..Default::default()
+    }));
+    assert_eq!(status, DEBUG_STATUS);
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn pod_status_ignores_finished_debug_container() {
+    let pod = running_pod_with_ephemeral(ContainerState {
+        terminated: Some(ContainerStateTerminated {
+            exit_code: 130,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(pod_status(&pod), "Running");
+}
+
 #[test]
 fn pod_status_oomkilled_from_terminated_nonzero_exit() {
     let pod = Pod {
@@ -467,6 +889,47 @@ fn deploy_status_degraded() {
     assert_eq!(deploy_status(&d), "1/3");
 }
 
+#[test]
+fn deploy_status_mid_rollout_shows_progression() {
+    let d = Deployment {
+        spec: Some(DeploymentSpec {
+            replicas: Some(3),
+            ..Default::default()
+        }),
+        status: Some(DeploymentStatus {
+            replicas: Some(4),
+            updated_replicas: Some(2),
+            ready_replicas: Some(2),
+            available_replicas: Some(2),
+            unavailable_replicas: Some(2),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let status = deploy_status(&d);
+    assert_eq!(status, "2/3 (updated 2/3, available 2, unavailable 2)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+#[test]
+fn deploy_status_finished_rollout_is_plain_ratio() {
+    let d = Deployment {
+        spec: Some(DeploymentSpec {
+            replicas: Some(3),
+            ..Default::default()
+        }),
+        status: Some(DeploymentStatus {
+            replicas: Some(3),
+            updated_replicas: Some(3),
+            ready_replicas: Some(3),
+            available_replicas: Some(3),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(deploy_status(&d), "3/3");
+}
+
 #[test]
 fn deploy_status_zero_replicas() {
     let d = Deployment {
@@ -483,6 +946,64 @@ fn deploy_status_zero_replicas() {
     assert_eq!(deploy_status(&d), "0/0");
 }
 
+// ── deploy_restarted (rollout restart marker) ──────────────────────────────────
+
+fn deployment_restarted_at(ts: &str) -> Deployment {
+    Deployment {
+        spec: Some(DeploymentSpec {
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    annotations: Some(
+                        [(RESTARTED_AT_ANNOTATION.to_string(), ts.to_string())]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn deploy_restarted_marks_recent_restart() {
+    let two_min_ago = Timestamp::from_second(Timestamp::now().as_second() - 120).unwrap();
+    let d = deployment_restarted_at(&two_min_ago.to_string());
+    assert_eq!(deploy_restarted(&d), Some(two_min_ago.as_second()));
+    assert_eq!(restart_marker(deploy_restarted(&d)), "↺ 2m ago");
+}
+
+#[test]
+fn deploy_restarted_ignores_old_or_missing_annotation() {
+    assert_eq!(deploy_restarted(&Deployment::default()), None);
+    let old = deployment_restarted_at("2020-01-01T00:00:00Z");
+    assert_eq!(restart_marker(deploy_restarted(&old)), "");
+    let garbage = deployment_restarted_at("not-a-timestamp");
+    assert_eq!(deploy_restarted(&garbage), None);
+}
+
+#[test]
+fn restart_marker_is_worked_out_when_the_row_is_drawn() {
+    use kuberift::items::K8sItem;
+    let item = K8sItem::new(ResourceKind::Deployment, "default", "web", "3/3", "9d", "");
+    let now = Timestamp::now().as_second();
+    item.state().write().unwrap().restarted_at = Some(now - 5 * 60);
+    assert_eq!(item.restarted(), "↺ 5m ago");
+    // The same stored restart, read once it is over an hour old.
+    item.state().write().unwrap().restarted_at = Some(now - 2 * 60 * 60);
+    assert_eq!(item.restarted(), "");
+    let row: String = item
+        .row_line(None)
+        .spans
+        .iter()
+        .map(|s| s.content.as_ref())
+        .collect();
+    assert!(!row.contains('↺'));
+}
+
 // ── statefulset_status ────────────────────────────────────────────────────────
 
 #[test]
@@ -574,6 +1095,160 @@ fn secret_status_service_account_type() {
     assert_eq!(secret_status(&s), "kubernetes.io/service-account-token");
 }
 
+// ── hpa_status ────────────────────────────────────────────────────────────────
+
+fn hpa(current: i32, desired: i32, cpu: Option<i32>) -> HorizontalPodAutoscaler {
+    let metric = cpu.map(|percent| MetricStatus {
+        type_: "Resource".to_string(),
+        resource: Some(ResourceMetricStatus {
+            name: "cpu".to_string(),
+            current: MetricValueStatus {
+                average_utilization: Some(percent),
+                ..Default::default()
+            },
+        }),
+        ..Default::default()
+    });
+    HorizontalPodAutoscaler {
+        status: Some(HorizontalPodAutoscalerStatus {
+            current_replicas: Some(current),
+            desired_replicas: desired,
+            current_metrics: metric.map(|m| vec![m]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn hpa_status_default_is_zero_of_zero() {
+    assert_eq!(hpa_status(&HorizontalPodAutoscaler::default()), "0/0");
+}
+
+#[test]
+fn hpa_status_scaling_up_shows_utilization() {
+    let status = hpa_status(&hpa(3, 5, Some(80)));
+    assert_eq!(status, "3/5 (cpu 80%)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+#[test]
+fn hpa_status_settled_is_healthy() {
+    let status = hpa_status(&hpa(5, 5, Some(45)));
+    assert_eq!(status, "5/5 (cpu 45%)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn hpa_status_without_metrics_is_plain_ratio() {
+    assert_eq!(hpa_status(&hpa(2, 2, None)), "2/2");
+}
+
+// ── endpoints_status ──────────────────────────────────────────────────────────
+
+fn addresses(ips: &[&str]) -> Option<Vec<EndpointAddress>> {
+    Some(
+        ips.iter()
+            .map(|ip| EndpointAddress {
+                ip: (*ip).to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn endpoints_status_counts_ready_addresses_across_subsets() {
+    let ep = Endpoints {
+        subsets: Some(vec![
+            EndpointSubset {
+                addresses: addresses(&["10.0.0.1", "10.0.0.2"]),
+                // Not ready yet: not counted.
+                not_ready_addresses: addresses(&["10.0.0.9"]),
+                ..Default::default()
+            },
+            EndpointSubset {
+                addresses: addresses(&["10.0.1.1"]),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    let status = endpoints_status(&ep);
+    assert_eq!(status, "3 ready");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn endpoints_status_without_addresses_is_a_warning() {
+    let status = endpoints_status(&Endpoints::default());
+    assert_eq!(status, "0 ready");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Warning);
+}
+
+// ── serviceaccount_status / role_status / rolebinding_status ──────────────────
+
+#[test]
+fn serviceaccount_status_counts_secrets() {
+    assert_eq!(
+        serviceaccount_status(&ServiceAccount::default()),
+        "0 secrets"
+    );
+    let sa = ServiceAccount {
+        secrets: Some(vec![
+            ObjectReference {
+                name: Some("builder-token".to_string()),
+                ..Default::default()
+            },
+            ObjectReference {
+                name: Some("registry".to_string()),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(serviceaccount_status(&sa), "2 secrets");
+}
+
+#[test]
+fn role_status_counts_rules() {
+    assert_eq!(role_status(&Role::default()), "0 rules");
+    let rule = |resource: &str| PolicyRule {
+        api_groups: Some(vec![String::new()]),
+        resources: Some(vec![resource.to_string()]),
+        verbs: vec!["get".to_string(), "list".to_string()],
+        ..Default::default()
+    };
+    let role = Role {
+        rules: Some(vec![rule("pods"), rule("pods/log")]),
+        ..Default::default()
+    };
+    assert_eq!(role_status(&role), "2 rules");
+}
+
+#[test]
+fn rolebinding_status_counts_subjects() {
+    assert_eq!(rolebinding_status(&RoleBinding::default()), "0 subjects");
+    let subject = |kind: &str, name: &str| Subject {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        ..Default::default()
+    };
+    let rb = RoleBinding {
+        role_ref: RoleRef {
+            api_group: "rbac.authorization.k8s.io".to_string(),
+            kind: "Role".to_string(),
+            name: "pod-reader".to_string(),
+        },
+        subjects: Some(vec![
+            subject("User", "jane"),
+            subject("ServiceAccount", "builder"),
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(rolebinding_status(&rb), "2 subjects");
+}
+
 // ── ingress_status ────────────────────────────────────────────────────────────
 
 #[test]
@@ -700,6 +1375,75 @@ fn node_status_no_ready_condition_in_list_returns_unknown() {
     assert_eq!(node_status(&node), "Unknown");
 }
 
+fn ready_node(labels: &[&str], kubelet_version: &str) -> Node {
+    Node {
+        metadata: ObjectMeta {
+            labels: Some(
+                labels
+                    .iter()
+                    .map(|key| ((*key).to_string(), String::new()))
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+        status: Some(NodeStatus {
+            conditions: Some(vec![NodeCondition {
+                type_: "Ready".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            }]),
+            node_info: Some(NodeSystemInfo {
+                kubelet_version: kubelet_version.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn node_status_shows_control_plane_role_and_version() {
+    let node = ready_node(
+        &[
+            "node-role.kubernetes.io/control-plane",
+            "kubernetes.io/hostname",
+        ],
+        "v1.29.2",
+    );
+    let status = node_status(&node);
+    assert_eq!(status, "Ready (control-plane, v1.29.2)");
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Healthy);
+}
+
+#[test]
+fn node_status_worker_without_role_label_shows_version_only() {
+    let node = ready_node(&["kubernetes.io/hostname"], "v1.29.2");
+    assert_eq!(node_status(&node), "Ready (v1.29.2)");
+}
+
+#[test]
+fn node_status_lists_several_roles_sorted() {
+    let node = ready_node(
+        &[
+            "node-role.kubernetes.io/worker",
+            "node-role.kubernetes.io/control-plane",
+        ],
+        "",
+    );
+    assert_eq!(node_status(&node), "Ready (control-plane, worker)");
+}
+
+#[test]
+fn node_status_not_ready_with_details_is_still_critical() {
+    let mut node = ready_node(&["node-role.kubernetes.io/worker"], "v1.28.9");
+    node.status.as_mut().unwrap().conditions.as_mut().unwrap()[0].status = "False".to_string();
+    let status = node_status(&node);
+    assert_eq!(status, "NotReady (worker, v1.28.9)");
+    assert!(status.starts_with("NotReady"));
+    assert_eq!(StatusHealth::classify(&status), StatusHealth::Critical);
+}
+
 // ── namespace_status ──────────────────────────────────────────────────────────
 
 #[test]
@@ -1018,5 +1762,995 @@ fn sort_items_by_age() {
         .iter()
         .map(|i| i.state().read().unwrap().age.clone())
         .collect();
-    assert_eq!(ages, vec!["1d", "30m", "5h"]);
+    // Oldest first, so the newest lands at the top in skim.
+    assert_eq!(ages, vec!["1d", "5h", "30m"]);
+}
+
+fn aged(ages: &[&str]) -> Vec<kuberift::items::K8sItem> {
+    ages.iter()
+        .enumerate()
+        .map(|(i, age)| {
+            kuberift::items::K8sItem::new(
+                ResourceKind::Pod,
+                "default",
+                format!("pod-{i}"),
+                "Running",
+                *age,
+                "",
+            )
+        })
+        .collect()
+}
+
+fn ages_of(items: &[kuberift::items::K8sItem]) -> Vec<String> {
+    items
+        .iter()
+        .map(|i| i.state().read().unwrap().age.clone())
+        .collect()
+}
+
+#[test]
+fn sort_items_by_age_is_numeric_across_units() {
+    let mut items = aged(&["10m", "?", "2d", "2m", "3h", "1h"]);
+    sort_items(&mut items, SortField::Age);
+    assert_eq!(ages_of(&items), ["?", "2d", "3h", "1h", "10m", "2m"]);
+}
+
+#[test]
+fn sort_items_by_age_reversed_keeps_unknown_at_the_same_end() {
+    let mut items = aged(&["10m", "?", "2d", "2m", "3h", "1h"]);
+    sort_items_with(&mut items, SortField::Age, true);
+    assert_eq!(ages_of(&items), ["?", "2m", "10m", "1h", "3h", "2d"]);
+}
+
+#[test]
+fn sort_items_reverse_flips_other_fields() {
+    let mut items = make_items();
+    sort_items_with(&mut items, SortField::Name, true);
+    let names: Vec<&str> = items.iter().map(|i| i.name()).collect();
+    assert_eq!(names, vec!["nginx", "backend", "api"]);
+}
+
+// ── watcher_configs ───────────────────────────────────────────────────────────
+
+#[test]
+fn watcher_config_uses_configured_page_size() {
+    let opts = WatchOptions {
+        page_size: Some(100),
+        ..WatchOptions::default()
+    };
+    assert_eq!(watcher_configs(&opts, true)[0].page_size, Some(100));
+}
+
+#[test]
+fn watcher_config_keeps_kube_default_page_size() {
+    let default = kube::runtime::watcher::Config::default().page_size;
+    assert_eq!(
+        watcher_configs(&WatchOptions::default(), true)[0].page_size,
+        default
+    );
+}
+
+#[test]
+fn watcher_config_namespace_only_for_namespaced_kinds() {
+    let opts = WatchOptions {
+        namespace: Some("prod".to_string()),
+        label_selector: Some("app=api".to_string()),
+        ..WatchOptions::default()
+    };
+    let namespaced = watcher_configs(&opts, true);
+    assert_eq!(namespaced.len(), 1);
+    assert_eq!(
+        namespaced[0].field_selector.as_deref(),
+        Some("metadata.namespace=prod")
+    );
+    assert_eq!(namespaced[0].label_selector.as_deref(), Some("app=api"));
+    let cluster = watcher_configs(&opts, false);
+    assert_eq!(cluster.len(), 1);
+    assert_eq!(cluster[0].field_selector, None);
+}
+
+#[test]
+fn watcher_configs_one_per_namespace() {
+    let opts = WatchOptions {
+        namespace: Some("app, app-system".to_string()),
+        label_selector: Some("tier=web".to_string()),
+        page_size: Some(50),
+        ..WatchOptions::default()
+    };
+    let configs = watcher_configs(&opts, true);
+    let fields: Vec<Option<&str>> = configs
+        .iter()
+        .map(|c| c.field_selector.as_deref())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            Some("metadata.namespace=app"),
+            Some("metadata.namespace=app-system")
+        ]
+    );
+    for config in &configs {
+        assert_eq!(config.label_selector.as_deref(), Some("tier=web"));
+        assert_eq!(config.page_size, Some(50));
+    }
+    assert_eq!(
+        watcher_configs(&opts, false).len(),
+        1,
+        "cluster-scoped kinds keep a single cluster-wide watcher"
+    );
+}
+
+// ── parse_namespaces ──────────────────────────────────────────────────────────
+
+#[test]
+fn parse_namespaces_single() {
+    assert_eq!(parse_namespaces("prod"), ["prod"]);
+}
+
+#[test]
+fn parse_namespaces_multiple() {
+    assert_eq!(
+        parse_namespaces("app,app-system,monitoring"),
+        ["app", "app-system", "monitoring"]
+    );
+}
+
+#[test]
+fn parse_namespaces_trims_whitespace_and_drops_empties() {
+    assert_eq!(
+        parse_namespaces(" app , app-system ,,"),
+        ["app", "app-system"]
+    );
+    assert!(parse_namespaces(" , ").is_empty());
+}
+
+#[test]
+fn parse_namespaces_drops_repeats() {
+    assert_eq!(parse_namespaces("app,web,app"), ["app", "web"]);
+}
+
+#[test]
+fn watch_options_namespaces_empty_without_scope() {
+    assert!(WatchOptions::default().namespaces().is_empty());
+}
+
+// ── parse_age ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_age_orders_units() {
+    let m = parse_age("45m").unwrap();
+    let h = parse_age("3h").unwrap();
+    let d = parse_age("2d").unwrap();
+    assert!(m < h && h < d, "expected 45m < 3h < 2d");
+}
+
+#[test]
+fn parse_age_values() {
+    use std::time::Duration;
+    assert_eq!(parse_age("30s"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_age("45m"), Some(Duration::from_secs(45 * 60)));
+    assert_eq!(parse_age("1d4h"), Some(Duration::from_secs(28 * 3600)));
+}
+
+#[test]
+fn parse_age_weeks_months_and_years() {
+    use std::time::Duration;
+    const DAY: u64 = 24 * 3600;
+    assert_eq!(parse_age("7w"), Some(Duration::from_secs(49 * DAY)));
+    assert_eq!(parse_age("1y"), Some(Duration::from_secs(365 * DAY)));
+    assert_eq!(parse_age("1y2mo"), Some(Duration::from_secs(425 * DAY)));
+    let w = parse_age("52w").unwrap();
+    let y = parse_age("1y").unwrap();
+    let d = parse_age("27d").unwrap();
+    assert!(
+        d < parse_age("4w").unwrap() && w < y,
+        "expected 27d < 4w and 52w < 1y"
+    );
+}
+
+#[test]
+fn parse_age_rejects_unknown_and_malformed() {
+    assert_eq!(parse_age("?"), None);
+    assert_eq!(parse_age(""), None);
+    assert_eq!(parse_age("12"), None);
+    assert_eq!(parse_age("h"), None);
+    assert_eq!(parse_age("3x"), None);
+}
+
+// ── Shutdown / wait_for_init ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn wait_for_init_returns_promptly_when_channel_closes() {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::sync::Notify;
+
+    let all_done = Notify::new();
+    let shutdown = Arc::new(Shutdown::new());
+    let closer = shutdown.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        closer.trigger();
+    });
+
+    let start = Instant::now();
+    let waited = wait_for_init(&all_done, &shutdown, Some(Duration::from_secs(8))).await;
+    assert_eq!(waited, InitWait::Closed);
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "coordinator must not sit out the init timeout: {:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn wait_for_init_already_shut_down() {
+    let shutdown = Shutdown::new();
+    shutdown.trigger();
+    assert!(shutdown.is_triggered());
+    let waited = wait_for_init(
+        &tokio::sync::Notify::new(),
+        &shutdown,
+        Some(std::time::Duration::from_secs(8)),
+    )
+    .await;
+    assert_eq!(waited, InitWait::Closed);
+}
+
+#[tokio::test]
+async fn wait_for_init_all_done_and_timeout() {
+    use std::time::Duration;
+    let all_done = tokio::sync::Notify::new();
+    all_done.notify_one();
+    let shutdown = Shutdown::new();
+    assert_eq!(
+        wait_for_init(&all_done, &shutdown, Some(Duration::from_secs(8))).await,
+        InitWait::AllDone
+    );
+    assert_eq!(
+        wait_for_init(&all_done, &shutdown, Some(Duration::from_millis(10))).await,
+        InitWait::TimedOut
+    );
+}
+
+#[tokio::test]
+async fn wait_for_init_respects_the_given_timeout() {
+    use std::time::{Duration, Instant};
+    let all_done = tokio::sync::Notify::new();
+    let shutdown = Shutdown::new();
+    let started = Instant::now();
+    assert_eq!(
+        wait_for_init(&all_done, &shutdown, Some(Duration::from_millis(50))).await,
+        InitWait::TimedOut
+    );
+    assert!(started.elapsed() >= Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn wait_for_init_without_timeout_waits_for_all_done() {
+    use std::time::Duration;
+    let all_done = tokio::sync::Notify::new();
+    let shutdown = Shutdown::new();
+    // Nothing has finished: with no timeout, the wait must still be pending
+    // well after the default would have been cut short.
+    let pending = tokio::time::timeout(
+        Duration::from_millis(100),
+        wait_for_init(&all_done, &shutdown, None),
+    )
+    .await;
+    assert!(pending.is_err(), "returned without a timeout: {pending:?}");
+
+    all_done.notify_one();
+    assert_eq!(
+        wait_for_init(&all_done, &shutdown, None).await,
+        InitWait::AllDone
+    );
+}
+
+// ── connecting placeholder ────────────────────────────────────────────────────
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_resources_sends_placeholder_before_any_item() {
+    use kuberift::items::{InfoItem, K8sItem};
+    use skim::prelude::*;
+
+    // Nothing listens on port 1, so no real item can arrive before the placeholder.
+    let config = kube::Config::new("http://127.0.0.1:1".parse().unwrap());
+    let client = kube::Client::try_from(config).unwrap();
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let opts = WatchOptions {
+        max_retries: 1,
+        ..WatchOptions::default()
+    };
+    tokio::spawn(async move {
+        let _ = watch_resources(client, tx, &[ResourceKind::Pod], &[], opts).await;
+    });
+
+    let first =
+        tokio::task::spawn_blocking(move || rx.recv_timeout(std::time::Duration::from_secs(5)))
+            .await
+            .unwrap()
+            .expect("watch_resources must send something promptly");
+    assert_eq!(first.len(), 1);
+    let inner: &dyn SkimItem = &*first[0];
+    let info = inner
+        .as_any()
+        .downcast_ref::<InfoItem>()
+        .expect("first row must be the placeholder");
+    assert_eq!(info.current_text(), connecting_text(""));
+    assert!(inner.as_any().downcast_ref::<K8sItem>().is_none());
+}
+
+#[test]
+fn connecting_text_names_the_context() {
+    assert_eq!(connecting_text(""), "(connecting to cluster…)");
+    assert!(connecting_text("prod").contains("prod"));
+}
+
+#[test]
+fn unavailable_text_names_the_kind_and_context() {
+    assert_eq!(
+        unavailable_text(&ResourceKind::Pod, ""),
+        "(pod unavailable: watch gave up)"
+    );
+    assert_eq!(
+        unavailable_text(&ResourceKind::Job, "prod"),
+        "(prod: job unavailable: watch gave up)"
+    );
+}
+
+#[test]
+fn unsupported_text_names_the_kind_and_context() {
+    assert_eq!(
+        unsupported_text(&ResourceKind::HorizontalPodAutoscaler, ""),
+        "(hpa unsupported: not served by this cluster)"
+    );
+    assert!(unsupported_text(&ResourceKind::Pod, "prod").starts_with("(prod: pod unsupported"));
+}
+
+#[test]
+fn loaded_text_shows_the_summary_counts() {
+    assert_eq!(loaded_text("", (142, 3, 11)), "(items:142 crit:3 warn:11)");
+    assert_eq!(
+        loaded_text("prod", (5, 0, 1)),
+        "(prod: items:5 crit:0 warn:1)"
+    );
+}
+
+// ── group_by_context ──────────────────────────────────────────────────────────
+
+#[test]
+fn group_by_context_groups_and_separates() {
+    use kuberift::items::{InfoItem, K8sItem};
+    use skim::SkimItem;
+
+    let item = |ctx: &str, name: &str, status: &str| {
+        K8sItem::new(ResourceKind::Pod, "default", name, status, "1h", ctx)
+    };
+    let rows = group_by_context(vec![
+        item("west", "w-ok", "Running"),
+        item("east", "e-ok", "Running"),
+        item("west", "w-crash", "CrashLoopBackOff"),
+        item("east", "e-pending", "Pending"),
+    ]);
+
+    let texts: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let inner: &dyn SkimItem = &**row;
+            if let Some(info) = inner.as_any().downcast_ref::<InfoItem>() {
+                info.current_text()
+            } else {
+                let k8s = inner.as_any().downcast_ref::<K8sItem>().unwrap();
+                format!("{}/{}", k8s.context(), k8s.name())
+            }
+        })
+        .collect();
+    assert_eq!(
+        texts,
+        [
+            context_separator("east").current_text(),
+            "east/e-pending".to_string(),
+            "east/e-ok".to_string(),
+            context_separator("west").current_text(),
+            "west/w-crash".to_string(),
+            "west/w-ok".to_string(),
+        ]
+    );
+}
+
+// ── unused PVCs ───────────────────────────────────────────────────────────────
+
+#[test]
+fn pod_claims_lists_pvc_volumes() {
+    use k8s_openapi::api::core::v1::{PersistentVolumeClaimVolumeSource, PodSpec, Volume};
+    let pod = Pod {
+        spec: Some(PodSpec {
+            volumes: Some(vec![
+                Volume {
+                    name: "data".to_string(),
+                    persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                        claim_name: "data-db-0".to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Volume {
+                    name: "tmp".to_string(),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(pod_claims(&pod), ["data-db-0"]);
+    assert!(pod_claims(&Pod::default()).is_empty());
+}
+
+#[test]
+fn only_pod_hooks_record_claims() {
+    use k8s_openapi::api::core::v1::{PersistentVolumeClaimVolumeSource, PodSpec, Volume};
+    let pod = Pod {
+        spec: Some(PodSpec {
+            volumes: Some(vec![Volume {
+                name: "data".to_string(),
+                persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                    claim_name: "data-db-0".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(pod_hooks().state(&pod).claims, ["data-db-0"]);
+    let svc = KindHooks::new(service_status).state(&Service::default());
+    assert!(svc.claims.is_empty());
+}
+
+#[test]
+fn has_controller_needs_a_controller_reference() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+    let owner = |controller| OwnerReference {
+        kind: "ReplicaSet".to_string(),
+        name: "web-abc".to_string(),
+        controller,
+        ..Default::default()
+    };
+    let meta = |refs| ObjectMeta {
+        owner_references: Some(refs),
+        ..Default::default()
+    };
+    assert!(has_controller(&meta(vec![owner(None), owner(Some(true))])));
+    assert!(!has_controller(&meta(vec![
+        owner(None),
+        owner(Some(false))
+    ])));
+    assert!(!has_controller(&ObjectMeta::default()));
+}
+
+#[test]
+fn mark_unused_pvcs_flags_only_unmounted_bound_claims() {
+    use kuberift::items::K8sItem;
+    let pod = K8sItem::new(ResourceKind::Pod, "db", "db-0", "Running", "1d", "");
+    pod.state().write().unwrap().claims = vec!["data-db-0".to_string()];
+    let pvc = |ns: &str, name: &str, status: &str| {
+        K8sItem::new(
+            ResourceKind::PersistentVolumeClaim,
+            ns,
+            name,
+            status,
+            "1d",
+            "",
+        )
+    };
+    let items = vec![
+        pod,
+        pvc("db", "data-db-0", "Bound"),
+        pvc("db", "data-db-old", "Bound"),
+        pvc("db", "data-db-new", "Pending"),
+        // Same claim name in another namespace is not mounted by db/db-0.
+        pvc("other", "data-db-0", "Bound"),
+    ];
+    // A terminating pod left out of the list still mounts db/data-db-old.
+    let hidden: std::collections::HashSet<_> =
+        [(String::new(), "db".to_string(), "data-db-old".to_string())]
+            .into_iter()
+            .collect();
+    mark_unused_pvcs(&items, &hidden);
+    let statuses: Vec<String> = items[1..].iter().map(K8sItem::status).collect();
+    assert_eq!(statuses, ["Bound", "Bound", "Pending", UNUSED_PVC_STATUS]);
+    assert_eq!(
+        StatusHealth::classify(UNUSED_PVC_STATUS),
+        StatusHealth::Warning
+    );
+}
+
+// ── Stale watch detection (--stale-after) ────────────────────────────────────
+
+/// Activity whose last event was `secs_ago` before `now`, with `now` placed
+/// an hour after the real clock so the subtraction can't underflow.
+fn activity(secs_ago: u64, has_items: bool, now: std::time::Instant) -> KindActivity {
+    KindActivity {
+        last_event: now - std::time::Duration::from_secs(secs_ago),
+        has_items,
+    }
+}
+
+fn later() -> std::time::Instant {
+    std::time::Instant::now() + std::time::Duration::from_secs(3600)
+}
+
+#[test]
+fn stale_only_after_the_window_with_items() {
+    use std::time::Duration;
+    let now = later();
+    let window = Duration::from_secs(600);
+    assert!(!is_stale(activity(599, true, now), now, window));
+    assert!(is_stale(activity(600, true, now), now, window));
+    assert!(is_stale(activity(3600, true, now), now, window));
+    // A kind with nothing listed is quiet by nature.
+    assert!(!is_stale(activity(3600, false, now), now, window));
+}
+
+#[test]
+fn stale_kinds_lists_sorted_short_names() {
+    use std::collections::HashMap;
+    use std::time::Duration;
+    let now = later();
+    let activity: HashMap<ResourceKind, KindActivity> = [
+        (ResourceKind::Service, activity(900, true, now)),
+        (ResourceKind::Pod, activity(5, true, now)),
+        (ResourceKind::Deployment, activity(700, true, now)),
+        (ResourceKind::Secret, activity(900, false, now)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        stale_kinds(&activity, now, Duration::from_secs(600)),
+        ["deploy", "svc"]
+    );
+}
+
+#[test]
+fn stale_text_names_kinds_and_window() {
+    let text = stale_text(
+        "(items:3 crit:0 warn:0)",
+        &["svc".to_string()],
+        std::time::Duration::from_secs(600),
+    );
+    assert!(text.starts_with("(items:3 crit:0 warn:0)"), "{text}");
+    assert!(text.contains("no events for svc in 10m"), "{text}");
+}
+
+// ── InitBatch (shared initial batch across contexts) ──────────────────────────
+
+fn batch_item(ctx: &str, name: &str) -> kuberift::items::K8sItem {
+    kuberift::items::K8sItem::new(ResourceKind::Pod, "default", name, "Running", "1m", ctx)
+}
+
+#[tokio::test]
+async fn init_batch_slow_context_does_not_delay_first_batch() {
+    use skim::prelude::*;
+    use std::time::Duration;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let batch = InitBatch::new(tx, 2, &WatchOptions::default(), &[ResourceKind::Pod]);
+
+    // "east" loads; "west" is slow and never reports in time.
+    batch.add(vec![batch_item("east", "api-0")]);
+    batch.session_done();
+
+    let started = std::time::Instant::now();
+    let waited = batch.run(Some(Duration::from_millis(100))).await;
+    assert_eq!(waited, InitWait::TimedOut);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(rx.try_recv().expect("batch sent at the timeout").len(), 1);
+
+    // The slow context's list is sent on its own when it finally arrives.
+    batch.add(vec![
+        batch_item("west", "api-0"),
+        batch_item("west", "api-1"),
+    ]);
+    assert_eq!(rx.try_recv().expect("late list forwarded").len(), 2);
+}
+
+#[tokio::test]
+async fn init_batch_sends_as_soon_as_every_context_is_done() {
+    use skim::prelude::*;
+    use std::time::Duration;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let batch = InitBatch::new(tx, 2, &WatchOptions::default(), &[]);
+    batch.add(vec![batch_item("east", "a")]);
+    batch.session_done();
+    batch.add(vec![batch_item("west", "b")]);
+    batch.session_done();
+
+    let waited = batch.run(Some(Duration::from_secs(30))).await;
+    assert_eq!(waited, InitWait::AllDone);
+    assert_eq!(rx.try_recv().unwrap().len(), 2);
+}
+
+/// Statuses of the PVCs `InitBatch` sends for one unmounted `Bound` claim
+/// and one mounted only by a terminating pod that `hidden_claims` holds.
+async fn batch_pvc_statuses(opts: &WatchOptions, hidden_claims: Vec<String>) -> Vec<String> {
+    use kuberift::items::K8sItem;
+    use skim::prelude::*;
+    use std::time::Duration;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let batch = InitBatch::new(tx, 1, opts, &[ResourceKind::Pod]);
+    batch.add_hidden_claims("", "db", hidden_claims);
+    batch.add(vec![
+        K8sItem::new(
+            ResourceKind::PersistentVolumeClaim,
+            "db",
+            "a",
+            "Bound",
+            "1d",
+            "",
+        ),
+        K8sItem::new(
+            ResourceKind::PersistentVolumeClaim,
+            "db",
+            "b",
+            "Bound",
+            "1d",
+            "",
+        ),
+    ]);
+    batch.session_done();
+    batch.run(Some(Duration::from_secs(30))).await;
+    let mut statuses: Vec<(String, String)> = rx
+        .try_recv()
+        .unwrap()
+        .iter()
+        .filter_map(|item| item.as_any().downcast_ref::<K8sItem>())
+        .map(|item| (item.name().to_string(), item.status()))
+        .collect();
+    statuses.sort();
+    statuses.into_iter().map(|(_, status)| status).collect()
+}
+
+#[tokio::test]
+async fn init_batch_counts_hidden_terminating_pods_as_mounting() {
+    let statuses = batch_pvc_statuses(&WatchOptions::default(), vec!["b".to_string()]).await;
+    assert_eq!(statuses, [UNUSED_PVC_STATUS, "Bound"]);
+}
+
+#[tokio::test]
+async fn init_batch_leaves_pvcs_unmarked_under_a_label_selector() {
+    let opts = WatchOptions {
+        label_selector: Some("app=db".to_string()),
+        ..WatchOptions::default()
+    };
+    assert_eq!(
+        batch_pvc_statuses(&opts, Vec::new()).await,
+        ["Bound", "Bound"]
+    );
+}
+
+/// Statuses of the batch `InitBatch` sends for a healthy, a critical and a
+/// warning pod under `layout`.
+async fn batch_statuses(layout: Layout) -> Vec<String> {
+    use kuberift::items::K8sItem;
+    use skim::prelude::*;
+    use std::time::Duration;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let opts = WatchOptions {
+        layout,
+        ..WatchOptions::default()
+    };
+    let batch = InitBatch::new(tx, 1, &opts, &[]);
+    batch.add(vec![
+        K8sItem::new(ResourceKind::Pod, "default", "ok", "Running", "1m", ""),
+        K8sItem::new(
+            ResourceKind::Pod,
+            "default",
+            "bad",
+            "CrashLoopBackOff",
+            "1m",
+            "",
+        ),
+        K8sItem::new(ResourceKind::Pod, "default", "meh", "Pending", "1m", ""),
+    ]);
+    batch.session_done();
+    batch.run(Some(Duration::from_secs(30))).await;
+    rx.try_recv()
+        .unwrap()
+        .iter()
+        .filter_map(|item| item.as_any().downcast_ref::<K8sItem>().map(K8sItem::status))
+        .collect()
+}
+
+#[tokio::test]
+async fn init_batch_order_follows_layout() {
+    // Default layout draws the last item at the top: unhealthy last.
+    assert_eq!(
+        batch_statuses(Layout::Default).await,
+        ["Running", "Pending", "CrashLoopBackOff"]
+    );
+    // Reverse layout draws the first item at the top: unhealthy first.
+    assert_eq!(
+        batch_statuses(Layout::Reverse).await,
+        ["CrashLoopBackOff", "Pending", "Running"]
+    );
+}
+
+// ── --changes-only ───────────────────────────────────────────────────────────
+
+fn named_pod(name: &str) -> Pod {
+    Pod {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Feed `events` through the Pod event loop and return the names sent to
+/// skim, sorted (live items may overtake the initial batch).
+async fn sent_names(
+    events: Vec<kube::runtime::watcher::Event<Pod>>,
+    opts: WatchOptions,
+) -> Vec<String> {
+    use skim::prelude::*;
+    use std::sync::Arc;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let batch = Arc::new(InitBatch::new(tx, 1, &opts, &[ResourceKind::Pod]));
+    let stream = futures::stream::iter(events.into_iter().map(Ok));
+    watch_event_stream(stream, batch.clone(), ResourceKind::Pod, opts, pod_hooks()).await;
+    batch.run(Some(std::time::Duration::from_secs(5))).await;
+    let mut names: Vec<String> = rx
+        .try_iter()
+        .flatten()
+        .filter_map(|item| {
+            let inner: &dyn SkimItem = &*item;
+            inner
+                .as_any()
+                .downcast_ref::<kuberift::items::K8sItem>()
+                .map(|k| k.name().to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Like [`sent_names`], but keeps each batch skim received, in order.
+async fn sent_batches(
+    events: Vec<kube::runtime::watcher::Event<Pod>>,
+    opts: WatchOptions,
+) -> Vec<Vec<String>> {
+    use skim::prelude::*;
+    use std::sync::Arc;
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    let batch = Arc::new(InitBatch::new(tx, 1, &opts, &[ResourceKind::Pod]));
+    let stream = futures::stream::iter(events.into_iter().map(Ok));
+    watch_event_stream(stream, batch.clone(), ResourceKind::Pod, opts, pod_hooks()).await;
+    batch.run(Some(std::time::Duration::from_secs(5))).await;
+    rx.try_iter()
+        .map(|batch| {
+            batch
+                .iter()
+                .filter_map(|item| {
+                    let inner: &dyn SkimItem = &**item;
+                    inner
+                        .as_any()
+                        .downcast_ref::<kuberift::items::K8sItem>()
+                        .map(|k| k.name().to_string())
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|names| !names.is_empty())
+        .collect()
+}
+
+#[tokio::test]
+async fn reconnect_batch_uses_the_configured_sort() {
+    use kube::runtime::watcher::Event as W;
+    let events = || {
+        vec![
+            W::Init,
+            W::InitApply(named_pod("first")),
+            W::InitDone,
+            // Reconnect: two pods appeared while disconnected.
+            W::Init,
+            W::InitApply(named_pod("first")),
+            W::InitApply(named_pod("zeta")),
+            W::InitApply(named_pod("beta")),
+            W::InitDone,
+        ]
+    };
+    let by_name = WatchOptions {
+        sort_field: SortField::Name,
+        ..WatchOptions::default()
+    };
+    let batches = sent_batches(events(), by_name.clone()).await;
+    assert!(
+        batches.contains(&vec!["beta".to_string(), "zeta".to_string()]),
+        "{batches:?}"
+    );
+
+    let reversed = WatchOptions {
+        sort_reverse: true,
+        ..by_name
+    };
+    let batches = sent_batches(events(), reversed).await;
+    assert!(
+        batches.contains(&vec!["zeta".to_string(), "beta".to_string()]),
+        "{batches:?}"
+    );
+}
+
+// ── --hide-terminating ────────────────────────────────────────────────────────
+
+fn terminating_pod(name: &str) -> Pod {
+    let mut pod = named_pod(name);
+    pod.metadata.deletion_timestamp = Some(Time(Timestamp::now()));
+    pod
+}
+
+fn hide_terminating() -> WatchOptions {
+    WatchOptions {
+        hide_terminating: true,
+        ..WatchOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn terminating_pods_are_shown_by_default() {
+    use kube::runtime::watcher::Event as W;
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(named_pod("web-new")),
+            W::InitApply(terminating_pod("web-old")),
+            W::InitDone,
+            W::Apply(terminating_pod("web-older")),
+        ],
+        WatchOptions::default(),
+    )
+    .await;
+    assert_eq!(names, ["web-new", "web-old", "web-older"]);
+}
+
+#[tokio::test]
+async fn hide_terminating_filters_terminating_pods() {
+    use kube::runtime::watcher::Event as W;
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(named_pod("web-new")),
+            W::InitApply(terminating_pod("web-old")),
+            W::InitDone,
+            W::Apply(terminating_pod("web-older")),
+        ],
+        hide_terminating(),
+    )
+    .await;
+    assert_eq!(names, ["web-new"]);
+}
+
+#[test]
+fn terminating_hidden_applies_to_pods_only() {
+    let opts = hide_terminating();
+    assert!(terminating_hidden(&ResourceKind::Pod, "Terminating", &opts));
+    assert!(!terminating_hidden(&ResourceKind::Pod, "Running", &opts));
+    assert!(!terminating_hidden(
+        &ResourceKind::Namespace,
+        "Terminating",
+        &opts
+    ));
+    assert!(!terminating_hidden(
+        &ResourceKind::Pod,
+        "Terminating",
+        &WatchOptions::default()
+    ));
+}
+
+fn changes_only() -> WatchOptions {
+    WatchOptions {
+        changes_only: true,
+        ..WatchOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn initial_list_is_sent_by_default() {
+    use kube::runtime::watcher::Event as W;
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(named_pod("old")),
+            W::InitDone,
+            W::Apply(named_pod("new")),
+        ],
+        WatchOptions::default(),
+    )
+    .await;
+    assert_eq!(names, ["new", "old"]);
+}
+
+#[tokio::test]
+async fn changes_only_skips_initial_list_but_sends_later_apply() {
+    use kube::runtime::watcher::Event as W;
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(named_pod("old")),
+            W::InitDone,
+            W::Apply(named_pod("new")),
+        ],
+        changes_only(),
+    )
+    .await;
+    assert_eq!(names, ["new"]);
+}
+
+#[tokio::test]
+async fn changes_only_shows_modified_and_deleted_preexisting_objects() {
+    use kube::runtime::watcher::Event as W;
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(named_pod("edited")),
+            W::InitApply(named_pod("removed")),
+            W::InitDone,
+            W::Apply(named_pod("edited")),
+            W::Delete(named_pod("removed")),
+        ],
+        changes_only(),
+    )
+    .await;
+    assert_eq!(names, ["edited", "removed"]);
+}
+
+#[test]
+fn watching_text_names_the_context() {
+    assert_eq!(watching_text(""), "(watching for changes…)");
+    assert_eq!(watching_text("prod"), "(prod: watching for changes…)");
+}
+
+// ── --exclude-namespaces ─────────────────────────────────────────────────────
+
+#[test]
+fn namespace_excluded_drops_listed_namespaces_only() {
+    let excluded = vec!["kube-system".to_string(), "monitoring".to_string()];
+    assert!(namespace_excluded("kube-system", &excluded));
+    assert!(namespace_excluded("monitoring", &excluded));
+    assert!(!namespace_excluded("default", &excluded));
+    // Cluster-scoped items have no namespace and always pass.
+    assert!(!namespace_excluded("", &excluded));
+    assert!(!namespace_excluded("kube-system", &[]));
+}
+
+fn pod_in(ns: &str, name: &str) -> Pod {
+    let mut pod = named_pod(name);
+    pod.metadata.namespace = Some(ns.to_string());
+    pod
+}
+
+#[tokio::test]
+async fn excluded_namespaces_are_dropped_from_initial_and_live_items() {
+    use kube::runtime::watcher::Event as W;
+    let opts = WatchOptions {
+        exclude_namespaces: vec!["kube-system".to_string()],
+        ..WatchOptions::default()
+    };
+    let names = sent_names(
+        vec![
+            W::Init,
+            W::InitApply(pod_in("kube-system", "coredns")),
+            W::InitApply(pod_in("default", "api")),
+            W::InitDone,
+            W::Apply(pod_in("kube-system", "kube-proxy")),
+            W::Apply(pod_in("default", "web")),
+        ],
+        opts,
+    )
+    .await;
+    assert_eq!(names, ["api", "web"]);
 }
@@ -0,0 +1,129 @@
+//! Tests for kuberift::rollout — `--rollout` completion checks and rendering.
+
+use k8s_openapi::api::apps::v1::{
+    Deployment, DeploymentCondition, DeploymentSpec, DeploymentStatus,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use kuberift::rollout::{match_labels_selector, render_rollout, rollout_state, RolloutState};
+use std::collections::BTreeMap;
+
+/// A three-replica deployment at generation 2, observed at `observed`.
+fn deployment(observed: i64, status: DeploymentStatus) -> Deployment {
+    Deployment {
+        metadata: ObjectMeta {
+            name: Some("web".to_string()),
+            generation: Some(2),
+            ..Default::default()
+        },
+        spec: Some(DeploymentSpec {
+            replicas: Some(3),
+            ..Default::default()
+        }),
+        status: Some(DeploymentStatus {
+            observed_generation: Some(observed),
+            ..status
+        }),
+    }
+}
+
+fn replicas(total: i32, updated: i32, available: i32) -> DeploymentStatus {
+    DeploymentStatus {
+        replicas: Some(total),
+        updated_replicas: Some(updated),
+        available_replicas: Some(available),
+        ready_replicas: Some(available),
+        ..Default::default()
+    }
+}
+
+// ── rollout_state ─────────────────────────────────────────────────────────────
+
+#[test]
+fn rollout_complete_when_all_updated_and_available() {
+    assert_eq!(
+        rollout_state(&deployment(2, replicas(3, 3, 3))),
+        RolloutState::Complete
+    );
+}
+
+#[test]
+fn rollout_in_progress_mid_update() {
+    assert_eq!(
+        rollout_state(&deployment(2, replicas(4, 2, 3))),
+        RolloutState::InProgress
+    );
+}
+
+#[test]
+fn rollout_in_progress_while_old_replicas_remain() {
+    assert_eq!(
+        rollout_state(&deployment(2, replicas(4, 3, 3))),
+        RolloutState::InProgress
+    );
+}
+
+#[test]
+fn rollout_in_progress_until_new_spec_observed() {
+    // Counts look finished, but they describe the previous generation.
+    assert_eq!(
+        rollout_state(&deployment(1, replicas(3, 3, 3))),
+        RolloutState::InProgress
+    );
+}
+
+#[test]
+fn rollout_failed_on_progress_deadline() {
+    let status = DeploymentStatus {
+        conditions: Some(vec![DeploymentCondition {
+            type_: "Progressing".to_string(),
+            status: "False".to_string(),
+            reason: Some("ProgressDeadlineExceeded".to_string()),
+            ..Default::default()
+        }]),
+        ..replicas(4, 1, 3)
+    };
+    assert_eq!(
+        rollout_state(&deployment(2, status)),
+        RolloutState::Failed("ProgressDeadlineExceeded".to_string())
+    );
+}
+
+// ── match_labels_selector ─────────────────────────────────────────────────────
+
+#[test]
+fn match_labels_selector_joins_labels() {
+    let selector = LabelSelector {
+        match_labels: Some(BTreeMap::from([
+            ("app".to_string(), "web".to_string()),
+            ("tier".to_string(), "fe".to_string()),
+        ])),
+        ..Default::default()
+    };
+    assert_eq!(
+        match_labels_selector(&selector).as_deref(),
+        Some("app=web,tier=fe")
+    );
+    assert_eq!(match_labels_selector(&LabelSelector::default()), None);
+}
+
+// ── render_rollout ────────────────────────────────────────────────────────────
+
+#[test]
+fn render_rollout_lists_pods_by_name() {
+    let pods = [
+        ("web-new-b".to_string(), "ContainerCreating".to_string()),
+        ("web-new-a".to_string(), "Running".to_string()),
+        ("web-old-c".to_string(), "Terminating".to_string()),
+    ];
+    assert_eq!(
+        render_rollout(
+            "web",
+            "2/3 (updated 2/3, available 2, unavailable 1)",
+            &pods
+        ),
+        "deploy/web  2/3 (updated 2/3, available 2, unavailable 1)\n\
+         \x20 web-new-a  Running\n\
+         \x20 web-new-b  ContainerCreating\n\
+         \x20 web-old-c  Terminating\n"
+    );
+}
@@ -0,0 +1,86 @@
+//! Tests for kuberift::top — `kubectl top` parsing, sorting and rendering.
+
+use kuberift::top::{parse_top, render_top, sort_top, top_args, TopSort, TopTarget};
+
+const PODS_ALL_NS: &str = "\
+NAMESPACE     NAME                        CPU(cores)   MEMORY(bytes)
+kube-system   coredns-5d78c9869d-abcde    3m           14Mi
+prod          api-7d9f8b6c5-x2x9z         250m         512Mi
+prod          worker-6f8b9c4d7-mn3qr      1200m        128Mi
+";
+
+const NODES: &str = "\
+NAME     CPU(cores)   CPU%   MEMORY(bytes)   MEMORY%
+node-a   350m         17%    2Gi             52%
+node-b   1            50%    1024Mi          26%
+";
+
+// ── parse_top ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_top_pods_all_namespaces() {
+    let rows = parse_top(PODS_ALL_NS);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1].namespace, "prod");
+    assert_eq!(rows[1].name, "api-7d9f8b6c5-x2x9z");
+    assert_eq!(rows[1].cpu, "250m");
+    assert!((rows[1].cpu_cores - 0.25).abs() < 1e-9);
+    assert!((rows[1].memory_bytes - 512.0 * 1024.0 * 1024.0).abs() < 1.0);
+}
+
+#[test]
+fn parse_top_nodes_skips_percent_columns() {
+    let rows = parse_top(NODES);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].namespace, "");
+    assert_eq!(rows[0].name, "node-a");
+    assert_eq!(rows[0].memory, "2Gi");
+    assert!((rows[1].cpu_cores - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn parse_top_without_header_is_empty() {
+    assert!(parse_top("").is_empty());
+    assert!(parse_top("error: Metrics API not available\n").is_empty());
+}
+
+// ── sort_top / render_top ─────────────────────────────────────────────────────
+
+#[test]
+fn sort_top_by_cpu_and_memory_descending() {
+    let mut rows = parse_top(PODS_ALL_NS);
+    sort_top(&mut rows, TopSort::Cpu);
+    assert_eq!(rows[0].name, "worker-6f8b9c4d7-mn3qr");
+    sort_top(&mut rows, TopSort::Memory);
+    assert_eq!(rows[0].name, "api-7d9f8b6c5-x2x9z");
+}
+
+#[test]
+fn render_top_limits_rows() {
+    let mut rows = parse_top(PODS_ALL_NS);
+    sort_top(&mut rows, TopSort::Cpu);
+    let table = render_top(&rows, 2);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3, "header + 2 rows:\n{table}");
+    assert!(lines[0].starts_with("NAMESPACE/NAME"));
+    assert!(lines[1].contains("prod/worker-6f8b9c4d7-mn3qr"));
+}
+
+// ── top_args ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn top_args_pods_default_to_all_namespaces() {
+    assert_eq!(top_args(TopTarget::Pods, None, ""), ["top", "pods", "-A"]);
+    assert_eq!(
+        top_args(TopTarget::Pods, Some("prod"), "east"),
+        ["top", "pods", "-n", "prod", "--context", "east"]
+    );
+}
+
+#[test]
+fn top_args_nodes_ignore_namespace() {
+    assert_eq!(
+        top_args(TopTarget::Nodes, Some("prod"), ""),
+        ["top", "nodes"]
+    );
+}
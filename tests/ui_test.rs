@@ -0,0 +1,284 @@
+//! Tests for kuberift::ui — skim option builders.
+
+use kuberift::items::{K8sItem, Layout, ResourceKind};
+use kuberift::ui::{
+    build_pick_options, build_skim_options, dedup_selection, grouped_json_output, instance_stage,
+    key_help, kind_choice_label, kind_choices, list_binds, list_columns, pick_output, ListView,
+};
+
+fn view() -> ListView<'static> {
+    ListView {
+        ctx_label: "prod",
+        kind_label: "all",
+        show_ctx_switch: true,
+        footer: "filters: none",
+        ..ListView::default()
+    }
+}
+
+// ── mouse ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn mouse_disabled_by_default() {
+    let options = build_skim_options(&view()).unwrap();
+    assert!(options.no_mouse, "mouse mode must be off unless --mouse");
+}
+
+#[test]
+fn mouse_flag_enables_mouse_mode() {
+    let options = build_skim_options(&ListView {
+        mouse: true,
+        ..view()
+    })
+    .unwrap();
+    assert!(!options.no_mouse, "--mouse must enable skim's mouse mode");
+}
+
+#[test]
+fn mouse_mode_keeps_action_bindings() {
+    let options = build_skim_options(&ListView {
+        mouse: true,
+        ..view()
+    })
+    .unwrap();
+    for key in ["ctrl-l:accept", "ctrl-d:accept", "ctrl-x:accept"] {
+        assert!(
+            options.bind.iter().any(|b| b == key),
+            "missing binding {key}"
+        );
+    }
+}
+
+// ── --exact ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn fuzzy_matching_by_default() {
+    assert!(!build_skim_options(&view()).unwrap().exact);
+}
+
+#[test]
+fn exact_mode_configures_skim_and_keeps_bindings() {
+    let view = ListView {
+        exact: true,
+        ..view()
+    };
+    let options = build_skim_options(&view).unwrap();
+    assert!(options.exact);
+    for key in ["ctrl-l:accept", "ctrl-d:accept", "alt-m:accept"] {
+        assert!(
+            options.bind.iter().any(|b| b == key),
+            "missing binding {key}"
+        );
+    }
+    assert!(options.header.unwrap_or_default().contains("[EXACT]"));
+    assert!(build_pick_options(&view).unwrap().exact);
+}
+
+#[test]
+fn list_binds_leave_skims_word_keys_alone() {
+    let binds = list_binds(true);
+    let bound = |key: &str| binds.iter().any(|b| b.starts_with(&format!("{key}:")));
+    assert!(!bound("alt-f"), "alt-f is skim's forward-word");
+    assert!(!bound("alt-b"), "alt-b is skim's backward-word");
+    assert!(!bound("alt-d"), "alt-d is skim's kill-word");
+    assert!(binds.iter().any(|b| b == "alt-t:accept"));
+    assert!(binds.iter().any(|b| b == "alt-v:accept"));
+    assert!(binds.iter().any(|b| b == "alt-z:accept"));
+}
+
+// ── header ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn header_shows_namespace_badge() {
+    let options = build_skim_options(&ListView {
+        namespace: Some("payments"),
+        ..view()
+    })
+    .unwrap();
+    let header = options.header.unwrap_or_default();
+    assert!(header.contains("[ns:payments]"), "header: {header}");
+    assert!(header.contains("filters: none"), "header: {header}");
+}
+
+#[test]
+fn header_key_hints_fit_one_line() {
+    let options = build_skim_options(&view()).unwrap();
+    let header = options.header.unwrap_or_default();
+    let hints = header.lines().nth(1).unwrap_or_default();
+    assert!(hints.chars().count() <= 120, "hints: {hints}");
+    assert!(hints.contains("f1 all-keys"), "hints: {hints}");
+}
+
+#[test]
+fn key_help_lists_every_bound_key() {
+    for show_ctx_switch in [false, true] {
+        let help = key_help(show_ctx_switch);
+        for bind in list_binds(show_ctx_switch) {
+            let key = bind.split(':').next().unwrap();
+            if key == "f1" {
+                continue;
+            }
+            assert!(
+                help.lines()
+                    .any(|line| line.split_whitespace().any(|word| word == key)),
+                "{key} missing from help:\n{help}"
+            );
+        }
+    }
+    assert!(!key_help(false).contains("ctrl-x"));
+}
+
+// ── --height / --layout ───────────────────────────────────────────────────────
+
+#[test]
+fn height_and_layout_default() {
+    let options = build_skim_options(&view()).unwrap();
+    assert_eq!(options.height, "60%");
+    assert_eq!(options.layout, "default");
+}
+
+#[test]
+fn height_and_layout_follow_flags() {
+    let view = ListView {
+        height: Some(100),
+        layout: Layout::Reverse,
+        ..view()
+    };
+    for options in [
+        build_skim_options(&view).unwrap(),
+        build_pick_options(&view).unwrap(),
+    ] {
+        assert_eq!(options.height, "100%");
+        assert_eq!(options.layout, "reverse");
+    }
+}
+
+// ── --pick ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn pick_options_have_no_action_bindings() {
+    let options = build_pick_options(&view()).unwrap();
+    assert!(
+        options.bind.is_empty(),
+        "pick mode must not bind action keys: {:?}",
+        options.bind
+    );
+    let header = options.header.unwrap_or_default();
+    assert!(!header.contains("ctrl-d"), "header: {header}");
+}
+
+#[test]
+fn pick_output_prints_one_line_per_selection() {
+    let pod = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    let node = K8sItem::new(ResourceKind::Node, "", "node-1", "Ready", "9d", "");
+    assert_eq!(pick_output(&[&pod, &node]), "pod/prod/api-0\nnode/node-1\n");
+}
+
+#[test]
+fn pick_output_empty_selection() {
+    assert_eq!(pick_output(&[]), "");
+}
+
+// ── grouped_json_output ───────────────────────────────────────────────────────
+
+#[test]
+fn grouped_json_output_keys_selections_by_context() {
+    let prod_api = K8sItem::new(ResourceKind::Pod, "web", "api-0", "Running", "1h", "prod");
+    let staging_api = K8sItem::new(
+        ResourceKind::Pod,
+        "web",
+        "api-0",
+        "Pending",
+        "2m",
+        "staging",
+    );
+    let prod_node = K8sItem::new(ResourceKind::Node, "", "node-1", "Ready", "9d", "prod");
+    let out = grouped_json_output(&[&prod_api, &staging_api, &prod_node]).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+    let groups = value.as_object().unwrap();
+    assert_eq!(groups.keys().collect::<Vec<_>>(), ["prod", "staging"]);
+    let names = |ctx: &str| -> Vec<String> {
+        value[ctx]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| {
+                format!(
+                    "{}/{}",
+                    item["kind"].as_str().unwrap(),
+                    item["name"].as_str().unwrap()
+                )
+            })
+            .collect()
+    };
+    assert_eq!(names("prod"), ["pod/api-0", "node/node-1"]);
+    assert_eq!(names("staging"), ["pod/api-0"]);
+    assert_eq!(value["staging"][0]["status"], "Pending");
+}
+
+#[test]
+fn grouped_json_output_single_cluster_uses_empty_key() {
+    let pod = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    let value: serde_json::Value =
+        serde_json::from_str(&grouped_json_output(&[&pod]).unwrap()).unwrap();
+    assert_eq!(value[""][0]["name"], "api-0");
+    assert_eq!(grouped_json_output(&[]).unwrap(), "{}\n");
+}
+
+// ── dedup_selection ───────────────────────────────────────────────────────────
+
+#[test]
+fn duplicate_selection_emits_one_line() {
+    let first = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    let resent = K8sItem::new(ResourceKind::Pod, "prod", "api-0", "Running", "1h", "");
+    let other = K8sItem::new(ResourceKind::Pod, "prod", "api-1", "Running", "1h", "");
+    let items = dedup_selection(vec![&first, &other, &resent]);
+    assert_eq!(pick_output(&items), "pod/prod/api-0\npod/prod/api-1\n");
+}
+
+#[test]
+fn list_columns_leaves_room_for_preview_and_gutter() {
+    assert_eq!(list_columns(120), 57);
+    assert_eq!(list_columns(2), 0);
+}
+
+// ── --two-stage ───────────────────────────────────────────────────────────────
+
+fn item(kind: ResourceKind, name: &str, status: &str) -> K8sItem {
+    K8sItem::new(kind, "default", name, status, "1d", "")
+}
+
+#[test]
+fn kind_choices_count_live_items_in_kind_order() {
+    let items = [
+        item(ResourceKind::Pod, "a", "Running"),
+        item(ResourceKind::Service, "b", "ClusterIP"),
+        item(ResourceKind::Pod, "c", "CrashLoopBackOff"),
+        item(ResourceKind::Pod, "d", "[DELETED]"),
+        item(ResourceKind::Secret, "e", "[DELETED]"),
+    ];
+    let kinds = [
+        ResourceKind::Service,
+        ResourceKind::Secret,
+        ResourceKind::Pod,
+        ResourceKind::Node,
+    ];
+    assert_eq!(
+        kind_choices(&items, &kinds),
+        [(ResourceKind::Service, 1), (ResourceKind::Pod, 2)]
+    );
+}
+
+#[test]
+fn kind_choice_label_pads_kind() {
+    assert_eq!(kind_choice_label(&ResourceKind::Pod, 12), "pod      12");
+}
+
+#[test]
+fn picked_kind_scopes_the_instance_stage() {
+    let (kinds, label) = instance_stage(Some(ResourceKind::Deployment)).unwrap();
+    assert_eq!(kinds, [ResourceKind::Deployment]);
+    assert_eq!(label, "deploy");
+    assert_eq!(instance_stage(None), None);
+}